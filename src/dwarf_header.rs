@@ -0,0 +1,168 @@
+#![allow(dead_code)]
+
+pub const DW_UT_COMPILE: u8 = 0x01;
+pub const DW_UT_TYPE: u8 = 0x02;
+pub const DW_UT_PARTIAL: u8 = 0x03;
+pub const DW_UT_SKELETON: u8 = 0x04;
+pub const DW_UT_SPLIT_COMPILE: u8 = 0x05;
+pub const DW_UT_SPLIT_TYPE: u8 = 0x06;
+
+pub const DW_TAG_COMPILE_UNIT: u64 = 0x11;
+pub const DW_TAG_PARTIAL_UNIT: u64 = 0x3c;
+pub const DW_TAG_TYPE_UNIT: u64 = 0x41;
+pub const DW_TAG_SKELETON_UNIT: u64 = 0x4a;
+
+pub const DW_CHILDREN_NO: u8 = 0x00;
+pub const DW_CHILDREN_YES: u8 = 0x01;
+
+pub const DW_AT_NAME: u64 = 0x03;
+pub const DW_AT_STMT_LIST: u64 = 0x10;
+pub const DW_AT_LOW_PC: u64 = 0x11;
+pub const DW_AT_HIGH_PC: u64 = 0x12;
+pub const DW_AT_LANGUAGE: u64 = 0x13;
+pub const DW_AT_COMP_DIR: u64 = 0x1b;
+pub const DW_AT_PRODUCER: u64 = 0x25;
+pub const DW_AT_RANGES: u64 = 0x55;
+pub const DW_AT_STR_OFFSETS_BASE: u64 = 0x72;
+pub const DW_AT_ADDR_BASE: u64 = 0x73;
+pub const DW_AT_RNGLISTS_BASE: u64 = 0x74;
+pub const DW_AT_DWO_NAME: u64 = 0x76;
+pub const DW_AT_GNU_DWO_NAME: u64 = 0x2130;
+pub const DW_AT_GNU_ADDR_BASE: u64 = 0x2133;
+
+pub const DW_FORM_ADDR: u64 = 0x01;
+pub const DW_FORM_BLOCK2: u64 = 0x03;
+pub const DW_FORM_BLOCK4: u64 = 0x04;
+pub const DW_FORM_DATA2: u64 = 0x05;
+pub const DW_FORM_DATA4: u64 = 0x06;
+pub const DW_FORM_DATA8: u64 = 0x07;
+pub const DW_FORM_STRING: u64 = 0x08;
+pub const DW_FORM_BLOCK: u64 = 0x09;
+pub const DW_FORM_BLOCK1: u64 = 0x0a;
+pub const DW_FORM_DATA1: u64 = 0x0b;
+pub const DW_FORM_FLAG: u64 = 0x0c;
+pub const DW_FORM_SDATA: u64 = 0x0d;
+pub const DW_FORM_STRP: u64 = 0x0e;
+pub const DW_FORM_UDATA: u64 = 0x0f;
+pub const DW_FORM_REF_ADDR: u64 = 0x10;
+pub const DW_FORM_REF1: u64 = 0x11;
+pub const DW_FORM_REF2: u64 = 0x12;
+pub const DW_FORM_REF4: u64 = 0x13;
+pub const DW_FORM_REF8: u64 = 0x14;
+pub const DW_FORM_REF_UDATA: u64 = 0x15;
+pub const DW_FORM_INDIRECT: u64 = 0x16;
+pub const DW_FORM_SEC_OFFSET: u64 = 0x17;
+pub const DW_FORM_EXPRLOC: u64 = 0x18;
+pub const DW_FORM_FLAG_PRESENT: u64 = 0x19;
+pub const DW_FORM_STRX: u64 = 0x1a;
+pub const DW_FORM_ADDRX: u64 = 0x1b;
+pub const DW_FORM_REF_SUP4: u64 = 0x1c;
+pub const DW_FORM_STRP_SUP: u64 = 0x1d;
+pub const DW_FORM_DATA16: u64 = 0x1e;
+pub const DW_FORM_LINE_STRP: u64 = 0x1f;
+pub const DW_FORM_REF_SIG8: u64 = 0x20;
+pub const DW_FORM_IMPLICIT_CONST: u64 = 0x21;
+pub const DW_FORM_LOCLISTX: u64 = 0x22;
+pub const DW_FORM_RNGLISTX: u64 = 0x23;
+pub const DW_FORM_REF_SUP8: u64 = 0x24;
+pub const DW_FORM_STRX1: u64 = 0x25;
+pub const DW_FORM_STRX2: u64 = 0x26;
+pub const DW_FORM_STRX3: u64 = 0x27;
+pub const DW_FORM_STRX4: u64 = 0x28;
+pub const DW_FORM_ADDRX1: u64 = 0x29;
+pub const DW_FORM_ADDRX2: u64 = 0x2a;
+pub const DW_FORM_ADDRX3: u64 = 0x2b;
+pub const DW_FORM_ADDRX4: u64 = 0x2c;
+pub const DW_FORM_GNU_ADDR_INDEX: u64 = 0x1f01;
+pub const DW_FORM_GNU_STR_INDEX: u64 = 0x1f02;
+pub const DW_FORM_GNU_REF_ALT: u64 = 0x1f20;
+pub const DW_FORM_GNU_STRP_ALT: u64 = 0x1f21;
+
+pub const DW_LANG_C89: u64 = 0x0001;
+pub const DW_LANG_C: u64 = 0x0002;
+pub const DW_LANG_ADA83: u64 = 0x0003;
+pub const DW_LANG_C_PLUS_PLUS: u64 = 0x0004;
+pub const DW_LANG_COBOL74: u64 = 0x0005;
+pub const DW_LANG_COBOL85: u64 = 0x0006;
+pub const DW_LANG_FORTRAN77: u64 = 0x0007;
+pub const DW_LANG_FORTRAN90: u64 = 0x0008;
+pub const DW_LANG_PASCAL83: u64 = 0x0009;
+pub const DW_LANG_MODULA2: u64 = 0x000a;
+pub const DW_LANG_JAVA: u64 = 0x000b;
+pub const DW_LANG_C99: u64 = 0x000c;
+pub const DW_LANG_ADA95: u64 = 0x000d;
+pub const DW_LANG_FORTRAN95: u64 = 0x000e;
+pub const DW_LANG_PLI: u64 = 0x000f;
+pub const DW_LANG_OBJC: u64 = 0x0010;
+pub const DW_LANG_OBJC_PLUS_PLUS: u64 = 0x0011;
+pub const DW_LANG_UPC: u64 = 0x0012;
+pub const DW_LANG_D: u64 = 0x0013;
+pub const DW_LANG_PYTHON: u64 = 0x0014;
+pub const DW_LANG_OPENCL: u64 = 0x0015;
+pub const DW_LANG_GO: u64 = 0x0016;
+pub const DW_LANG_MODULA3: u64 = 0x0017;
+pub const DW_LANG_HASKELL: u64 = 0x0018;
+pub const DW_LANG_C_PLUS_PLUS_03: u64 = 0x0019;
+pub const DW_LANG_C_PLUS_PLUS_11: u64 = 0x001a;
+pub const DW_LANG_OCAML: u64 = 0x001b;
+pub const DW_LANG_RUST: u64 = 0x001c;
+pub const DW_LANG_C11: u64 = 0x001d;
+pub const DW_LANG_SWIFT: u64 = 0x001e;
+pub const DW_LANG_JULIA: u64 = 0x001f;
+pub const DW_LANG_DYLAN: u64 = 0x0020;
+pub const DW_LANG_C_PLUS_PLUS_14: u64 = 0x0021;
+pub const DW_LANG_FORTRAN03: u64 = 0x0022;
+pub const DW_LANG_FORTRAN08: u64 = 0x0023;
+pub const DW_LANG_RENDERSCRIPT: u64 = 0x0024;
+pub const DW_LANG_BLISS: u64 = 0x0025;
+pub const DW_LANG_KOTLIN: u64 = 0x0026;
+pub const DW_LANG_ZIG: u64 = 0x0027;
+pub const DW_LANG_CRYSTAL: u64 = 0x0028;
+pub const DW_LANG_C_PLUS_PLUS_17: u64 = 0x002a;
+pub const DW_LANG_C_PLUS_PLUS_20: u64 = 0x002b;
+pub const DW_LANG_C17: u64 = 0x002c;
+pub const DW_LANG_FORTRAN18: u64 = 0x002d;
+pub const DW_LANG_ADA2005: u64 = 0x002e;
+pub const DW_LANG_ADA2012: u64 = 0x002f;
+pub const DW_LANG_MIPS_ASSEMBLER: u64 = 0x8001;
+
+pub const DW_LNCT_PATH: u64 = 0x01;
+pub const DW_LNCT_DIRECTORY_INDEX: u64 = 0x02;
+pub const DW_LNCT_TIMESTAMP: u64 = 0x03;
+pub const DW_LNCT_SIZE: u64 = 0x04;
+pub const DW_LNCT_MD5: u64 = 0x05;
+
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
+pub const DW_LNS_COPY: u8 = 0x01;
+pub const DW_LNS_ADVANCE_PC: u8 = 0x02;
+pub const DW_LNS_ADVANCE_LINE: u8 = 0x03;
+pub const DW_LNS_SET_FILE: u8 = 0x04;
+pub const DW_LNS_SET_COLUMN: u8 = 0x05;
+pub const DW_LNS_NEGATE_STMT: u8 = 0x06;
+pub const DW_LNS_SET_BASIC_BLOCK: u8 = 0x07;
+pub const DW_LNS_CONST_ADD_PC: u8 = 0x08;
+pub const DW_LNS_FIXED_ADVANCE_PC: u8 = 0x09;
+pub const DW_LNS_SET_PROLOGUE_END: u8 = 0x0a;
+pub const DW_LNS_SET_EPILOGUE_BEGIN: u8 = 0x0b;
+pub const DW_LNS_SET_ISA: u8 = 0x0c;
+
+pub const DW_LNE_END_SEQUENCE: u8 = 0x01;
+pub const DW_LNE_SET_ADDRESS: u8 = 0x02;
+pub const DW_LNE_DEFINE_FILE: u8 = 0x03;
+pub const DW_LNE_SET_DISCRIMINATOR: u8 = 0x04;
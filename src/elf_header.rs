@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#![allow(clippy::identity_op)]
 
 pub const EI_NIDENT: u8 = 16;
 pub const EI_MAG0: u8 = 0;
@@ -34,6 +35,12 @@ pub const ELFOSABI_FREEBSD: u8 = 9;
 pub const ELFOSABI_TRU64: u8 = 10;
 pub const ELFOSABI_MODESTO: u8 = 11;
 pub const ELFOSABI_OPENBSD: u8 = 12;
+pub const ELFOSABI_OPENVMS: u8 = 13;
+pub const ELFOSABI_NSK: u8 = 14;
+pub const ELFOSABI_AROS: u8 = 15;
+pub const ELFOSABI_FENIXOS: u8 = 16;
+pub const ELFOSABI_CLOUDABI: u8 = 17;
+pub const ELFOSABI_OPENVOS: u8 = 18;
 pub const ELFOSABI_ARM: u8 = 97;
 pub const ELFOSABI_STANDALONE: u8 = 255;
 pub const EI_ABIVERSION: u8 = 8;
@@ -53,6 +60,7 @@ pub const EM_SPARC: u16 = 2;
 pub const EM_386: u16 = 3;
 pub const EM_68K: u16 = 4;
 pub const EM_88K: u16 = 5;
+pub const EM_IAMCU: u16 = 6;
 pub const EM_860: u16 = 7;
 pub const EM_MIPS: u16 = 8;
 pub const EM_S370: u16 = 9;
@@ -64,6 +72,7 @@ pub const EM_960: u16 = 19;
 pub const EM_PPC: u16 = 20;
 pub const EM_PPC64: u16 = 21;
 pub const EM_S390: u16 = 22;
+pub const EM_SPU: u16 = 23;
 pub const EM_V800: u16 = 36;
 pub const EM_FR20: u16 = 37;
 pub const EM_RH32: u16 = 38;
@@ -92,6 +101,8 @@ pub const EM_ST100: u16 = 60;
 pub const EM_TINYJ: u16 = 61;
 pub const EM_X86_64: u16 = 62;
 pub const EM_PDSP: u16 = 63;
+pub const EM_PDP10: u16 = 64;
+pub const EM_PDP11: u16 = 65;
 pub const EM_FX66: u16 = 66;
 pub const EM_ST9PLUS: u16 = 67;
 pub const EM_ST7: u16 = 68;
@@ -121,10 +132,113 @@ pub const EM_PJ: u16 = 91;
 pub const EM_OPENRISC: u16 = 92;
 pub const EM_ARC_A5: u16 = 93;
 pub const EM_XTENSA: u16 = 94;
+pub const EM_VIDEOCORE: u16 = 95;
+pub const EM_TMM_GPP: u16 = 96;
+pub const EM_NS32K: u16 = 97;
+pub const EM_TPC: u16 = 98;
+pub const EM_SNP1K: u16 = 99;
+pub const EM_ST200: u16 = 100;
+pub const EM_IP2K: u16 = 101;
+pub const EM_MAX: u16 = 102;
+pub const EM_CR: u16 = 103;
+pub const EM_F2MC16: u16 = 104;
+pub const EM_MSP430: u16 = 105;
+pub const EM_BLACKFIN: u16 = 106;
+pub const EM_SE_C33: u16 = 107;
+pub const EM_SEP: u16 = 108;
+pub const EM_ARCA: u16 = 109;
+pub const EM_UNICORE: u16 = 110;
+pub const EM_EXCESS: u16 = 111;
+pub const EM_DXP: u16 = 112;
+pub const EM_ALTERA_NIOS2: u16 = 113;
+pub const EM_CRX: u16 = 114;
+pub const EM_XGATE: u16 = 115;
+pub const EM_C166: u16 = 116;
+pub const EM_M16C: u16 = 117;
+pub const EM_DSPIC30F: u16 = 118;
+pub const EM_CE: u16 = 119;
+pub const EM_M32C: u16 = 120;
+pub const EM_TSK3000: u16 = 131;
+pub const EM_RS08: u16 = 132;
+pub const EM_SHARC: u16 = 133;
+pub const EM_ECOG2: u16 = 134;
+pub const EM_SCORE7: u16 = 135;
+pub const EM_DSP24: u16 = 136;
+pub const EM_VIDEOCORE3: u16 = 137;
+pub const EM_LATTICEMICO32: u16 = 138;
+pub const EM_SE_C17: u16 = 139;
+pub const EM_TI_C6000: u16 = 140;
+pub const EM_TI_C2000: u16 = 141;
+pub const EM_TI_C5500: u16 = 142;
+pub const EM_TI_ARP32: u16 = 143;
+pub const EM_TI_PRU: u16 = 144;
+pub const EM_MMDSP_PLUS: u16 = 160;
+pub const EM_CYPRESS_M8C: u16 = 161;
+pub const EM_R32C: u16 = 162;
+pub const EM_TRIMEDIA: u16 = 163;
+pub const EM_QDSP6: u16 = 164;
+pub const EM_8051: u16 = 165;
+pub const EM_STXP7X: u16 = 166;
+pub const EM_NDS32: u16 = 167;
+pub const EM_ECOG1X: u16 = 168;
+pub const EM_MAXQ30: u16 = 169;
+pub const EM_XIMO16: u16 = 170;
+pub const EM_MANIK: u16 = 171;
+pub const EM_CRAYNV2: u16 = 172;
+pub const EM_RX: u16 = 173;
+pub const EM_METAG: u16 = 174;
+pub const EM_MCST_ELBRUS: u16 = 175;
+pub const EM_ECOG16: u16 = 176;
+pub const EM_CR16: u16 = 177;
+pub const EM_ETPU: u16 = 178;
+pub const EM_SLE9X: u16 = 179;
+pub const EM_L10M: u16 = 180;
+pub const EM_K10M: u16 = 181;
+pub const EM_AARCH64: u16 = 183;
+pub const EM_AVR32: u16 = 185;
+pub const EM_STM8: u16 = 186;
+pub const EM_TILE64: u16 = 187;
+pub const EM_TILEPRO: u16 = 188;
+pub const EM_MICROBLAZE: u16 = 189;
+pub const EM_CUDA: u16 = 190;
+pub const EM_TILEGX: u16 = 191;
+pub const EM_CLOUDSHIELD: u16 = 192;
+pub const EM_COREA_1ST: u16 = 193;
+pub const EM_COREA_2ND: u16 = 194;
+pub const EM_ARCV2: u16 = 195;
+pub const EM_OPEN8: u16 = 196;
+pub const EM_RL78: u16 = 197;
+pub const EM_VIDEOCORE5: u16 = 198;
+pub const EM_78KOR: u16 = 199;
+pub const EM_56800EX: u16 = 200;
+pub const EM_BA1: u16 = 201;
+pub const EM_BA2: u16 = 202;
+pub const EM_XCORE: u16 = 203;
+pub const EM_MCHP_PIC: u16 = 204;
+pub const EM_INTELGT: u16 = 205;
+pub const EM_KM32: u16 = 210;
+pub const EM_KMX32: u16 = 211;
+pub const EM_EMX16: u16 = 212;
+pub const EM_EMX8: u16 = 213;
+pub const EM_KVARC: u16 = 214;
+pub const EM_CDP: u16 = 215;
+pub const EM_COGE: u16 = 216;
+pub const EM_COOL: u16 = 217;
+pub const EM_NORC: u16 = 218;
+pub const EM_CSR_KALIMBA: u16 = 219;
+pub const EM_Z80: u16 = 220;
+pub const EM_VISIUM: u16 = 221;
+pub const EM_FT32: u16 = 222;
+pub const EM_MOXIE: u16 = 223;
+pub const EM_AMDGPU: u16 = 224;
+pub const EM_RISCV: u16 = 243;
+pub const EM_BPF: u16 = 247;
+pub const EM_CSKY: u16 = 252;
+pub const EM_LOONGARCH: u16 = 258;
 pub const EM_ALPHA: u16 = 0x9026;
 pub const EV_NONE: u8 = 0;
 pub const EV_CURRENT: u8 = 1;
-pub const SHN_UNDEF: u8 = 0;
+pub const SHN_UNDEF: u32 = 0;
 pub const SHN_LORESERVE: u32 = 0xff00;
 pub const SHN_LOPROC: u32 = 0xff00;
 pub const SHN_BEFORE: u32 = 0xff00;
@@ -181,10 +295,17 @@ pub const SHF_LINK_ORDER: u64 = 1 << 7;
 pub const SHF_OS_NONCONFORMING: u64 = 1 << 8;
 pub const SHF_GROUP: u64 = 1 << 9;
 pub const SHF_TLS: u64 = 1 << 10;
+pub const SHF_COMPRESSED: u64 = 1 << 11;
 pub const SHF_MASKOS: u64 = 0x0ff00000;
 pub const SHF_MASKPROC: u64 = 0xf0000000;
 pub const SHF_ORDERED: u64 = 1 << 30;
 pub const SHF_EXCLUDE: u64 = 1 << 31;
+pub const ELFCOMPRESS_ZLIB: u32 = 1;
+pub const ELFCOMPRESS_ZSTD: u32 = 2;
+pub const ELFCOMPRESS_LOOS: u32 = 0x60000000;
+pub const ELFCOMPRESS_HIOS: u32 = 0x6fffffff;
+pub const ELFCOMPRESS_LOPROC: u32 = 0x70000000;
+pub const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
 pub const GRP_COMDAT: u32 = 0x1;
 pub const SYMINFO_BT_SELF: u32 = 0xffff;
 pub const SYMINFO_BT_PARENT: u32 = 0xfffe;
@@ -220,6 +341,7 @@ pub const STV_DEFAULT: u8 = 0;
 pub const STV_INTERNAL: u8 = 1;
 pub const STV_HIDDEN: u8 = 2;
 pub const STV_PROTECTED: u8 = 3;
+pub const PN_XNUM: u16 = 0xffff;
 pub const PT_NULL: u32 = 0;
 pub const PT_LOAD: u32 = 1;
 pub const PT_DYNAMIC: u32 = 2;
@@ -244,63 +366,70 @@ pub const PF_W: u32 = 1 << 1;
 pub const PF_R: u32 = 1 << 2;
 pub const PF_MASKOS: u32 = 0x0ff00000;
 pub const PF_MASKPROC: u32 = 0xf0000000;
-pub const NT_PRSTATUS: u8 = 1;
-pub const NT_FPREGSET: u8 = 2;
-pub const NT_PRPSINFO: u8 = 3;
-pub const NT_PRXREG: u8 = 4;
-pub const NT_TASKSTRUCT: u8 = 4;
-pub const NT_PLATFORM: u8 = 5;
-pub const NT_AUXV: u8 = 6;
-pub const NT_GWINDOWS: u8 = 7;
-pub const NT_ASRS: u8 = 8;
-pub const NT_PSTATUS: u8 = 10;
-pub const NT_PSINFO: u8 = 13;
-pub const NT_PRCRED: u8 = 14;
-pub const NT_UTSNAME: u8 = 15;
-pub const NT_LWPSTATUS: u8 = 16;
-pub const NT_LWPSINFO: u8 = 17;
-pub const NT_PRFPXREG: u8 = 20;
+pub const NT_PRSTATUS: u32 = 1;
+pub const NT_FPREGSET: u32 = 2;
+pub const NT_PRPSINFO: u32 = 3;
+pub const NT_PRXREG: u32 = 4;
+pub const NT_TASKSTRUCT: u32 = 4;
+pub const NT_PLATFORM: u32 = 5;
+pub const NT_AUXV: u32 = 6;
+pub const NT_GWINDOWS: u32 = 7;
+pub const NT_ASRS: u32 = 8;
+pub const NT_PSTATUS: u32 = 10;
+pub const NT_PSINFO: u32 = 13;
+pub const NT_PRCRED: u32 = 14;
+pub const NT_UTSNAME: u32 = 15;
+pub const NT_LWPSTATUS: u32 = 16;
+pub const NT_LWPSINFO: u32 = 17;
+pub const NT_PRFPXREG: u32 = 20;
 pub const NT_PRXFPREG: u32 = 0x46e62b7f;
+pub const NT_SIGINFO: u32 = 0x53494749;
+pub const NT_FILE: u32 = 0x46494c45;
 pub const NT_PPC_VMX: u32 = 0x100;
 pub const NT_PPC_SPE: u32 = 0x101;
 pub const NT_PPC_VSX: u32 = 0x102;
 pub const NT_386_TLS: u32 = 0x200;
 pub const NT_386_IOPERM: u32 = 0x201;
-pub const NT_VERSION: u8 = 1;
-pub const DT_NULL: u8 = 0;
-pub const DT_NEEDED: u8 = 1;
-pub const DT_PLTRELSZ: u8 = 2;
-pub const DT_PLTGOT: u8 = 3;
-pub const DT_HASH: u8 = 4;
-pub const DT_STRTAB: u8 = 5;
-pub const DT_SYMTAB: u8 = 6;
-pub const DT_RELA: u8 = 7;
-pub const DT_RELASZ: u8 = 8;
-pub const DT_RELAENT: u8 = 9;
-pub const DT_STRSZ: u8 = 10;
-pub const DT_SYMENT: u8 = 11;
-pub const DT_INIT: u8 = 12;
-pub const DT_FINI: u8 = 13;
-pub const DT_SONAME: u8 = 14;
-pub const DT_RPATH: u8 = 15;
-pub const DT_SYMBOLIC: u8 = 16;
-pub const DT_REL: u8 = 17;
-pub const DT_RELSZ: u8 = 18;
-pub const DT_RELENT: u8 = 19;
-pub const DT_PLTREL: u8 = 20;
-pub const DT_DEBUG: u8 = 21;
-pub const DT_TEXTREL: u8 = 22;
-pub const DT_JMPREL: u8 = 23;
-pub const DT_BIND_NOW: u8 = 24;
-pub const DT_INIT_ARRAY: u8 = 25;
-pub const DT_FINI_ARRAY: u8 = 26;
-pub const DT_INIT_ARRAYSZ: u8 = 27;
-pub const DT_FINI_ARRAYSZ: u8 = 28;
-pub const DT_RUNPATH: u8 = 29;
-pub const DT_FLAGS: u8 = 30;
-pub const DT_ENCODING: u8 = 32;
-pub const DT_PREINIT_ARRAY: u8 = 32;
-pub const DT_PREINIT_ARRAYSZ: u8 = 33;
+pub const NT_X86_XSTATE: u32 = 0x202;
+pub const NT_VERSION: u32 = 1;
+pub const DT_NULL: u32 = 0;
+pub const DT_NEEDED: u32 = 1;
+pub const DT_PLTRELSZ: u32 = 2;
+pub const DT_PLTGOT: u32 = 3;
+pub const DT_HASH: u32 = 4;
+pub const DT_STRTAB: u32 = 5;
+pub const DT_SYMTAB: u32 = 6;
+pub const DT_RELA: u32 = 7;
+pub const DT_RELASZ: u32 = 8;
+pub const DT_RELAENT: u32 = 9;
+pub const DT_STRSZ: u32 = 10;
+pub const DT_SYMENT: u32 = 11;
+pub const DT_INIT: u32 = 12;
+pub const DT_FINI: u32 = 13;
+pub const DT_SONAME: u32 = 14;
+pub const DT_RPATH: u32 = 15;
+pub const DT_SYMBOLIC: u32 = 16;
+pub const DT_REL: u32 = 17;
+pub const DT_RELSZ: u32 = 18;
+pub const DT_RELENT: u32 = 19;
+pub const DT_PLTREL: u32 = 20;
+pub const DT_DEBUG: u32 = 21;
+pub const DT_TEXTREL: u32 = 22;
+pub const DT_JMPREL: u32 = 23;
+pub const DT_BIND_NOW: u32 = 24;
+pub const DT_INIT_ARRAY: u32 = 25;
+pub const DT_FINI_ARRAY: u32 = 26;
+pub const DT_INIT_ARRAYSZ: u32 = 27;
+pub const DT_FINI_ARRAYSZ: u32 = 28;
+pub const DT_RUNPATH: u32 = 29;
+pub const DT_FLAGS: u32 = 30;
+pub const DT_ENCODING: u32 = 32;
+pub const DT_PREINIT_ARRAY: u32 = 32;
+pub const DT_PREINIT_ARRAYSZ: u32 = 33;
+pub const DT_SYMTAB_SHNDX: u32 = 34;
+pub const DT_RELRSZ: u32 = 35;
+pub const DT_RELR: u32 = 36;
+pub const DT_RELRENT: u32 = 37;
 pub const DT_LOOS: u32 = 0x6000000d;
 pub const DT_HIOS: u32 = 0x6ffff000;
 pub const DT_LOPROC: u32 = 0x70000000;
@@ -319,7 +448,7 @@ pub const DT_POSFLAG_1: u32 = 0x6ffffdfd;
 pub const DT_SYMINSZ: u32 = 0x6ffffdfe;
 pub const DT_SYMINENT: u32 = 0x6ffffdff;
 pub const DT_VALRNGHI: u32 = 0x6ffffdff;
-pub const DT_VALNUM: u8 = 12;
+pub const DT_VALNUM: u32 = 12;
 pub const DT_ADDRRNGLO: u32 = 0x6ffffe00;
 pub const DT_GNU_HASH: u32 = 0x6ffffef5;
 pub const DT_TLSDESC_PLT: u32 = 0x6ffffef6;
@@ -333,7 +462,7 @@ pub const DT_PLTPAD: u32 = 0x6ffffefd;
 pub const DT_MOVETAB: u32 = 0x6ffffefe;
 pub const DT_SYMINFO: u32 = 0x6ffffeff;
 pub const DT_ADDRRNGHI: u32 = 0x6ffffeff;
-pub const DT_ADDRNUM: u8 = 11;
+pub const DT_ADDRNUM: u32 = 11;
 pub const DT_VERSYM: u32 = 0x6ffffff0;
 pub const DT_RELACOUNT: u32 = 0x6ffffff9;
 pub const DT_RELCOUNT: u32 = 0x6ffffffa;
@@ -342,10 +471,10 @@ pub const DT_VERDEF: u32 = 0x6ffffffc;
 pub const DT_VERDEFNUM: u32 = 0x6ffffffd;
 pub const DT_VERNEED: u32 = 0x6ffffffe;
 pub const DT_VERNEEDNUM: u32 = 0x6fffffff;
-pub const DT_VERSIONTAGNUM: u8 = 16;
+pub const DT_VERSIONTAGNUM: u32 = 16;
 pub const DT_AUXILIARY: u32 = 0x7ffffffd;
 pub const DT_FILTER: u32 = 0x7fffffff;
-pub const DT_EXTRANUM: u8 = 3;
+pub const DT_EXTRANUM: u32 = 3;
 pub const DF_ORIGIN: u32 = 0x00000001;
 pub const DF_SYMBOLIC: u32 = 0x00000002;
 pub const DF_TEXTREL: u32 = 0x00000004;
@@ -368,65 +497,106 @@ pub const DF_1_CONFALT: u32 = 0x00002000;
 pub const DF_1_ENDFILTEE: u32 = 0x00004000;
 pub const DF_1_DISPRELDNE: u32 = 0x00008000;
 pub const DF_1_DISPRELPND: u32 = 0x00010000;
+pub const DF_1_NODIRECT: u32 = 0x00020000;
+pub const DF_1_IGNMULDEF: u32 = 0x00040000;
+pub const DF_1_NOKSYMS: u32 = 0x00080000;
+pub const DF_1_NOHDR: u32 = 0x00100000;
+pub const DF_1_EDITED: u32 = 0x00200000;
+pub const DF_1_NORELOC: u32 = 0x00400000;
+pub const DF_1_SYMINTPOSE: u32 = 0x00800000;
+pub const DF_1_GLOBAUDIT: u32 = 0x01000000;
+pub const DF_1_SINGLETON: u32 = 0x02000000;
+pub const DF_1_STUB: u32 = 0x04000000;
+pub const DF_1_PIE: u32 = 0x08000000;
 pub const DTF_1_PARINIT: u32 = 0x00000001;
 pub const DTF_1_CONFEXP: u32 = 0x00000002;
 pub const DF_P1_LAZYLOAD: u32 = 0x00000001;
 pub const DF_P1_GROUPPERM: u32 = 0x00000002;
 pub const VER_DEF_NONE: u8 = 0;
 pub const VER_DEF_CURRENT: u8 = 1;
-pub const VER_FLG_BASE: u32 = 0x1;
-pub const VER_FLG_WEAK: u32 = 0x2;
-pub const VER_NDX_LOCAL: u8 = 0;
-pub const VER_NDX_GLOBAL: u8 = 1;
-pub const VER_NDX_LORESERVE: u32 = 0xff00;
-pub const VER_NDX_ELIMINATE: u32 = 0xff01;
+pub const VER_FLG_BASE: u16 = 0x1;
+pub const VER_FLG_WEAK: u16 = 0x2;
+pub const VER_FLG_INFO: u16 = 0x4;
+pub const VER_NDX_LOCAL: u16 = 0;
+pub const VER_NDX_GLOBAL: u16 = 1;
+pub const VER_NDX_LORESERVE: u16 = 0xff00;
+pub const VER_NDX_ELIMINATE: u16 = 0xff01;
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+pub const VERSYM_VERSION: u16 = 0x7fff;
 pub const VER_NEED_NONE: u8 = 0;
 pub const VER_NEED_CURRENT: u8 = 1;
-pub const AT_NULL: u8 = 0;
-pub const AT_IGNORE: u8 = 1;
-pub const AT_EXECFD: u8 = 2;
-pub const AT_PHDR: u8 = 3;
-pub const AT_PHENT: u8 = 4;
-pub const AT_PHNUM: u8 = 5;
-pub const AT_PAGESZ: u8 = 6;
-pub const AT_BASE: u8 = 7;
-pub const AT_FLAGS: u8 = 8;
-pub const AT_ENTRY: u8 = 9;
-pub const AT_NOTELF: u8 = 10;
-pub const AT_UID: u8 = 11;
-pub const AT_EUID: u8 = 12;
-pub const AT_GID: u8 = 13;
-pub const AT_EGID: u8 = 14;
-pub const AT_CLKTCK: u8 = 17;
-pub const AT_PLATFORM: u8 = 15;
-pub const AT_HWCAP: u8 = 16;
-pub const AT_FPUCW: u8 = 18;
-pub const AT_DCACHEBSIZE: u8 = 19;
-pub const AT_ICACHEBSIZE: u8 = 20;
-pub const AT_UCACHEBSIZE: u8 = 21;
-pub const AT_IGNOREPPC: u8 = 22;
-pub const AT_SECURE: u8 = 23;
-pub const AT_BASE_PLATFORM: u8 = 24;
-pub const AT_RANDOM: u8 = 25;
-pub const AT_EXECFN: u8 = 31;
-pub const AT_SYSINFO: u8 = 32;
-pub const AT_SYSINFO_EHDR: u8 = 33;
-pub const AT_L1I_CACHESHAPE: u8 = 34;
-pub const AT_L1D_CACHESHAPE: u8 = 35;
-pub const AT_L2_CACHESHAPE: u8 = 36;
-pub const AT_L3_CACHESHAPE: u8 = 37;
+pub const AT_NULL: u64 = 0;
+pub const AT_IGNORE: u64 = 1;
+pub const AT_EXECFD: u64 = 2;
+pub const AT_PHDR: u64 = 3;
+pub const AT_PHENT: u64 = 4;
+pub const AT_PHNUM: u64 = 5;
+pub const AT_PAGESZ: u64 = 6;
+pub const AT_BASE: u64 = 7;
+pub const AT_FLAGS: u64 = 8;
+pub const AT_ENTRY: u64 = 9;
+pub const AT_NOTELF: u64 = 10;
+pub const AT_UID: u64 = 11;
+pub const AT_EUID: u64 = 12;
+pub const AT_GID: u64 = 13;
+pub const AT_EGID: u64 = 14;
+pub const AT_CLKTCK: u64 = 17;
+pub const AT_PLATFORM: u64 = 15;
+pub const AT_HWCAP: u64 = 16;
+pub const AT_FPUCW: u64 = 18;
+pub const AT_DCACHEBSIZE: u64 = 19;
+pub const AT_ICACHEBSIZE: u64 = 20;
+pub const AT_UCACHEBSIZE: u64 = 21;
+pub const AT_IGNOREPPC: u64 = 22;
+pub const AT_SECURE: u64 = 23;
+pub const AT_BASE_PLATFORM: u64 = 24;
+pub const AT_RANDOM: u64 = 25;
+pub const AT_HWCAP2: u64 = 26;
+pub const AT_RSEQ_FEATURE_SIZE: u64 = 27;
+pub const AT_RSEQ_ALIGN: u64 = 28;
+pub const AT_HWCAP3: u64 = 29;
+pub const AT_HWCAP4: u64 = 30;
+pub const AT_EXECFN: u64 = 31;
+pub const AT_SYSINFO: u64 = 32;
+pub const AT_SYSINFO_EHDR: u64 = 33;
+pub const AT_L1I_CACHESHAPE: u64 = 34;
+pub const AT_L1D_CACHESHAPE: u64 = 35;
+pub const AT_L2_CACHESHAPE: u64 = 36;
+pub const AT_L3_CACHESHAPE: u64 = 37;
+pub const AT_MINSIGSTKSZ: u64 = 51;
 pub const ELF_NOTE_SOLARIS: &str = "SUNW Solaris";
 pub const ELF_NOTE_GNU: &str = "GNU";
-pub const ELF_NOTE_PAGESIZE_HINT: u8 = 1;
-pub const NT_GNU_ABI_TAG: u8 = 1;
-pub const ELF_NOTE_ABI: u8 = NT_GNU_ABI_TAG;
-pub const ELF_NOTE_OS_LINUX: u8 = 0;
-pub const ELF_NOTE_OS_GNU: u8 = 1;
-pub const ELF_NOTE_OS_SOLARIS2: u8 = 2;
-pub const ELF_NOTE_OS_FREEBSD: u8 = 3;
-pub const NT_GNU_HWCAP: u8 = 2;
-pub const NT_GNU_BUILD_ID: u8 = 3;
-pub const NT_GNU_GOLD_VERSION: u8 = 4;
+pub const ELF_NOTE_CORE: &str = "CORE";
+pub const ELF_NOTE_LINUX: &str = "LINUX";
+pub const ELF_NOTE_PAGESIZE_HINT: u32 = 1;
+pub const NT_GNU_ABI_TAG: u32 = 1;
+pub const ELF_NOTE_ABI: u32 = NT_GNU_ABI_TAG;
+pub const ELF_NOTE_OS_LINUX: u32 = 0;
+pub const ELF_NOTE_OS_GNU: u32 = 1;
+pub const ELF_NOTE_OS_SOLARIS2: u32 = 2;
+pub const ELF_NOTE_OS_FREEBSD: u32 = 3;
+pub const NT_GNU_HWCAP: u32 = 2;
+pub const NT_GNU_BUILD_ID: u32 = 3;
+pub const NT_GNU_GOLD_VERSION: u32 = 4;
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 1 << 0;
+pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 1 << 1;
+pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 1 << 2;
+pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 1 << 3;
+pub const ELF_NOTE_FDO: &str = "FDO";
+pub const NT_FDO_PACKAGING_METADATA: u32 = 0xcafe1a7e;
 pub const EF_CPU32: u32 = 0x00810000;
 pub const R_68K_NONE: u8 = 0;
 pub const R_68K_32: u8 = 1;
@@ -509,6 +679,7 @@ pub const R_386_TLS_GOTDESC: u8 = 39;
 pub const R_386_TLS_DESC_CALL: u8 = 40;
 pub const R_386_TLS_DESC: u8 = 41;
 pub const R_386_IRELATIVE: u8 = 42;
+pub const R_386_GOT32X: u8 = 43;
 pub const STT_SPARC_REGISTER: u8 = 13;
 pub const EF_SPARCV9_MM: u8 = 3;
 pub const EF_SPARCV9_TSO: u8 = 0;
@@ -620,28 +791,47 @@ pub const HWCAP_SPARC_V9: u8 = 16;
 pub const HWCAP_SPARC_ULTRA3: u8 = 32;
 pub const HWCAP_SPARC_BLKINIT: u8 = 64;
 pub const HWCAP_SPARC_N2: u8 = 128;
-pub const EF_MIPS_NOREORDER: u8 = 1;
-pub const EF_MIPS_PIC: u8 = 2;
-pub const EF_MIPS_CPIC: u8 = 4;
-pub const EF_MIPS_XGOT: u8 = 8;
-pub const EF_MIPS_64BIT_WHIRL: u8 = 16;
-pub const EF_MIPS_ABI2: u8 = 32;
-pub const EF_MIPS_ABI_ON32: u8 = 64;
+pub const EF_MIPS_NOREORDER: u32 = 1;
+pub const EF_MIPS_PIC: u32 = 2;
+pub const EF_MIPS_CPIC: u32 = 4;
+pub const EF_MIPS_XGOT: u32 = 8;
+pub const EF_MIPS_64BIT_WHIRL: u32 = 16;
+pub const EF_MIPS_ABI2: u32 = 32;
+pub const EF_MIPS_ABI_ON32: u32 = 64;
+pub const EF_MIPS_FP64: u32 = 512;
+pub const EF_MIPS_NAN2008: u32 = 1024;
+pub const EF_MIPS_ABI: u32 = 0x0000f000;
+pub const E_MIPS_ABI_O32: u32 = 0x00001000;
+pub const E_MIPS_ABI_O64: u32 = 0x00002000;
+pub const E_MIPS_ABI_EABI32: u32 = 0x00003000;
+pub const E_MIPS_ABI_EABI64: u32 = 0x00004000;
+pub const EF_MIPS_ARCH_ASE: u32 = 0x0f000000;
+pub const EF_MIPS_ARCH_ASE_MDMX: u32 = 0x08000000;
+pub const EF_MIPS_ARCH_ASE_M16: u32 = 0x04000000;
+pub const EF_MIPS_MICROMIPS: u32 = 0x02000000;
 pub const EF_MIPS_ARCH: u32 = 0xf0000000;
 pub const EF_MIPS_ARCH_1: u32 = 0x00000000;
 pub const EF_MIPS_ARCH_2: u32 = 0x10000000;
 pub const EF_MIPS_ARCH_3: u32 = 0x20000000;
 pub const EF_MIPS_ARCH_4: u32 = 0x30000000;
 pub const EF_MIPS_ARCH_5: u32 = 0x40000000;
-pub const EF_MIPS_ARCH_32: u32 = 0x60000000;
-pub const EF_MIPS_ARCH_64: u32 = 0x70000000;
+pub const EF_MIPS_ARCH_32: u32 = 0x50000000;
+pub const EF_MIPS_ARCH_64: u32 = 0x60000000;
+pub const EF_MIPS_ARCH_32R2: u32 = 0x70000000;
+pub const EF_MIPS_ARCH_64R2: u32 = 0x80000000;
+pub const EF_MIPS_ARCH_32R6: u32 = 0x90000000;
+pub const EF_MIPS_ARCH_64R6: u32 = 0xa0000000;
 pub const E_MIPS_ARCH_1: u32 = 0x00000000;
 pub const E_MIPS_ARCH_2: u32 = 0x10000000;
 pub const E_MIPS_ARCH_3: u32 = 0x20000000;
 pub const E_MIPS_ARCH_4: u32 = 0x30000000;
 pub const E_MIPS_ARCH_5: u32 = 0x40000000;
-pub const E_MIPS_ARCH_32: u32 = 0x60000000;
-pub const E_MIPS_ARCH_64: u32 = 0x70000000;
+pub const E_MIPS_ARCH_32: u32 = 0x50000000;
+pub const E_MIPS_ARCH_64: u32 = 0x60000000;
+pub const E_MIPS_ARCH_32R2: u32 = 0x70000000;
+pub const E_MIPS_ARCH_64R2: u32 = 0x80000000;
+pub const E_MIPS_ARCH_32R6: u32 = 0x90000000;
+pub const E_MIPS_ARCH_64R6: u32 = 0xa0000000;
 pub const SHN_MIPS_ACOMMON: u32 = 0xff00;
 pub const SHN_MIPS_TEXT: u32 = 0xff01;
 pub const SHN_MIPS_DATA: u32 = 0xff02;
@@ -1292,6 +1482,8 @@ pub const EF_ARM_EABI_VER2: u32 = 0x02000000;
 pub const EF_ARM_EABI_VER3: u32 = 0x03000000;
 pub const EF_ARM_EABI_VER4: u32 = 0x04000000;
 pub const EF_ARM_EABI_VER5: u32 = 0x05000000;
+pub const EF_ARM_ABI_FLOAT_SOFT: u32 = 0x200;
+pub const EF_ARM_ABI_FLOAT_HARD: u32 = 0x400;
 pub const STT_ARM_TFUNC: u8 = STT_LOPROC;
 pub const STT_ARM_16BIT: u8 = STT_HIPROC;
 pub const SHF_ARM_ENTRYSECT: u32 = 0x10000000;
@@ -1378,7 +1570,7 @@ pub const R_IA64_DIR64LSB: u32 = 0x27;
 pub const R_IA64_GPREL22: u32 = 0x2a;
 pub const R_IA64_GPREL64I: u32 = 0x2b;
 pub const R_IA64_GPREL32MSB: u32 = 0x2c;
-pub const R_IA64_GPREL32LSB: u32 = 0x2;
+pub const R_IA64_GPREL32LSB: u32 = 0x2d;
 pub const R_IA64_GPREL64MSB: u32 = 0x2e;
 pub const R_IA64_GPREL64LSB: u32 = 0x2f;
 pub const R_IA64_LTOFF22: u32 = 0x32;
@@ -1593,10 +1785,20 @@ pub const R_X86_64_TPOFF32: u8 = 23;
 pub const R_X86_64_PC64: u8 = 24;
 pub const R_X86_64_GOTOFF64: u8 = 25;
 pub const R_X86_64_GOTPC32: u8 = 26;
+pub const R_X86_64_GOT64: u8 = 27;
+pub const R_X86_64_GOTPCREL64: u8 = 28;
+pub const R_X86_64_GOTPC64: u8 = 29;
+pub const R_X86_64_GOTPLT64: u8 = 30;
+pub const R_X86_64_PLTOFF64: u8 = 31;
+pub const R_X86_64_SIZE32: u8 = 32;
+pub const R_X86_64_SIZE64: u8 = 33;
 pub const R_X86_64_GOTPC32_TLSDESC: u8 = 34;
 pub const R_X86_64_TLSDESC_CALL: u8 = 35;
 pub const R_X86_64_TLSDESC: u8 = 36;
 pub const R_X86_64_IRELATIVE: u8 = 37;
+pub const R_X86_64_RELATIVE64: u8 = 38;
+pub const R_X86_64_GOTPCRELX: u8 = 41;
+pub const R_X86_64_REX_GOTPCRELX: u8 = 42;
 pub const R_MN10300_NONE: u8 = 0;
 pub const R_MN10300_32: u8 = 1;
 pub const R_MN10300_16: u8 = 2;
@@ -1664,3 +1866,20 @@ pub const R_M32R_GOTPC_LO: u8 = 61;
 pub const R_M32R_GOTOFF_HI_ULO: u8 = 62;
 pub const R_M32R_GOTOFF_HI_SLO: u8 = 63;
 pub const R_M32R_GOTOFF_LO: u8 = 64;
+pub const R_AARCH64_ABS64: u32 = 257;
+pub const R_AARCH64_ABS32: u32 = 258;
+pub const R_AARCH64_PREL64: u32 = 260;
+pub const R_AARCH64_PREL32: u32 = 261;
+pub const R_AARCH64_RELATIVE: u32 = 1027;
+pub const EF_RISCV_RVC: u32 = 0x0001;
+pub const EF_RISCV_FLOAT_ABI: u32 = 0x0006;
+pub const EF_RISCV_FLOAT_ABI_SOFT: u32 = 0x0000;
+pub const EF_RISCV_FLOAT_ABI_SINGLE: u32 = 0x0002;
+pub const EF_RISCV_FLOAT_ABI_DOUBLE: u32 = 0x0004;
+pub const EF_RISCV_FLOAT_ABI_QUAD: u32 = 0x0006;
+pub const EF_RISCV_RVE: u32 = 0x0008;
+pub const EF_RISCV_TSO: u32 = 0x0010;
+pub const R_RISCV_32: u8 = 1;
+pub const R_RISCV_64: u8 = 2;
+pub const R_RISCV_RELATIVE: u8 = 3;
+pub const R_RISCV_32_PCREL: u8 = 57;
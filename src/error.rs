@@ -41,4 +41,18 @@ impl std::fmt::Display for Error {
     }
 }
 
+impl Error {
+    // the message alone, without a backtrace or the path being parsed, for errors shown
+    // as part of the output
+    pub fn kind(&self) -> String {
+        match self {
+            Self::Cli(msg) => msg.clone(),
+            Self::Io(err, _) => format!("IO error: {err}"),
+            Self::Seek(count) => format!("Seeked too many bytes ({count})"),
+            Self::UnknownFormat(..) => "Unknown format".into(),
+            Self::RunCtx(_, err) => err.kind(),
+        }
+    }
+}
+
 pub type Res<T> = Result<T, Error>;
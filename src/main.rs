@@ -1,3 +1,4 @@
+pub mod dwarf_header;
 pub mod elf_header;
 pub mod error;
 pub mod parse;
@@ -7,9 +8,24 @@ use std::io::Write;
 
 const CURSOR_SIZE_LIMIT: u64 = 32 * 1024 * 1024;
 
+fn parse_address(arg: &[u8]) -> Res<u64> {
+    let invalid = || {
+        Error::Cli(format!(
+            "Invalid address '{}'",
+            String::from_utf8_lossy(arg)
+        ))
+    };
+    let arg = std::str::from_utf8(arg).map_err(|_| invalid())?;
+    match arg.strip_prefix("0x").or_else(|| arg.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => arg.parse(),
+    }
+    .map_err(|_| invalid())
+}
+
 struct Args {
     help: bool,
-    all: bool,
+    options: parse::Options,
     file_paths: Box<[std::path::PathBuf]>,
 }
 
@@ -21,16 +37,107 @@ impl TryFrom<std::env::ArgsOs> for Args {
         let mut args = args.peekable();
         let mut help = false;
         let mut all = false;
+        let mut security = false;
+        let mut dwarf = false;
+        let mut unwind = false;
+        let mut addresses = vec![];
+        let mut dependencies = false;
+        let mut sysroot = None;
+        let mut library_path = vec![];
+        let mut strings = false;
+        let mut min_length = parse::strings::DEFAULT_MIN_LENGTH;
+        let mut dump = None;
+        let mut output = None;
+        let mut disassemble = vec![];
+        let mut symbol_lookups = vec![];
         while let Some(arg) = args.peek() {
-            let arg = arg.as_encoded_bytes();
+            let arg = arg.as_encoded_bytes().to_vec();
             if arg.starts_with(b"--") {
-                match arg {
-                    b"--help" => help = true,
-                    b"--all" => all = true,
+                // options with a value take it after `=` or from the next argument
+                let (name, inline_value) = match arg.iter().position(|&byte| byte == b'=') {
+                    Some(position) => (&arg[..position], Some(&arg[position + 1..])),
+                    None => (&arg[..], None),
+                };
+                let mut value = || -> Res<std::ffi::OsString> {
+                    if let Some(value) = inline_value {
+                        return std::str::from_utf8(value)
+                            .map(Into::into)
+                            .map_err(|_| Error::Cli("Invalid argument".into()));
+                    }
+                    args.next();
+                    args.peek().cloned().ok_or_else(|| {
+                        Error::Cli(format!(
+                            "Missing value after '{}'",
+                            String::from_utf8_lossy(name)
+                        ))
+                    })
+                };
+                match (name, inline_value) {
+                    (b"--help", None) => help = true,
+                    (b"--all", None) => all = true,
+                    (b"--security", None) => security = true,
+                    (b"--dwarf", None) => dwarf = true,
+                    (b"--unwind", None) => unwind = true,
+                    (b"--libraries", None) => dependencies = true,
+                    (b"--strings", None) => strings = true,
+                    (b"--addr", _) => addresses.push(parse_address(value()?.as_encoded_bytes())?),
+                    (b"--sysroot", _) => sysroot = Some(value()?.into()),
+                    (b"--min-length", _) => {
+                        min_length = value()?
+                            .to_str()
+                            .and_then(|value| value.parse().ok())
+                            .ok_or_else(|| Error::Cli("Invalid minimum string length".into()))?;
+                    }
+                    (b"--library-path", _) => library_path.extend(std::env::split_paths(&value()?)),
+                    (b"--dump-section", _) => {
+                        dump = Some(parse::Dump::Section(
+                            value()?
+                                .into_string()
+                                .map_err(|_| Error::Cli("Invalid section name".into()))?,
+                        ));
+                    }
+                    (b"--dump-segment", _) => {
+                        dump = Some(parse::Dump::Segment(
+                            value()?
+                                .to_str()
+                                .and_then(|value| value.parse().ok())
+                                .ok_or_else(|| Error::Cli("Invalid segment number".into()))?,
+                        ));
+                    }
+                    (b"--dump-chunk", _) => {
+                        dump = Some(parse::Dump::Chunk(
+                            value()?
+                                .into_string()
+                                .ok()
+                                .filter(|value| value.len() == 4)
+                                .ok_or_else(|| Error::Cli("Invalid chunk type".into()))?,
+                        ));
+                    }
+                    (b"--output", _) => output = Some(value()?.into()),
+                    (b"--disasm", _) => {
+                        let value = value()?;
+                        let value = value.as_encoded_bytes();
+                        disassemble.push(if value == b"entry" {
+                            parse::Target::Entry
+                        } else if let Ok(address) = parse_address(value) {
+                            parse::Target::Address(address)
+                        } else {
+                            parse::Target::Symbol(
+                                std::str::from_utf8(value)
+                                    .map_err(|_| Error::Cli("Invalid symbol name".into()))?
+                                    .into(),
+                            )
+                        });
+                    }
+                    (b"--lookup", _) => symbol_lookups.push(
+                        value()?
+                            .into_string()
+                            .map_err(|_| Error::Cli("Invalid symbol name".into()))?,
+                    ),
                     _ => {
                         return Err(Error::Cli(format!(
                             "Unknown argument '{}'",
-                            std::str::from_utf8(arg)
+                            std::str::from_utf8(&arg)
                                 .map_err(|_| Error::Cli("Invalid argument".into()))?
                         )));
                     }
@@ -40,6 +147,10 @@ impl TryFrom<std::env::ArgsOs> for Args {
                     match small_arg {
                         b'h' => help = true,
                         b'a' => all = true,
+                        b's' => security = true,
+                        b'd' => dwarf = true,
+                        b'u' => unwind = true,
+                        b'l' => dependencies = true,
                         _ => {
                             return Err(Error::Cli(format!(
                                 "Unknown argument '{}'",
@@ -51,6 +162,10 @@ impl TryFrom<std::env::ArgsOs> for Args {
                 }
                 help |= arg.contains(&b'h');
                 all |= arg.contains(&b'a');
+                security |= arg.contains(&b's');
+                dwarf |= arg.contains(&b'd');
+                unwind |= arg.contains(&b'u');
+                dependencies |= arg.contains(&b'l');
             } else {
                 break;
             }
@@ -59,7 +174,21 @@ impl TryFrom<std::env::ArgsOs> for Args {
         help |= args.peek().is_none();
         Ok(Self {
             help,
-            all,
+            options: parse::Options {
+                all,
+                security,
+                dwarf,
+                unwind,
+                addresses,
+                dependencies,
+                sysroot,
+                library_path,
+                strings: strings.then_some(min_length),
+                dump,
+                output,
+                disassemble,
+                symbol_lookups,
+            },
             file_paths: args.map(Into::into).collect(),
         })
     }
@@ -72,8 +201,23 @@ fn run(args: Args) -> Res<()> {
 Usage: inspector [options] paths...
 
 Options:
-    -h, --help    Display help
-    -a, --all     Show all file metadata
+    -h, --help              Display help
+    -a, --all               Show all file metadata
+    -s, --security          Show binary hardening checks
+    -d, --dwarf             Summarize DWARF debug information
+    -u, --unwind            Show .eh_frame unwind tables
+    -l, --libraries         Resolve the shared library dependency tree without running the file
+    --addr <address>        Resolve an address to its segment, section, symbol and source line
+    --sysroot <dir>         Look up libraries under another root directory
+    --library-path <dirs>   Search these directories like LD_LIBRARY_PATH
+    --strings               List printable ASCII, UTF-8 and UTF-16LE strings with their location
+    --min-length <count>    Shortest string listed by --strings (default 4)
+    --dump-section <name>   Hex dump the bytes of an ELF section
+    --dump-segment <number> Hex dump the bytes of an ELF program segment, numbered from 1
+    --dump-chunk <type>     Hex dump the data of all PNG chunks of a type, such as tEXt
+    --output <file>         Write the dumped bytes to a file instead
+    --disasm <target>       Disassemble x86-64 or AArch64 code at a symbol, an address or entry
+    --lookup <name>         Look a dynamic symbol up through the hash tables like the loader does
 "
         );
         return Ok(());
@@ -93,19 +237,18 @@ Options:
     };
 
     for file_path in &args.file_paths {
-        let Ok(meta) = std::fs::metadata(&file_path) else {
+        let Ok(meta) = std::fs::metadata(file_path) else {
             eprintln!("Failed to stat '{}'", file_path.display());
             continue;
         };
         let table = if meta.len() > CURSOR_SIZE_LIMIT {
-            println!("foo");
-            let file = std::io::BufReader::new(std::fs::File::open(&file_path)?);
-            write_path(&mut stdout, &file_path)?;
-            parse::start(file, args.all)
+            let file = std::io::BufReader::new(std::fs::File::open(file_path)?);
+            write_path(&mut stdout, file_path)?;
+            parse::start(file, file_path, &args.options)
         } else {
-            let contents = std::io::Cursor::new(std::fs::read(&file_path)?);
-            write_path(&mut stdout, &file_path)?;
-            parse::start(contents, args.all)
+            let contents = std::io::Cursor::new(std::fs::read(file_path)?);
+            write_path(&mut stdout, file_path)?;
+            parse::start(contents, file_path, &args.options)
         }
         .map_err(|err| Error::RunCtx(file_path.into(), Box::new(err)))?;
         table.display(&mut stdout)?;
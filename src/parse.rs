@@ -1,5 +1,10 @@
+mod ar;
+mod dump;
 mod elf;
+mod inflate;
 mod png;
+pub mod strings;
+mod zstd;
 
 use crate::error::{Error, Res};
 
@@ -62,7 +67,7 @@ impl<const N: usize> Pull for [u8; N] {
     }
 }
 
-#[derive(Default)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
 pub enum Endianness {
     #[default]
     Little,
@@ -122,7 +127,7 @@ pub struct Table {
 #[derive(Default)]
 struct Section {
     name: Option<Str>,
-    len: u16,
+    len: usize,
     width: u16,
 }
 
@@ -147,7 +152,7 @@ impl Table {
             } else {
                 writeln!(target)?;
             }
-            for (key, value) in entries_iter.by_ref().take(section.len.into()) {
+            for (key, value) in entries_iter.by_ref().take(section.len) {
                 write!(target, "{key}:")?;
                 target.write_all(&[b' '; 100][..section.width as usize - key.len() + 1])?;
                 writeln!(target, "{value}")?;
@@ -176,14 +181,60 @@ impl Table {
     pub fn new_unnamed_section(&mut self) {
         self.sections.push(Default::default());
     }
+
+    // entries before the first section of the other table join the current section, and
+    // its later sections are named after where they came from
+    pub fn append(&mut self, other: Table, origin: &str) {
+        let mut entries = other.entries.into_iter();
+        for (i, section) in other.sections.into_iter().enumerate() {
+            if i > 0 {
+                match section.name {
+                    Some(name) => self.new_named_section(format!("{name} in {origin}")),
+                    None => self.new_unnamed_section(),
+                }
+            }
+            for (key, value) in entries.by_ref().take(section.len) {
+                self.add_entry(key, value);
+            }
+        }
+    }
+}
+
+pub enum Dump {
+    Section(String),
+    Segment(usize),
+    Chunk(String),
+}
+
+pub enum Target {
+    Symbol(String),
+    Address(u64),
+    Entry,
+}
+
+#[derive(Default)]
+pub struct Options {
+    pub all: bool,
+    pub security: bool,
+    pub dwarf: bool,
+    pub unwind: bool,
+    pub addresses: Vec<u64>,
+    pub dependencies: bool,
+    pub sysroot: Option<std::path::PathBuf>,
+    pub library_path: Vec<std::path::PathBuf>,
+    pub strings: Option<usize>,
+    pub dump: Option<Dump>,
+    pub output: Option<std::path::PathBuf>,
+    pub disassemble: Vec<Target>,
+    pub symbol_lookups: Vec<String>,
 }
 
-pub fn start<B: Bytes>(mut bytes: B, all: bool) -> Res<Table> {
+pub fn start<B: Bytes>(mut bytes: B, path: &std::path::Path, options: &Options) -> Res<Table> {
     macro_rules! try_parse {
         ($mod:ident) => {
             if $mod::matching_magic(&mut bytes)? {
                 bytes.rewind()?;
-                return $mod::Parser::default().parse(bytes, all);
+                return $mod::Parser::default().parse(bytes, path, options);
             }
             bytes.rewind()?;
         };
@@ -191,6 +242,7 @@ pub fn start<B: Bytes>(mut bytes: B, all: bool) -> Res<Table> {
 
     try_parse!(elf);
     try_parse!(png);
+    try_parse!(ar);
     // add parse modules here
 
     unknown!();
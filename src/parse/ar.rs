@@ -0,0 +1,310 @@
+use crate::{
+    error::{Error, Res},
+    parse::{Bytes, Endianness, Options, Table},
+    unknown,
+};
+
+const MAGIC: [u8; 8] = *b"!<arch>\n";
+const HEADER_END: [u8; 2] = *b"`\n";
+const HEADER_SIZE: u64 = 60;
+
+pub fn matching_magic(bytes: &mut impl Bytes) -> Res<bool> {
+    Ok(bytes.pull::<[_; _]>()? == MAGIC)
+}
+
+struct Member {
+    name: String,
+    // where the member header starts, which is what symbol indexes point to
+    offset: u64,
+    data_offset: u64,
+    size: u64,
+    timestamp: u64,
+    uid: u64,
+    gid: u64,
+    mode: u64,
+}
+
+#[derive(Default)]
+enum Variant {
+    #[default]
+    SystemV,
+    Gnu,
+    Bsd,
+}
+
+#[derive(Default)]
+pub struct Parser {
+    variant: Variant,
+    members: Vec<Member>,
+    // names with the offset of the member header defining them
+    symbols: Option<Vec<(String, u64)>>,
+    long_names: Vec<u8>,
+}
+
+// header fields are ASCII numbers padded with spaces, and left blank by some tools
+fn field(raw: &[u8], radix: u32) -> Res<u64> {
+    let Ok(text) = std::str::from_utf8(raw) else {
+        unknown!();
+    };
+    match text.trim_end_matches([' ', '\0']) {
+        "" => Ok(0),
+        text => match u64::from_str_radix(text, radix) {
+            Ok(value) => Ok(value),
+            Err(_) => unknown!(),
+        },
+    }
+}
+
+fn c_strings(data: &[u8]) -> impl Iterator<Item = String> {
+    data.split(|&byte| byte == 0)
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+}
+
+// seconds since the Unix epoch as a UTC date, converting days to a civil date as in
+// Howard Hinnant's chrono algorithms
+fn fmt_timestamp(seconds: u64) -> String {
+    let (days, time) = (seconds / 86400, seconds % 86400);
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+impl Parser {
+    pub fn parse(
+        mut self,
+        mut bytes: impl Bytes,
+        path: &std::path::Path,
+        options: &Options,
+    ) -> Res<Table> {
+        let mut table = Table::default();
+        self.read_members(&mut bytes)?;
+        table.add_entry(
+            "Format",
+            match self.variant {
+                Variant::SystemV => "System V",
+                Variant::Gnu => "GNU",
+                Variant::Bsd => "BSD",
+            },
+        );
+        table.add_entry("Members", self.members.len().to_string());
+        table.add_entry(
+            "Symbol Index",
+            match &self.symbols {
+                Some(symbols) => format!("{} symbols", symbols.len()),
+                None => "None".into(),
+            },
+        );
+
+        let count = self.members.len();
+        for (i, member) in self.members.iter().enumerate() {
+            table.new_named_section(format!("Member {}/{count} '{}'", i + 1, member.name));
+            table.add_entry("Offset", format!("0x{:X}", member.offset));
+            table.add_entry("Size", format!("{} bytes", member.size));
+            table.add_entry("Modified", fmt_timestamp(member.timestamp));
+            table.add_entry("Owner", format!("uid {}, gid {}", member.uid, member.gid));
+            table.add_entry("Mode", format!("{:o}", member.mode));
+            if let Some(symbols) = &self.symbols {
+                let indexed = symbols
+                    .iter()
+                    .filter(|(_, offset)| *offset == member.offset)
+                    .count();
+                table.add_entry("Indexed Symbols", indexed.to_string());
+            }
+
+            let data = self.member_data(&mut bytes, member)?;
+            // members are parsed like files of their own, so object files get the ELF parser
+            match super::start(std::io::Cursor::new(data), path, options) {
+                Ok(member_table) => table.append(member_table, &format!("'{}'", member.name)),
+                Err(Error::UnknownFormat(..)) => table.add_entry("Contents", "Unknown format"),
+                Err(err) => table.add_entry("Error", err.kind()),
+            }
+        }
+
+        if options.all
+            && let Some(symbols) = &self.symbols
+        {
+            table.new_named_section(format!("Symbol Index ({} symbols)", symbols.len()));
+            for (i, (name, offset)) in symbols.iter().enumerate() {
+                let member = match self.members.iter().find(|member| member.offset == *offset) {
+                    Some(member) => format!("'{}'", member.name),
+                    None => format!("unknown member at 0x{offset:X}"),
+                };
+                table.add_entry(format!("Symbol {i}"), format!("{name} in {member}"));
+            }
+        }
+        Ok(table)
+    }
+
+    fn read_members(&mut self, bytes: &mut impl Bytes) -> Res<()> {
+        let len = bytes.seek(std::io::SeekFrom::End(0))?;
+        let mut offset = MAGIC.len() as u64;
+        loop {
+            // member headers start at even offsets, after a newline padding odd sized data
+            offset += offset % 2;
+            bytes.jump(offset)?;
+            if bytes.fill_buf()?.is_empty() {
+                return Ok(());
+            }
+            let name: [u8; 16] = bytes.pull()?;
+            let timestamp = field(&bytes.pull::<[_; 12]>()?, 10)?;
+            let uid = field(&bytes.pull::<[_; 6]>()?, 10)?;
+            let gid = field(&bytes.pull::<[_; 6]>()?, 10)?;
+            let mode = field(&bytes.pull::<[_; 8]>()?, 8)?;
+            let size = field(&bytes.pull::<[_; 10]>()?, 10)?;
+            if bytes.pull::<[_; 2]>()? != HEADER_END {
+                unknown!();
+            }
+            let mut member = Member {
+                name: String::new(),
+                offset,
+                data_offset: offset + HEADER_SIZE,
+                size,
+                timestamp,
+                uid,
+                gid,
+                mode,
+            };
+            // sizes come straight from the header, so check them before reading any data
+            if size > len.saturating_sub(member.data_offset) {
+                unknown!();
+            }
+            offset = member.data_offset + size;
+
+            let name = String::from_utf8_lossy(&name);
+            let name = name.trim_end_matches(' ');
+            match name {
+                "/" | "/SYM64/" => {
+                    let word_size = if name == "/" { 4 } else { 8 };
+                    let data = self.member_data(bytes, &member)?;
+                    self.symbols = Some(gnu_symbol_index(&data, word_size)?);
+                    continue;
+                }
+                "//" => {
+                    self.variant = Variant::Gnu;
+                    self.long_names = self.member_data(bytes, &member)?;
+                    continue;
+                }
+                _ => {}
+            }
+            member.name = if let Some(long_offset) = name.strip_prefix('/') {
+                // GNU names too long for the header are kept in the "//" member
+                let Some(long_name) = field(long_offset.as_bytes(), 10)
+                    .ok()
+                    .and_then(|long_offset| self.long_names.get(long_offset as usize..))
+                else {
+                    unknown!();
+                };
+                let end = long_name
+                    .iter()
+                    .position(|&byte| byte == b'\n')
+                    .unwrap_or(long_name.len());
+                let long_name = String::from_utf8_lossy(&long_name[..end]);
+                long_name.trim_end_matches('/').into()
+            } else if let Some(len) = name.strip_prefix("#1/") {
+                // BSD names too long for the header, or with spaces, come before the data
+                self.variant = Variant::Bsd;
+                let len = field(len.as_bytes(), 10)?;
+                if len > size {
+                    unknown!();
+                }
+                member.data_offset += len;
+                member.size -= len;
+                let mut long_name = vec![0; len as usize];
+                bytes.read_exact(&mut long_name)?;
+                String::from_utf8_lossy(&long_name)
+                    .trim_end_matches('\0')
+                    .into()
+            } else if let Some(short_name) = name.strip_suffix('/') {
+                self.variant = Variant::Gnu;
+                short_name.into()
+            } else {
+                name.into()
+            };
+
+            if let Some(kind) = member.name.strip_prefix("__.SYMDEF") {
+                self.variant = Variant::Bsd;
+                let word_size = if kind.starts_with("_64") { 8 } else { 4 };
+                let data = self.member_data(bytes, &member)?;
+                self.symbols = Some(bsd_symbol_index(&data, word_size)?);
+                continue;
+            }
+            self.members.push(member);
+        }
+    }
+
+    fn member_data(&self, bytes: &mut impl Bytes, member: &Member) -> Res<Vec<u8>> {
+        bytes.jump(member.data_offset)?;
+        let mut data = vec![0; member.size as usize];
+        bytes.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+fn pull_word(bytes: &mut impl Bytes, word_size: usize, endianness: Endianness) -> Res<u64> {
+    Ok(match word_size {
+        4 => bytes.pull_via::<u32>(endianness)?.into(),
+        _ => bytes.pull_via::<u64>(endianness)?,
+    })
+}
+
+// a big-endian count, the member offset of each symbol and then their names in order
+fn gnu_symbol_index(data: &[u8], word_size: usize) -> Res<Vec<(String, u64)>> {
+    let mut bytes = std::io::Cursor::new(data);
+    let count = pull_word(&mut bytes, word_size, Endianness::Big)? as usize;
+    let names_start = count
+        .checked_add(1)
+        .and_then(|words| words.checked_mul(word_size))
+        .filter(|&start| start <= data.len());
+    let Some(names_start) = names_start else {
+        unknown!();
+    };
+    let offsets = (0..count)
+        .map(|_| pull_word(&mut bytes, word_size, Endianness::Big))
+        .collect::<Res<Vec<_>>>()?;
+    Ok(c_strings(&data[names_start..]).zip(offsets).collect())
+}
+
+// ranlib entries pairing a name offset with a member offset, then the names, all in the
+// byte order of the machine that wrote them which is little-endian for any current one
+fn bsd_symbol_index(data: &[u8], word_size: usize) -> Res<Vec<(String, u64)>> {
+    let mut bytes = std::io::Cursor::new(data);
+    let entries_size = pull_word(&mut bytes, word_size, Endianness::Little)?;
+    let mut entries = vec![];
+    for _ in 0..entries_size / (2 * word_size as u64) {
+        let name = pull_word(&mut bytes, word_size, Endianness::Little)?;
+        let offset = pull_word(&mut bytes, word_size, Endianness::Little)?;
+        entries.push((name, offset));
+    }
+    let names_size = pull_word(&mut bytes, word_size, Endianness::Little)? as usize;
+    let names_start = bytes.position() as usize;
+    let names = names_start
+        .checked_add(names_size)
+        .and_then(|names_end| data.get(names_start..names_end));
+    let Some(names) = names else {
+        unknown!();
+    };
+    Ok(entries
+        .into_iter()
+        .map(|(name, offset)| {
+            let name = names.get(name as usize..).unwrap_or_default();
+            (c_strings(name).next().unwrap_or_default(), offset)
+        })
+        .collect())
+}
@@ -0,0 +1,40 @@
+use crate::{error::Res, parse::Table};
+
+const BYTES_PER_LINE: usize = 16;
+
+// hex dump of some contents keyed by address, or their raw bytes written to `output`
+pub fn contents(
+    table: &mut Table,
+    title: String,
+    data: &[u8],
+    base: u64,
+    output: Option<&std::path::Path>,
+) -> Res<()> {
+    table.new_named_section(format!("{title} ({} bytes)", data.len()));
+    if let Some(output) = output {
+        std::fs::write(output, data)?;
+        table.add_entry("Written To", output.display().to_string());
+        return Ok(());
+    }
+    for (i, line) in data.chunks(BYTES_PER_LINE).enumerate() {
+        let mut hex = String::new();
+        for (j, byte) in line.iter().enumerate() {
+            if j == BYTES_PER_LINE / 2 {
+                hex.push(' ');
+            }
+            hex += &format!("{byte:02x} ");
+        }
+        let ascii: String = line
+            .iter()
+            .map(|&byte| match byte {
+                0x20..=0x7e => byte.into(),
+                _ => '.',
+            })
+            .collect();
+        table.add_entry(
+            format!("0x{:X}", base.wrapping_add((i * BYTES_PER_LINE) as u64)),
+            format!("{hex:<width$}|{ascii}|", width = BYTES_PER_LINE * 3 + 1),
+        );
+    }
+    Ok(())
+}
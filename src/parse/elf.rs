@@ -1,24 +1,42 @@
 use crate::{
     elf_header::*,
     error::{Error, Res},
-    parse::{Bytes, Pull, Str, Table},
+    parse::{Bytes, Dump, Endianness, Options, Pull, Str, Table, dump, inflate, strings, zstd},
     unknown,
 };
 
+mod coredump;
+mod dependencies;
+mod disasm;
+mod dwarf;
+mod eflags;
+mod eh_frame;
+mod hash;
+mod init;
+mod lookup;
+mod machine;
+mod reloc;
+
 const MAGIC: [u8; 4] = [ELFMAG0, ELFMAG1, ELFMAG2, ELFMAG3];
 
 pub fn matching_magic(bytes: &mut impl Bytes) -> Res<bool> {
     Ok(bytes.pull::<[_; _]>()? == MAGIC)
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum WordSize {
     Four,
     Eight,
 }
 
+#[derive(Copy, Clone, Debug)]
+struct Encoding {
+    word_size: WordSize,
+    endianness: Endianness,
+}
+
 #[repr(u32)]
-#[derive(Debug)]
+#[derive(PartialEq, Debug)]
 enum SegmentType {
     Null = PT_NULL,
     Load = PT_LOAD,
@@ -35,10 +53,10 @@ enum SegmentType {
 }
 
 impl Pull for SegmentType {
-    type Format = ();
+    type Format = Endianness;
 
-    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, _: Self::Format) -> Res<Self> {
-        Ok(match bytes.pull()? {
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        Ok(match bytes.pull_via(endianness)? {
             PT_NULL => Self::Null,
             PT_LOAD => Self::Load,
             PT_DYNAMIC => Self::Dynamic,
@@ -60,32 +78,89 @@ struct ProgramHeader {
     r#type: SegmentType,
     flags: u32,
     offset: u64,
+    vaddr: u64,
+    paddr: u64,
+    filesz: u64,
+    memsz: u64,
+    align: u64,
 }
 
 impl Pull for ProgramHeader {
-    type Format = WordSize;
+    type Format = Encoding;
 
-    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, word_size: Self::Format) -> Res<Self> {
-        let r#type = bytes.pull()?;
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        Encoding {
+            word_size,
+            endianness,
+        }: Self::Format,
+    ) -> Res<Self> {
+        let r#type = bytes.pull_via(endianness)?;
         let flags;
         let offset;
+        let vaddr;
+        let paddr;
+        let filesz;
+        let memsz;
+        let align;
         match word_size {
             WordSize::Four => {
-                offset = bytes.pull::<u32>()?.into();
-                bytes.forward_sizeof::<[u32; 4]>()?;
-                flags = bytes.pull()?;
-                bytes.forward_sizeof::<u32>()?;
+                offset = bytes.pull_via::<u32>(endianness)?.into();
+                vaddr = bytes.pull_via::<u32>(endianness)?.into();
+                paddr = bytes.pull_via::<u32>(endianness)?.into();
+                filesz = bytes.pull_via::<u32>(endianness)?.into();
+                memsz = bytes.pull_via::<u32>(endianness)?.into();
+                flags = bytes.pull_via(endianness)?;
+                align = bytes.pull_via::<u32>(endianness)?.into();
             }
             WordSize::Eight => {
-                flags = bytes.pull()?;
-                offset = bytes.pull()?;
-                bytes.forward_sizeof::<[u64; 5]>()?;
+                flags = bytes.pull_via(endianness)?;
+                offset = bytes.pull_via(endianness)?;
+                vaddr = bytes.pull_via(endianness)?;
+                paddr = bytes.pull_via(endianness)?;
+                filesz = bytes.pull_via(endianness)?;
+                memsz = bytes.pull_via(endianness)?;
+                align = bytes.pull_via(endianness)?;
             }
         }
         Ok(Self {
             r#type,
             flags,
             offset,
+            vaddr,
+            paddr,
+            filesz,
+            memsz,
+            align,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct DynamicEntry {
+    tag: u64,
+    value: u64,
+}
+
+impl Pull for DynamicEntry {
+    type Format = Encoding;
+
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        Encoding {
+            word_size,
+            endianness,
+        }: Self::Format,
+    ) -> Res<Self> {
+        Ok(match word_size {
+            WordSize::Four => Self {
+                tag: bytes.pull_via::<u32>(endianness)?.into(),
+                value: bytes.pull_via::<u32>(endianness)?.into(),
+            },
+            WordSize::Eight => Self {
+                tag: bytes.pull_via(endianness)?,
+                value: bytes.pull_via(endianness)?,
+            },
         })
     }
 }
@@ -121,10 +196,10 @@ enum SectionType {
 }
 
 impl Pull for SectionType {
-    type Format = ();
+    type Format = Endianness;
 
-    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, _: Self::Format) -> Res<Self> {
-        Ok(match bytes.pull()? {
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        Ok(match bytes.pull_via(endianness)? {
             SHT_NULL => Self::Null,
             SHT_PROGBITS => Self::ProgBits,
             SHT_SYMTAB => Self::SymTab,
@@ -159,59 +234,423 @@ struct SectionHeader {
     name: u32,
     r#type: SectionType,
     flags: u64,
+    addr: u64,
     offset: u64,
     size: u64,
+    link: u32,
+    info: u32,
+    addralign: u64,
+    entsize: u64,
 }
 
 impl Pull for SectionHeader {
-    type Format = WordSize;
+    type Format = Encoding;
 
-    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, word_size: Self::Format) -> Res<Self> {
-        let name = bytes.pull()?;
-        let r#type = bytes.pull()?;
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        Encoding {
+            word_size,
+            endianness,
+        }: Self::Format,
+    ) -> Res<Self> {
+        let name = bytes.pull_via(endianness)?;
+        let r#type = bytes.pull_via(endianness)?;
         let flags;
+        let addr;
         let offset;
         let size;
+        let link;
+        let info;
+        let addralign;
+        let entsize;
         match word_size {
             WordSize::Four => {
-                flags = bytes.pull::<u32>()?.into();
-                bytes.forward_sizeof::<u32>()?;
-                offset = bytes.pull::<u32>()?.into();
-                size = bytes.pull::<u32>()?.into();
-                bytes.forward_sizeof::<[u32; 4]>()?;
+                flags = bytes.pull_via::<u32>(endianness)?.into();
+                addr = bytes.pull_via::<u32>(endianness)?.into();
+                offset = bytes.pull_via::<u32>(endianness)?.into();
+                size = bytes.pull_via::<u32>(endianness)?.into();
+                link = bytes.pull_via(endianness)?;
+                info = bytes.pull_via(endianness)?;
+                addralign = bytes.pull_via::<u32>(endianness)?.into();
+                entsize = bytes.pull_via::<u32>(endianness)?.into();
             }
             WordSize::Eight => {
-                flags = bytes.pull()?;
-                bytes.forward_sizeof::<u64>()?;
-                offset = bytes.pull()?;
-                size = bytes.pull()?;
-                bytes.forward_sizeof::<[u32; 2]>()?;
-                bytes.forward_sizeof::<[u64; 2]>()?;
+                flags = bytes.pull_via(endianness)?;
+                addr = bytes.pull_via(endianness)?;
+                offset = bytes.pull_via(endianness)?;
+                size = bytes.pull_via(endianness)?;
+                link = bytes.pull_via(endianness)?;
+                info = bytes.pull_via(endianness)?;
+                addralign = bytes.pull_via(endianness)?;
+                entsize = bytes.pull_via(endianness)?;
             }
         }
         Ok(Self {
             name,
             r#type,
             flags,
+            addr,
             offset,
             size,
+            link,
+            info,
+            addralign,
+            entsize,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct CompressionHeader {
+    r#type: u32,
+    size: u64,
+}
+
+impl Pull for CompressionHeader {
+    type Format = Encoding;
+
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        Encoding {
+            word_size,
+            endianness,
+        }: Self::Format,
+    ) -> Res<Self> {
+        let r#type = bytes.pull_via(endianness)?;
+        let size = match word_size {
+            WordSize::Four => bytes.pull_via::<u32>(endianness)?.into(),
+            WordSize::Eight => {
+                bytes.forward_sizeof::<u32>()?; // reserved
+                bytes.pull_via(endianness)?
+            }
+        };
+        // alignment of the uncompressed data
+        match word_size {
+            WordSize::Four => bytes.forward_sizeof::<u32>()?,
+            WordSize::Eight => bytes.forward_sizeof::<u64>()?,
+        }
+        Ok(Self { r#type, size })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Symbol {
+    name: u32,
+    value: u64,
+    size: u64,
+    info: u8,
+    other: u8,
+    shndx: u32,
+}
+
+impl Pull for Symbol {
+    type Format = Encoding;
+
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        Encoding {
+            word_size,
+            endianness,
+        }: Self::Format,
+    ) -> Res<Self> {
+        let name = bytes.pull_via(endianness)?;
+        let value;
+        let size;
+        let info;
+        let other;
+        let shndx;
+        match word_size {
+            WordSize::Four => {
+                value = bytes.pull_via::<u32>(endianness)?.into();
+                size = bytes.pull_via::<u32>(endianness)?.into();
+                info = bytes.pull_via(endianness)?;
+                other = bytes.pull_via(endianness)?;
+                shndx = bytes.pull_via::<u16>(endianness)?.into();
+            }
+            WordSize::Eight => {
+                info = bytes.pull_via(endianness)?;
+                other = bytes.pull_via(endianness)?;
+                shndx = bytes.pull_via::<u16>(endianness)?.into();
+                value = bytes.pull_via(endianness)?;
+                size = bytes.pull_via(endianness)?;
+            }
+        }
+        Ok(Self {
+            name,
+            value,
+            size,
+            info,
+            other,
+            shndx,
+        })
+    }
+}
+
+impl Symbol {
+    fn r#type(&self) -> u8 {
+        self.info & 0xf
+    }
+
+    fn binding(&self) -> u8 {
+        self.info >> 4
+    }
+
+    fn visibility(&self) -> u8 {
+        self.other & 0x3
+    }
+
+    fn size_of(word_size: WordSize) -> usize {
+        match word_size {
+            WordSize::Four => 16,
+            WordSize::Eight => 24,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Relocation {
+    offset: u64,
+    info: u64,
+    addend: Option<i64>,
+}
+
+impl Pull for Relocation {
+    type Format = (Encoding, bool);
+
+    fn pull_fmt<B: Bytes + ?Sized>(
+        bytes: &mut B,
+        (
+            Encoding {
+                word_size,
+                endianness,
+            },
+            has_addend,
+        ): Self::Format,
+    ) -> Res<Self> {
+        Ok(match word_size {
+            WordSize::Four => Self {
+                offset: bytes.pull_via::<u32>(endianness)?.into(),
+                info: bytes.pull_via::<u32>(endianness)?.into(),
+                addend: if has_addend {
+                    Some(bytes.pull_via::<u32>(endianness)? as i32 as i64)
+                } else {
+                    None
+                },
+            },
+            WordSize::Eight => Self {
+                offset: bytes.pull_via(endianness)?,
+                info: bytes.pull_via(endianness)?,
+                addend: if has_addend {
+                    Some(bytes.pull_via::<u64>(endianness)? as i64)
+                } else {
+                    None
+                },
+            },
+        })
+    }
+}
+
+impl Relocation {
+    fn size_of(word_size: WordSize, has_addend: bool) -> usize {
+        match (word_size, has_addend) {
+            (WordSize::Four, false) => 8,
+            (WordSize::Four, true) => 12,
+            (WordSize::Eight, false) => 16,
+            (WordSize::Eight, true) => 24,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Note {
+    name: String,
+    r#type: u32,
+    desc: Vec<u8>,
+}
+
+impl Pull for Note {
+    type Format = (Endianness, u64);
+
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, (endianness, align): Self::Format) -> Res<Self> {
+        let name_size: u32 = bytes.pull_via(endianness)?;
+        let desc_size: u32 = bytes.pull_via(endianness)?;
+        let r#type = bytes.pull_via(endianness)?;
+        let align_position = |bytes: &mut B| -> Res<()> {
+            let position = bytes.stream_position()?;
+            bytes.jump(position.div_ceil(align) * align)
+        };
+
+        let position = bytes.stream_position()?;
+        let name = read_range(&mut &mut *bytes, position, name_size.into())?;
+        align_position(bytes)?;
+        let name = match std::ffi::CStr::from_bytes_until_nul(&name) {
+            Ok(name) => name.to_string_lossy().into_owned(),
+            Err(_) => String::from_utf8_lossy(&name).into_owned(),
+        };
+
+        let position = bytes.stream_position()?;
+        let desc = read_range(&mut &mut *bytes, position, desc_size.into())?;
+        align_position(bytes)?;
+
+        Ok(Self { name, r#type, desc })
+    }
+}
+
+#[derive(Debug)]
+struct VersionDefinition {
+    flags: u16,
+    idx: u16,
+    aux_count: u16,
+    aux: u32,
+    next: u32,
+}
+
+impl Pull for VersionDefinition {
+    type Format = Endianness;
+
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        bytes.forward_sizeof::<u16>()?; // version
+        let flags = bytes.pull_via(endianness)?;
+        let idx = bytes.pull_via(endianness)?;
+        let aux_count = bytes.pull_via(endianness)?;
+        bytes.forward_sizeof::<u32>()?; // hash
+        Ok(Self {
+            flags,
+            idx,
+            aux_count,
+            aux: bytes.pull_via(endianness)?,
+            next: bytes.pull_via(endianness)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct VersionDefinitionAux {
+    name: u32,
+    next: u32,
+}
+
+impl Pull for VersionDefinitionAux {
+    type Format = Endianness;
+
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        Ok(Self {
+            name: bytes.pull_via(endianness)?,
+            next: bytes.pull_via(endianness)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct VersionRequirement {
+    aux_count: u16,
+    file: u32,
+    aux: u32,
+    next: u32,
+}
+
+impl Pull for VersionRequirement {
+    type Format = Endianness;
+
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        bytes.forward_sizeof::<u16>()?; // version
+        Ok(Self {
+            aux_count: bytes.pull_via(endianness)?,
+            file: bytes.pull_via(endianness)?,
+            aux: bytes.pull_via(endianness)?,
+            next: bytes.pull_via(endianness)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct VersionRequirementAux {
+    flags: u16,
+    idx: u16,
+    name: u32,
+    next: u32,
+}
+
+impl Pull for VersionRequirementAux {
+    type Format = Endianness;
+
+    fn pull_fmt<B: Bytes + ?Sized>(bytes: &mut B, endianness: Self::Format) -> Res<Self> {
+        bytes.forward_sizeof::<u32>()?; // hash
+        Ok(Self {
+            flags: bytes.pull_via(endianness)?,
+            idx: bytes.pull_via(endianness)?,
+            name: bytes.pull_via(endianness)?,
+            next: bytes.pull_via(endianness)?,
         })
     }
 }
 
+#[derive(Debug)]
+struct Version {
+    idx: u16,
+    name: String,
+    flags: u16,
+    refs: Vec<String>, // parent versions for definitions, required file for requirements
+}
+
+// splits names like GLIBC_2.34 into a prefix and a comparable version number
+fn version_number(name: &str) -> Option<(&str, Vec<u32>)> {
+    let (prefix, number) = name.rsplit_once('_')?;
+    let number = number
+        .split('.')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    Some((prefix, number))
+}
+
+fn strtab_entry(strtab: &[u8], offset: u64) -> Res<String> {
+    let Ok(offset) = usize::try_from(offset) else {
+        unknown!()
+    };
+    let Some(Ok(string)) = strtab
+        .get(offset..)
+        .map(std::ffi::CStr::from_bytes_until_nul)
+    else {
+        unknown!()
+    };
+    Ok(string.to_string_lossy().into_owned())
+}
+
 #[derive(Default, Debug)]
 pub struct Parser {
     word_size: Option<WordSize>,
+    endianness: Endianness,
+    file_type: u16,
+    machine: u16,
+    entry: u64,
     ph_offset: u64,
     ph_size: u16,
-    ph_count: u16,
+    ph_count: u32,
     sh_offset: u64,
     sh_size: u16,
-    sh_count: u16,
-    sh_idx_str_table: u16,
+    sh_count: u32,
+    sh_idx_str_table: u32,
+    pheaders: Vec<ProgramHeader>,
+    sheaders: Vec<SectionHeader>,
+    sh_names: Vec<String>,
 }
 
 impl Parser {
+    fn encoding(&self) -> Encoding {
+        Encoding {
+            word_size: self.word_size.expect("word size assigned"),
+            endianness: self.endianness,
+        }
+    }
+
+    fn fmt_word(&self, value: u64) -> String {
+        match self.word_size.expect("word size assigned") {
+            WordSize::Four => format!("0x{value:08X}"),
+            WordSize::Eight => format!("0x{value:016X}"),
+        }
+    }
+
     fn add_word_entry<V32: Into<Str>, V64: Into<Str>>(
         &mut self,
         table: &mut Table,
@@ -221,22 +660,124 @@ impl Parser {
         get_value_64: impl FnOnce(&mut Self, u64) -> Res<V64>,
     ) -> Res<()> {
         match self.word_size.expect("word size must be set") {
-            WordSize::Four => table.add_entry(key, get_value_32(self, bytes.pull()?)?),
-            WordSize::Eight => table.add_entry(key, get_value_64(self, bytes.pull()?)?),
+            WordSize::Four => {
+                table.add_entry(key, get_value_32(self, bytes.pull_via(self.endianness)?)?)
+            }
+            WordSize::Eight => {
+                table.add_entry(key, get_value_64(self, bytes.pull_via(self.endianness)?)?)
+            }
         }
         Ok(())
     }
 
-    pub fn parse(&mut self, mut bytes: impl Bytes, all: bool) -> Res<Table> {
+    pub fn parse(
+        &mut self,
+        mut bytes: impl Bytes,
+        path: &std::path::Path,
+        options: &Options,
+    ) -> Res<Table> {
         let mut table = Default::default();
         self.header(&mut bytes, &mut table)?;
-        if all {
+        if options.all
+            || options.security
+            || options.dwarf
+            || options.unwind
+            || !options.addresses.is_empty()
+            || options.dependencies
+            || options.strings.is_some()
+            || options.dump.is_some()
+            || !options.disassemble.is_empty()
+            || !options.symbol_lookups.is_empty()
+        {
+            self.read_pheaders(&mut bytes)?;
+            self.read_sheaders(&mut bytes)?;
+        }
+        if options.all {
             self.pheaders(&mut bytes, &mut table)?;
             self.sheaders(&mut bytes, &mut table)?;
+            self.notes(&mut bytes, &mut table)?;
+        }
+        if options.security {
+            self.security(&mut bytes, &mut table)?;
+        }
+        if options.dwarf {
+            self.dwarf(&mut bytes, &mut table)?;
+        }
+        if options.unwind {
+            self.unwind(&mut bytes, &mut table)?;
+        }
+        if !options.addresses.is_empty() {
+            self.lookup(&mut bytes, &mut table, &options.addresses)?;
+        }
+        if options.dependencies {
+            self.dependencies(&mut bytes, &mut table, path, options)?;
+        }
+        if let Some(min_length) = options.strings {
+            let found = strings::scan(&mut bytes, min_length)?;
+            strings::add_entries(&mut table, &found, |offset| self.section_at_offset(offset));
+        }
+        if let Some(dump) = &options.dump {
+            self.dump(&mut bytes, &mut table, dump, options.output.as_deref())?;
+        }
+        if !options.disassemble.is_empty() {
+            self.disassemble(&mut bytes, &mut table, &options.disassemble)?;
+        }
+        if !options.symbol_lookups.is_empty() {
+            self.symbol_lookups(&mut bytes, &mut table, &options.symbol_lookups)?;
         }
         Ok(table)
     }
 
+    // the bytes of a section or segment as stored in the file, so compressed sections stay compressed
+    fn dump(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        dump: &Dump,
+        output: Option<&std::path::Path>,
+    ) -> Res<()> {
+        match dump {
+            Dump::Section(name) => {
+                let idx = self
+                    .section_named(name)
+                    .ok_or_else(|| Error::Cli(format!("No section named {name}")))?;
+                let sheader = &self.sheaders[idx];
+                dump::contents(
+                    table,
+                    format!("Contents of Section '{name}'"),
+                    &raw_section_data(bytes, sheader)?,
+                    if sheader.flags & SHF_ALLOC > 0 {
+                        sheader.addr
+                    } else {
+                        0
+                    },
+                    output,
+                )
+            }
+            Dump::Segment(number) => {
+                let pheader = number
+                    .checked_sub(1)
+                    .and_then(|i| self.pheaders.get(i))
+                    .ok_or_else(|| {
+                        Error::Cli(format!(
+                            "No segment {number}, segments are numbered 1 to {}",
+                            self.pheaders.len()
+                        ))
+                    })?;
+                dump::contents(
+                    table,
+                    format!("Contents of Program Segment {number}"),
+                    &segment_data(bytes, pheader)?,
+                    pheader.vaddr,
+                    output,
+                )
+            }
+            Dump::Chunk(_) => Err(Error::Cli(
+                "Chunks can only be dumped from PNG files".into(),
+            )),
+        }
+    }
+
     fn header(&mut self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
         bytes.forward(MAGIC.len())?; // ignore magic
         let (word_size, entry_value) = match bytes.pull()? {
@@ -246,40 +787,44 @@ impl Parser {
         };
         table.add_entry("Word Size", entry_value);
         self.word_size = Some(word_size);
-        table.add_entry(
-            "Endianness",
-            match bytes.pull()? {
-                ELFDATA2LSB => "Little",
-                ELFDATA2MSB => "Big",
-                _ => unknown!(),
-            },
-        );
-        if bytes.pull::<u8>()? != EV_CURRENT {
+        let (endianness, entry_value) = match bytes.pull()? {
+            ELFDATA2LSB => (Endianness::Little, "Little"),
+            ELFDATA2MSB => (Endianness::Big, "Big"),
+            _ => unknown!(),
+        };
+        table.add_entry("Endianness", entry_value);
+        self.endianness = endianness;
+        if bytes.pull_via::<u8>(self.endianness)? != EV_CURRENT {
             unknown!();
         }
-        table.add_entry(
-            "OS ABI",
-            match bytes.pull()? {
-                ELFOSABI_SYSV => "System V",
-                ELFOSABI_HPUX => "HPUX",
-                ELFOSABI_NETBSD => "NetBsd",
-                ELFOSABI_LINUX => "Linux",
-                ELFOSABI_SOLARIS => "Solaris",
-                ELFOSABI_AIX => "AIX",
-                ELFOSABI_IRIX => "Irix",
-                ELFOSABI_FREEBSD => "FreeBsd",
-                ELFOSABI_TRU64 => "Tru64",
-                ELFOSABI_MODESTO => "Modesto",
-                ELFOSABI_OPENBSD => "OpenBsd",
-                ELFOSABI_ARM => "ARM",
-                ELFOSABI_STANDALONE => "Standalone",
-                _ => unknown!(),
-            },
-        );
+        let os_abi: Str = match bytes.pull_via(self.endianness)? {
+            ELFOSABI_SYSV => "System V".into(),
+            ELFOSABI_HPUX => "HPUX".into(),
+            ELFOSABI_NETBSD => "NetBsd".into(),
+            ELFOSABI_LINUX => "Linux".into(),
+            ELFOSABI_SOLARIS => "Solaris".into(),
+            ELFOSABI_AIX => "AIX".into(),
+            ELFOSABI_IRIX => "Irix".into(),
+            ELFOSABI_FREEBSD => "FreeBsd".into(),
+            ELFOSABI_TRU64 => "Tru64".into(),
+            ELFOSABI_MODESTO => "Modesto".into(),
+            ELFOSABI_OPENBSD => "OpenBsd".into(),
+            ELFOSABI_OPENVMS => "OpenVMS".into(),
+            ELFOSABI_NSK => "NonStop Kernel".into(),
+            ELFOSABI_AROS => "AROS".into(),
+            ELFOSABI_FENIXOS => "FenixOS".into(),
+            ELFOSABI_CLOUDABI => "CloudABI".into(),
+            ELFOSABI_OPENVOS => "OpenVOS".into(),
+            ELFOSABI_ARM => "ARM".into(),
+            ELFOSABI_STANDALONE => "Standalone".into(),
+            other => format!("Unknown (0x{other:X})").into(),
+        };
+        table.add_entry("OS ABI", os_abi);
         bytes.forward(8)?; // padding
+        self.file_type = bytes.pull_via(self.endianness)?;
         table.add_entry(
             "File Type",
-            match bytes.pull()? {
+            match self.file_type {
                 ET_NONE => "None",
                 ET_REL => "Relocatable",
                 ET_EXEC => "Executable",
@@ -288,92 +833,29 @@ impl Parser {
                 _ => unknown!(),
             },
         );
+        self.machine = bytes.pull_via(self.endianness)?;
         table.add_entry(
             "Architecture",
-            match bytes.pull()? {
-                EM_NONE => "No machine",
-                EM_M32 => "AT&T WE 32100",
-                EM_SPARC => "SUN SPARC",
-                EM_386 => "Intel 80386",
-                EM_68K => "Motorola m68k Family",
-                EM_88K => "Motorola m88k Family",
-                EM_860 => "Intel 80860",
-                EM_MIPS => "MIPS R3000 big-endian",
-                EM_S370 => "IBM System/370",
-                EM_MIPS_RS3_LE => "MIPS R3000 little-endian",
-                EM_PARISC => "HPPA",
-                EM_VPP500 => "Fujitsu VPP500",
-                EM_SPARC32PLUS => "Sun's v8plus",
-                EM_960 => "Intel 80960",
-                EM_PPC => "PowerPC",
-                EM_PPC64 => "PowerPC 64-bit",
-                EM_S390 => "IBM S390",
-                EM_V800 => "NEC V800 series",
-                EM_FR20 => "Fujitsu FR20",
-                EM_RH32 => "TRW RH-32",
-                EM_RCE => "Motorola RCE",
-                EM_ARM => "ARM",
-                EM_FAKE_ALPHA => "Digital Alpha",
-                EM_SH => "Hitachi SH",
-                EM_SPARCV9 => "SPARC v9 64-bit",
-                EM_TRICORE => "Siemens Tricore",
-                EM_ARC => "Argonaut RISC Core",
-                EM_H8_300 => "Hitachi H8/300",
-                EM_H8_300H => "Hitachi H8/300H",
-                EM_H8S => "Hitachi H8S",
-                EM_H8_500 => "Hitachi H8/500",
-                EM_IA_64 => "Intel Merced",
-                EM_MIPS_X => "Stanford MIPS-X",
-                EM_COLDFIRE => "Motorola Coldfire",
-                EM_68HC12 => "Motorola M68HC12",
-                EM_MMA => "Fujitsu MMA Multimedia Accelerator",
-                EM_PCP => "Siemens PCP",
-                EM_NCPU => "Sony nCPU embeeded RISC",
-                EM_NDR1 => "Denso NDR1 microprocessor",
-                EM_STARCORE => "Motorola Start*Core processor",
-                EM_ME16 => "Toyota ME16 processor",
-                EM_ST100 => "STMicroelectronic ST100 processor",
-                EM_TINYJ => "Advanced Logic Corp. Tinyj emb.fam",
-                EM_X86_64 => "AMD x86-64",
-                EM_PDSP => "Sony DSP Processor",
-                EM_FX66 => "Siemens FX66 microcontroller",
-                EM_ST9PLUS => "STMicroelectronics ST9+ 8/16 mc",
-                EM_ST7 => "STmicroelectronics ST7 8 bit mc",
-                EM_68HC16 | EM_68HC11 | EM_68HC08 | EM_68HC05 => "Motorola microcontroller",
-                EM_SVX => "Silicon Graphics SVx",
-                EM_ST19 => "STMicroelectronics ST19 8 bit mc",
-                EM_VAX => "Digital VAX",
-                EM_CRIS => "Axis Communications 32-bit embedded processor",
-                EM_JAVELIN => "Infineon Technologies 32-bit embedded processor",
-                EM_FIREPATH => "Element 14 64-bit DSP Processor",
-                EM_ZSP => "LSI Logic 16-bit DSP Processor",
-                EM_MMIX => "Donald Knuth's educational 64-bit processor",
-                EM_HUANY => "Harvard University machine-independent object files",
-                EM_PRISM => "SiTera Prism",
-                EM_AVR => "Atmel AVR 8-bit microcontroller",
-                EM_FR30 => "Fujitsu FR30",
-                EM_D10V => "Mitsubishi D10V",
-                EM_D30V => "Mitsubishi D30V",
-                EM_V850 => "NEC v850",
-                EM_M32R => "Mitsubishi M32R",
-                EM_MN10300 => "Matsushita MN10300",
-                EM_MN10200 => "Matsushita MN10200",
-                EM_PJ => "picoJava",
-                EM_OPENRISC => "OpenRISC 32-bit embedded processor",
-                EM_ARC_A5 => "ARC Cores Tangent-A5",
-                EM_XTENSA => "Tensilica Xtensa Architecture",
-                _ => unknown!(),
+            match machine::name(self.machine) {
+                Some(name) => name.to_owned(),
+                None => format!("Unknown (0x{:X})", self.machine),
             },
         );
-        if bytes.pull::<u32>()? != EV_CURRENT as u32 {
+        if bytes.pull_via::<u32>(self.endianness)? != EV_CURRENT as u32 {
             unknown!();
         }
         self.add_word_entry(
             table,
             "Entry Address",
             bytes,
-            |_, addr| Ok(format!("0x{addr:08X}")),
-            |_, addr| Ok(format!("0x{addr:016X}")),
+            |this, addr| {
+                this.entry = addr.into();
+                Ok(format!("0x{addr:08X}"))
+            },
+            |this, addr| {
+                this.entry = addr;
+                Ok(format!("0x{addr:016X}"))
+            },
         )?;
         fn fmt_byte_count<B: std::fmt::Display>(byte_count: B) -> Res<String> {
             Ok(format!("{byte_count} bytes"))
@@ -404,24 +886,61 @@ impl Parser {
                 fmt_byte_count(sh_offset)
             },
         )?;
-        bytes.forward_sizeof::<u32>()?; // flags, unimplemented
+        let flags: u32 = bytes.pull_via(self.endianness)?;
+        let descriptions = eflags::describe(self.machine, word_size, flags);
+        table.add_entry(
+            "Flags",
+            if descriptions.is_empty() {
+                format!("0x{flags:X}")
+            } else {
+                format!("0x{flags:X} ({})", descriptions.join(", "))
+            },
+        );
         bytes.forward_sizeof::<u16>()?; // header size
-        self.ph_size = bytes.pull()?;
-        self.ph_count = bytes.pull()?;
-        self.sh_size = bytes.pull()?;
-        self.sh_count = bytes.pull()?;
-        self.sh_idx_str_table = bytes.pull()?;
+        self.ph_size = bytes.pull_via(self.endianness)?;
+        self.ph_count = bytes.pull_via::<u16>(self.endianness)?.into();
+        self.sh_size = bytes.pull_via(self.endianness)?;
+        self.sh_count = bytes.pull_via::<u16>(self.endianness)?.into();
+        self.sh_idx_str_table = bytes.pull_via::<u16>(self.endianness)?.into();
+
+        // counts and indices that overflow their header fields live in the first section header
+        if self.sh_offset != 0
+            && (self.sh_count == 0
+                || self.ph_count == PN_XNUM.into()
+                || self.sh_idx_str_table == SHN_XINDEX)
+        {
+            bytes.jump(self.sh_offset)?;
+            let first: SectionHeader = bytes.pull_via(self.encoding())?;
+            if self.sh_count == 0 {
+                let Ok(sh_count) = first.size.try_into() else {
+                    unknown!()
+                };
+                self.sh_count = sh_count;
+            }
+            if self.ph_count == PN_XNUM.into() {
+                self.ph_count = first.info;
+            }
+            if self.sh_idx_str_table == SHN_XINDEX {
+                self.sh_idx_str_table = first.link;
+            }
+        }
 
         Ok(())
     }
 
-    fn pheaders(&mut self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+    fn read_pheaders(&mut self, bytes: &mut impl Bytes) -> Res<()> {
         bytes.jump(self.ph_offset)?;
-        for i in 0..self.ph_count {
-            table.new_named_section(format!("Program Segment {}/{}", i + 1, self.ph_count));
-            let pheader: ProgramHeader =
-                bytes.pull_via(self.word_size.expect("word size assigned"))?;
+        for _ in 0..self.ph_count {
+            let pheader = bytes.pull_via(self.encoding())?;
+            self.pheaders.push(pheader);
+        }
+
+        Ok(())
+    }
 
+    fn pheaders(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        for (i, pheader) in self.pheaders.iter().enumerate() {
+            table.new_named_section(format!("Program Segment {}/{}", i + 1, self.ph_count));
             table.add_entry(
                 "Type",
                 match pheader.r#type {
@@ -440,74 +959,287 @@ impl Parser {
                 },
             );
 
-            let flags: String = [
-                (pheader.flags & PF_R > 0, "Read"),
-                (pheader.flags & PF_W > 0, "Write"),
-                (pheader.flags & PF_X > 0, "Execute"),
-            ]
+            table.add_entry(
+                "Flags",
+                fmt_flags(&[
+                    (pheader.flags & PF_R > 0, "Read"),
+                    (pheader.flags & PF_W > 0, "Write"),
+                    (pheader.flags & PF_X > 0, "Execute"),
+                ]),
+            );
+            table.add_entry("Offset", format!("0x{:X}", pheader.offset));
+            table.add_entry("Virtual Address", self.fmt_word(pheader.vaddr));
+            table.add_entry("Physical Address", self.fmt_word(pheader.paddr));
+            table.add_entry("File Size", format!("{} bytes", pheader.filesz));
+            table.add_entry(
+                "Memory Size",
+                match pheader.memsz.checked_sub(pheader.filesz) {
+                    Some(extra) if extra > 0 => {
+                        format!("{} bytes ({extra} bytes zero-filled)", pheader.memsz)
+                    }
+                    _ => format!("{} bytes", pheader.memsz),
+                },
+            );
+            table.add_entry("Alignment", format!("0x{:X}", pheader.align));
+
+            if let SegmentType::Interp = pheader.r#type {
+                bytes.jump(pheader.offset)?;
+                let interpreter = match bytes.pull::<std::ffi::CString>()?.into_string() {
+                    Ok(string) => string,
+                    Err(err) => err.into_cstring().to_string_lossy().into_owned(),
+                };
+                table.add_entry("Interpreter", interpreter);
+            }
+        }
+
+        if !self.pheaders.is_empty() && !self.sheaders.is_empty() {
+            table.new_named_section("Segment to Section Mapping");
+            for (i, pheader) in self.pheaders.iter().enumerate() {
+                let names: Vec<_> = self
+                    .sheaders
+                    .iter()
+                    .zip(&self.sh_names)
+                    .skip(1)
+                    .filter(|(sheader, _)| section_in_segment(sheader, pheader))
+                    .map(|(_, name)| name.as_str())
+                    .collect();
+                table.add_entry(
+                    (i + 1).to_string(),
+                    if names.is_empty() {
+                        "None".into()
+                    } else {
+                        names.join(" ")
+                    },
+                );
+            }
+        }
+
+        if let Some(dynamic) = self
+            .pheaders
             .iter()
-            .filter_map(|&(enabled, flag)| enabled.then_some(flag))
-            .enumerate()
-            .flat_map(|(i, flag)| [if i == 0 { "" } else { ", " }, flag])
-            .collect();
-            if flags.is_empty() {
-                table.add_entry("Flags", "None");
-            } else {
-                table.add_entry("Flags", flags);
+            .find(|pheader| pheader.r#type == SegmentType::Dynamic)
+        {
+            let entries = self.dynamic_entries(bytes, dynamic)?;
+            self.dynamic(bytes, table, &entries)?;
+        }
+
+        Ok(())
+    }
+
+    fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        self.pheaders
+            .iter()
+            .filter(|pheader| pheader.r#type == SegmentType::Load)
+            .find_map(|pheader| {
+                let offset = vaddr.checked_sub(pheader.vaddr)?;
+                (offset < pheader.filesz).then(|| pheader.offset.checked_add(offset))?
+            })
+    }
+
+    fn dynamic_entries(
+        &self,
+        bytes: &mut impl Bytes,
+        dynamic: &ProgramHeader,
+    ) -> Res<Vec<DynamicEntry>> {
+        let word_size = self.word_size.expect("word size assigned");
+        let entry_size = match word_size {
+            WordSize::Four => 8,
+            WordSize::Eight => 16,
+        };
+        bytes.jump(dynamic.offset)?;
+        let mut entries = vec![];
+        for _ in 0..dynamic.filesz / entry_size {
+            let entry: DynamicEntry = bytes.pull_via(self.encoding())?;
+            if entry.tag == DT_NULL.into() {
+                break;
             }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
 
-            match pheader.r#type {
-                SegmentType::Interp => {
-                    let curr_pos = bytes.stream_position()?;
-                    bytes.jump(pheader.offset)?;
-                    let interpreter = match bytes.pull::<std::ffi::CString>()?.into_string() {
-                        Ok(string) => string,
-                        Err(err) => err.into_cstring().to_string_lossy().into_owned(),
-                    };
-                    table.add_entry("Interpreter", interpreter);
-                    bytes.jump(curr_pos)?;
+    fn dynamic_strtab(&self, bytes: &mut impl Bytes, entries: &[DynamicEntry]) -> Res<Vec<u8>> {
+        let find = |tag: u32| {
+            entries
+                .iter()
+                .find(|entry| entry.tag == tag.into())
+                .map(|entry| entry.value)
+        };
+        let (Some(addr), Some(size)) = (find(DT_STRTAB), find(DT_STRSZ)) else {
+            return Ok(vec![]);
+        };
+        let Some(offset) = self.vaddr_to_offset(addr) else {
+            unknown!()
+        };
+        read_range(bytes, offset, size)
+    }
+
+    fn dynamic(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        entries: &[DynamicEntry],
+    ) -> Res<()> {
+        let strtab = self.dynamic_strtab(bytes, entries)?;
+        table.new_named_section(format!("Dynamic Section ({} entries)", entries.len()));
+        for entry in entries {
+            let Ok(tag) = u32::try_from(entry.tag) else {
+                table.add_entry(format!("0x{:X}", entry.tag), format!("0x{:X}", entry.value));
+                continue;
+            };
+            let string = || strtab_entry(&strtab, entry.value);
+            let byte_count = || format!("{} bytes", entry.value);
+            let addr = || format!("0x{:X}", entry.value);
+            let (key, value): (Str, Str) = match tag {
+                DT_NEEDED => ("NEEDED".into(), string()?.into()),
+                DT_SONAME => ("SONAME".into(), string()?.into()),
+                DT_RPATH => ("RPATH".into(), string()?.into()),
+                DT_RUNPATH => ("RUNPATH".into(), string()?.into()),
+                DT_PLTRELSZ => ("PLTRELSZ".into(), byte_count().into()),
+                DT_PLTGOT => ("PLTGOT".into(), addr().into()),
+                DT_HASH => ("HASH".into(), addr().into()),
+                DT_STRTAB => ("STRTAB".into(), addr().into()),
+                DT_SYMTAB => ("SYMTAB".into(), addr().into()),
+                DT_RELA => ("RELA".into(), addr().into()),
+                DT_RELASZ => ("RELASZ".into(), byte_count().into()),
+                DT_RELAENT => ("RELAENT".into(), byte_count().into()),
+                DT_STRSZ => ("STRSZ".into(), byte_count().into()),
+                DT_SYMENT => ("SYMENT".into(), byte_count().into()),
+                DT_INIT => ("INIT".into(), addr().into()),
+                DT_FINI => ("FINI".into(), addr().into()),
+                DT_SYMBOLIC => ("SYMBOLIC".into(), "Yes".into()),
+                DT_REL => ("REL".into(), addr().into()),
+                DT_RELSZ => ("RELSZ".into(), byte_count().into()),
+                DT_RELENT => ("RELENT".into(), byte_count().into()),
+                DT_PLTREL => (
+                    "PLTREL".into(),
+                    match u32::try_from(entry.value) {
+                        Ok(DT_REL) => "REL".into(),
+                        Ok(DT_RELA) => "RELA".into(),
+                        _ => addr().into(),
+                    },
+                ),
+                DT_DEBUG => ("DEBUG".into(), addr().into()),
+                DT_TEXTREL => ("TEXTREL".into(), "Yes".into()),
+                DT_JMPREL => ("JMPREL".into(), addr().into()),
+                DT_BIND_NOW => ("BIND_NOW".into(), "Yes".into()),
+                DT_INIT_ARRAY => ("INIT_ARRAY".into(), addr().into()),
+                DT_FINI_ARRAY => ("FINI_ARRAY".into(), addr().into()),
+                DT_INIT_ARRAYSZ => ("INIT_ARRAYSZ".into(), byte_count().into()),
+                DT_FINI_ARRAYSZ => ("FINI_ARRAYSZ".into(), byte_count().into()),
+                DT_PREINIT_ARRAY => ("PREINIT_ARRAY".into(), addr().into()),
+                DT_PREINIT_ARRAYSZ => ("PREINIT_ARRAYSZ".into(), byte_count().into()),
+                DT_SYMTAB_SHNDX => ("SYMTAB_SHNDX".into(), addr().into()),
+                DT_RELRSZ => ("RELRSZ".into(), byte_count().into()),
+                DT_RELR => ("RELR".into(), addr().into()),
+                DT_RELRENT => ("RELRENT".into(), byte_count().into()),
+                DT_GNU_HASH => ("GNU_HASH".into(), addr().into()),
+                DT_TLSDESC_PLT => ("TLSDESC_PLT".into(), addr().into()),
+                DT_TLSDESC_GOT => ("TLSDESC_GOT".into(), addr().into()),
+                DT_CONFIG => ("CONFIG".into(), string()?.into()),
+                DT_DEPAUDIT => ("DEPAUDIT".into(), string()?.into()),
+                DT_AUDIT => ("AUDIT".into(), string()?.into()),
+                DT_VERSYM => ("VERSYM".into(), addr().into()),
+                DT_RELACOUNT => ("RELACOUNT".into(), entry.value.to_string().into()),
+                DT_RELCOUNT => ("RELCOUNT".into(), entry.value.to_string().into()),
+                DT_VERDEF => ("VERDEF".into(), addr().into()),
+                DT_VERDEFNUM => ("VERDEFNUM".into(), entry.value.to_string().into()),
+                DT_VERNEED => ("VERNEED".into(), addr().into()),
+                DT_VERNEEDNUM => ("VERNEEDNUM".into(), entry.value.to_string().into()),
+                DT_AUXILIARY => ("AUXILIARY".into(), string()?.into()),
+                DT_FILTER => ("FILTER".into(), string()?.into()),
+                DT_FLAGS => {
+                    let flags = entry.value as u32;
+                    (
+                        "FLAGS".into(),
+                        fmt_flags(&[
+                            (flags & DF_ORIGIN > 0, "ORIGIN"),
+                            (flags & DF_SYMBOLIC > 0, "SYMBOLIC"),
+                            (flags & DF_TEXTREL > 0, "TEXTREL"),
+                            (flags & DF_BIND_NOW > 0, "BIND_NOW"),
+                            (flags & DF_STATIC_TLS > 0, "STATIC_TLS"),
+                        ]),
+                    )
                 }
-                _ => {}
-            }
+                DT_FLAGS_1 => {
+                    let flags = entry.value as u32;
+                    (
+                        "FLAGS_1".into(),
+                        fmt_flags(&[
+                            (flags & DF_1_NOW > 0, "NOW"),
+                            (flags & DF_1_GLOBAL > 0, "GLOBAL"),
+                            (flags & DF_1_GROUP > 0, "GROUP"),
+                            (flags & DF_1_NODELETE > 0, "NODELETE"),
+                            (flags & DF_1_LOADFLTR > 0, "LOADFLTR"),
+                            (flags & DF_1_INITFIRST > 0, "INITFIRST"),
+                            (flags & DF_1_NOOPEN > 0, "NOOPEN"),
+                            (flags & DF_1_ORIGIN > 0, "ORIGIN"),
+                            (flags & DF_1_DIRECT > 0, "DIRECT"),
+                            (flags & DF_1_TRANS > 0, "TRANS"),
+                            (flags & DF_1_INTERPOSE > 0, "INTERPOSE"),
+                            (flags & DF_1_NODEFLIB > 0, "NODEFLIB"),
+                            (flags & DF_1_NODUMP > 0, "NODUMP"),
+                            (flags & DF_1_CONFALT > 0, "CONFALT"),
+                            (flags & DF_1_ENDFILTEE > 0, "ENDFILTEE"),
+                            (flags & DF_1_DISPRELDNE > 0, "DISPRELDNE"),
+                            (flags & DF_1_DISPRELPND > 0, "DISPRELPND"),
+                            (flags & DF_1_NODIRECT > 0, "NODIRECT"),
+                            (flags & DF_1_IGNMULDEF > 0, "IGNMULDEF"),
+                            (flags & DF_1_NOKSYMS > 0, "NOKSYMS"),
+                            (flags & DF_1_NOHDR > 0, "NOHDR"),
+                            (flags & DF_1_EDITED > 0, "EDITED"),
+                            (flags & DF_1_NORELOC > 0, "NORELOC"),
+                            (flags & DF_1_SYMINTPOSE > 0, "SYMINTPOSE"),
+                            (flags & DF_1_GLOBAUDIT > 0, "GLOBAUDIT"),
+                            (flags & DF_1_SINGLETON > 0, "SINGLETON"),
+                            (flags & DF_1_STUB > 0, "STUB"),
+                            (flags & DF_1_PIE > 0, "PIE"),
+                        ]),
+                    )
+                }
+                _ => (format!("0x{tag:X}").into(), addr().into()),
+            };
+            table.add_entry(key, value);
         }
 
         Ok(())
     }
 
-    fn sheaders(&mut self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
-        let name_strtab_header_addr =
-            self.sh_idx_str_table as u64 * self.sh_size as u64 + self.sh_offset;
+    fn read_sheaders(&mut self, bytes: &mut impl Bytes) -> Res<()> {
+        if self.sh_count == 0 {
+            return Ok(());
+        }
+        let Some(name_strtab_header_addr) = (self.sh_idx_str_table as u64)
+            .checked_mul(self.sh_size as u64)
+            .and_then(|offset| offset.checked_add(self.sh_offset))
+        else {
+            unknown!()
+        };
         bytes.jump(name_strtab_header_addr)?;
-        let name_strtab_header: SectionHeader =
-            bytes.pull_via(self.word_size.expect("word size assigned"))?;
+        let name_strtab_header: SectionHeader = bytes.pull_via(self.encoding())?;
         if name_strtab_header.r#type != SectionType::StrTab {
             unknown!();
         }
-        bytes.jump(name_strtab_header.offset)?; // jump to sheader name strtable
-        let name_strtab = {
-            let size = name_strtab_header
-                .size
-                .try_into()
-                .expect("size is within usize::MAX");
-            let mut strtab = vec![0; size];
-            bytes.read_exact(&mut strtab)?;
-            strtab
-        };
+        let name_strtab = raw_section_data(bytes, &name_strtab_header)?;
 
         bytes.jump(self.sh_offset)?;
+        for _ in 0..self.sh_count {
+            let sheader: SectionHeader = bytes.pull_via(self.encoding())?;
+            self.sh_names
+                .push(strtab_entry(&name_strtab, sheader.name.into())?);
+            self.sheaders.push(sheader);
+        }
+
+        Ok(())
+    }
+
+    fn sheaders(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
         let mut total_size = 0;
-        for i in 0..self.sh_count {
+        for (i, (sheader, name)) in self.sheaders.iter().zip(&self.sh_names).enumerate() {
             table.new_named_section(format!("Section {}/{}", i + 1, self.sh_count));
-            let sheader: SectionHeader =
-                bytes.pull_via(self.word_size.expect("word size assigned"))?;
             total_size += sheader.size;
 
-            let Ok(name) = std::ffi::CStr::from_bytes_until_nul(
-                &name_strtab[sheader.name.try_into().expect("u32 -> usize")..],
-            ) else {
-                unknown!()
-            };
-            table.add_entry("Name", name.to_string_lossy().into_owned());
+            table.add_entry("Name", name.clone());
 
             table.add_entry(
                 "Type",
@@ -539,41 +1271,1154 @@ impl Parser {
                     SectionType::Unknown => "Unknown",
                 },
             );
-            let flags: String = [
-                (sheader.flags & SHF_WRITE > 0, "Write"),
-                (sheader.flags & SHF_ALLOC > 0, "Alloc"),
-                (sheader.flags & SHF_EXECINSTR > 0, "Exec"),
-                (sheader.flags & SHF_MERGE > 0, "Merge"),
-                (sheader.flags & SHF_STRINGS > 0, "Strings"),
-                (sheader.flags & SHF_INFO_LINK > 0, "Info Link"),
-                (sheader.flags & SHF_LINK_ORDER > 0, "Link Order"),
-                (sheader.flags & SHF_OS_NONCONFORMING > 0, "OS Nonconforming"),
-                (sheader.flags & SHF_GROUP > 0, "Group"),
-                (sheader.flags & SHF_TLS > 0, "TLS"),
-                (sheader.flags & SHF_ORDERED > 0, "Ordered"),
-                (sheader.flags & SHF_EXCLUDE > 0, "Exclude"),
-            ]
-            .iter()
-            .filter_map(|&(enabled, flag)| enabled.then_some(flag))
-            .enumerate()
-            .flat_map(|(i, flag)| [if i == 0 { "" } else { ", " }, flag])
-            .collect();
-            if flags.is_empty() {
-                table.add_entry("Flags", "None");
+            table.add_entry(
+                "Flags",
+                fmt_flags(&[
+                    (sheader.flags & SHF_WRITE > 0, "Write"),
+                    (sheader.flags & SHF_ALLOC > 0, "Alloc"),
+                    (sheader.flags & SHF_EXECINSTR > 0, "Exec"),
+                    (sheader.flags & SHF_MERGE > 0, "Merge"),
+                    (sheader.flags & SHF_STRINGS > 0, "Strings"),
+                    (sheader.flags & SHF_INFO_LINK > 0, "Info Link"),
+                    (sheader.flags & SHF_LINK_ORDER > 0, "Link Order"),
+                    (sheader.flags & SHF_OS_NONCONFORMING > 0, "OS Nonconforming"),
+                    (sheader.flags & SHF_GROUP > 0, "Group"),
+                    (sheader.flags & SHF_TLS > 0, "TLS"),
+                    (sheader.flags & SHF_COMPRESSED > 0, "Compressed"),
+                    (sheader.flags & SHF_ORDERED > 0, "Ordered"),
+                    (sheader.flags & SHF_EXCLUDE > 0, "Exclude"),
+                ]),
+            );
+            table.add_entry("Address", self.fmt_word(sheader.addr));
+            table.add_entry("Offset", format!("0x{:X}", sheader.offset));
+            let compression = if sheader.flags & SHF_COMPRESSED > 0 || name.starts_with(".zdebug") {
+                let data = raw_section_data(bytes, sheader)?;
+                self.compression(&data, i)?
             } else {
-                table.add_entry("Flags", flags);
-            }
-
-            table.add_entry("Size", format!("{} bytes", sheader.size));
+                None
+            };
+            table.add_entry(
+                "Size",
+                match compression {
+                    Some((compression, size, _)) => format!(
+                        "{} bytes ({size} bytes uncompressed, {})",
+                        sheader.size,
+                        match compression {
+                            ELFCOMPRESS_ZLIB => "zlib".into(),
+                            ELFCOMPRESS_ZSTD => "zstd".into(),
+                            ELFCOMPRESS_LOOS..=ELFCOMPRESS_HIOS =>
+                                format!("OS specific {compression}"),
+                            ELFCOMPRESS_LOPROC..=ELFCOMPRESS_HIPROC => {
+                                format!("processor specific {compression}")
+                            }
+                            _ => format!("unknown {compression}"),
+                        }
+                    ),
+                    None => format!("{} bytes", sheader.size),
+                },
+            );
 
-            match name.to_bytes() {
-                _ => {}
-            }
+            let section_ref = |idx: u32| match self.sh_names.get(idx as usize) {
+                Some(name) => format!("{idx} ({name})"),
+                None => idx.to_string(),
+            };
+            let link_is_section = sheader.flags & SHF_LINK_ORDER > 0
+                || matches!(
+                    sheader.r#type,
+                    SectionType::SymTab
+                        | SectionType::DynSym
+                        | SectionType::Rel
+                        | SectionType::Rela
+                        | SectionType::Dynamic
+                        | SectionType::Hash
+                        | SectionType::GnuHash
+                        | SectionType::Group
+                        | SectionType::SymTabShNdx
+                        | SectionType::GnuVerDef
+                        | SectionType::GnuVerNeed
+                        | SectionType::GnuVerSym
+                );
+            table.add_entry(
+                "Link",
+                if link_is_section {
+                    section_ref(sheader.link)
+                } else {
+                    sheader.link.to_string()
+                },
+            );
+            table.add_entry(
+                "Info",
+                match sheader.r#type {
+                    SectionType::SymTab | SectionType::DynSym => {
+                        format!("{} (first non-local symbol)", sheader.info)
+                    }
+                    SectionType::Group => format!("{} (signature symbol)", sheader.info),
+                    SectionType::GnuVerDef | SectionType::GnuVerNeed => {
+                        format!("{} entries", sheader.info)
+                    }
+                    SectionType::Rel | SectionType::Rela if sheader.info != 0 => {
+                        section_ref(sheader.info)
+                    }
+                    _ if sheader.flags & SHF_INFO_LINK > 0 => section_ref(sheader.info),
+                    _ => sheader.info.to_string(),
+                },
+            );
+            table.add_entry("Alignment", format!("0x{:X}", sheader.addralign));
+            table.add_entry("Entry Size", format!("{} bytes", sheader.entsize));
         }
 
         table.new_unnamed_section();
         table.add_entry("Total Size of Sections", format!("{} bytes", total_size));
 
+        for i in 0..self.sheaders.len() {
+            match self.sheaders[i].r#type {
+                SectionType::SymTab | SectionType::DynSym => self.symbol_table(bytes, table, i)?,
+                SectionType::Rel | SectionType::Rela => self.relocations(bytes, table, i)?,
+                SectionType::Hash | SectionType::GnuHash => self.hash_table(bytes, table, i)?,
+                SectionType::GnuVerDef | SectionType::GnuVerNeed => {
+                    self.version_table(bytes, table, i)?
+                }
+                _ => {}
+            }
+        }
+        self.section_groups(bytes, table)?;
+        self.init_functions(bytes, table)?;
+
+        Ok(())
+    }
+
+    fn symbols(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<(String, Symbol)>> {
+        let word_size = self.word_size.expect("word size assigned");
+        let Some(sheader) = self
+            .sheaders
+            .get(idx)
+            .filter(|sheader| matches!(sheader.r#type, SectionType::SymTab | SectionType::DynSym))
+        else {
+            unknown!()
+        };
+        let strtab = self.section_data(bytes, sheader.link as usize)?;
+        let data = self.section_data(bytes, idx)?;
+        let entry_size = Symbol::size_of(word_size).max(
+            sheader
+                .entsize
+                .try_into()
+                .expect("entsize is within usize::MAX"),
+        );
+        let count = data.len() / entry_size;
+        let shndx_idx = self.sheaders.iter().position(|shndx_header| {
+            shndx_header.r#type == SectionType::SymTabShNdx && shndx_header.link as usize == idx
+        });
+        let shndx_data = match shndx_idx {
+            Some(shndx_idx) => self.section_data(bytes, shndx_idx)?,
+            None => vec![],
+        };
+        let mut data = std::io::Cursor::new(data);
+        let mut shndx_data = std::io::Cursor::new(shndx_data);
+        (0..count)
+            .map(|i| {
+                data.jump((i * entry_size) as u64)?;
+                let mut symbol: Symbol = data.pull_via(self.encoding())?;
+                if symbol.shndx == SHN_XINDEX && shndx_idx.is_some() {
+                    shndx_data.jump((i * size_of::<u32>()) as u64)?;
+                    symbol.shndx = shndx_data.pull_via(self.endianness)?;
+                }
+                Ok((strtab_entry(&strtab, symbol.name.into())?, symbol))
+            })
+            .collect()
+    }
+
+    // the symbols a relocation section refers to, or none when it does not link to a symbol table
+    fn linked_symbols(
+        &self,
+        bytes: &mut impl Bytes,
+        rel_header: &SectionHeader,
+    ) -> Res<Vec<(String, Symbol)>> {
+        match self.sheaders.get(rel_header.link as usize) {
+            Some(link) if matches!(link.r#type, SectionType::SymTab | SectionType::DynSym) => {
+                self.symbols(bytes, rel_header.link as usize)
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn symbol_table(&self, bytes: &mut impl Bytes, table: &mut Table, idx: usize) -> Res<()> {
+        let symbols = self.symbols(bytes, idx)?;
+        let versions = self.symbol_versions(bytes, &symbols, idx)?;
+        table.new_named_section(format!(
+            "Symbol Table '{}' ({} entries)",
+            self.sh_names[idx],
+            symbols.len()
+        ));
+        let value_width = match self.word_size.expect("word size assigned") {
+            WordSize::Four => 10,
+            WordSize::Eight => 18,
+        };
+        table.add_entry(
+            "Num",
+            format!(
+                "{:<value_width$} {:>6} {:<7} {:<6} {:<9} {:>5} Name",
+                "Value", "Size", "Type", "Bind", "Vis", "Ndx"
+            ),
+        );
+        for (i, (name, symbol)) in symbols.iter().enumerate() {
+            let r#type: Str = match symbol.r#type() {
+                STT_NOTYPE => "NOTYPE".into(),
+                STT_OBJECT => "OBJECT".into(),
+                STT_FUNC => "FUNC".into(),
+                STT_SECTION => "SECTION".into(),
+                STT_FILE => "FILE".into(),
+                STT_COMMON => "COMMON".into(),
+                STT_TLS => "TLS".into(),
+                STT_GNU_IFUNC => "IFUNC".into(),
+                other => other.to_string().into(),
+            };
+            let binding: Str = match symbol.binding() {
+                STB_LOCAL => "LOCAL".into(),
+                STB_GLOBAL => "GLOBAL".into(),
+                STB_WEAK => "WEAK".into(),
+                STB_GNU_UNIQUE => "UNIQUE".into(),
+                other => other.to_string().into(),
+            };
+            let visibility = match symbol.visibility() {
+                STV_DEFAULT => "DEFAULT",
+                STV_INTERNAL => "INTERNAL",
+                STV_HIDDEN => "HIDDEN",
+                _ => "PROTECTED",
+            };
+            let section_idx: Str = match symbol.shndx {
+                SHN_UNDEF => "UND".into(),
+                SHN_ABS => "ABS".into(),
+                SHN_COMMON => "COM".into(),
+                SHN_XINDEX => "XINDEX".into(),
+                idx => idx.to_string().into(),
+            };
+            table.add_entry(
+                i.to_string(),
+                format!(
+                    "{:<value_width$} {:>6} {type:<7} {binding:<6} {visibility:<9} {section_idx:>5} {name}{}",
+                    format!("0x{:0w$X}", symbol.value, w = value_width - 2),
+                    symbol.size,
+                    versions.get(i).map_or("", String::as_str),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn version_definitions(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<Version>> {
+        let sheader = &self.sheaders[idx];
+        let strtab = self.section_data(bytes, sheader.link as usize)?;
+        let mut data = std::io::Cursor::new(self.section_data(bytes, idx)?);
+        let mut versions = vec![];
+        let mut offset = 0;
+        loop {
+            data.jump(offset)?;
+            let definition: VersionDefinition = data.pull_via(self.endianness)?;
+            let mut names = vec![];
+            let mut aux_offset = offset + u64::from(definition.aux);
+            for _ in 0..definition.aux_count {
+                data.jump(aux_offset)?;
+                let aux: VersionDefinitionAux = data.pull_via(self.endianness)?;
+                names.push(strtab_entry(&strtab, aux.name.into())?);
+                aux_offset += u64::from(aux.next);
+            }
+            if names.is_empty() {
+                unknown!();
+            }
+            versions.push(Version {
+                idx: definition.idx,
+                name: names.remove(0),
+                flags: definition.flags,
+                refs: names,
+            });
+            if definition.next == 0 {
+                break;
+            }
+            offset += u64::from(definition.next);
+        }
+        Ok(versions)
+    }
+
+    fn version_requirements(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<Version>> {
+        let sheader = &self.sheaders[idx];
+        let strtab = self.section_data(bytes, sheader.link as usize)?;
+        let mut data = std::io::Cursor::new(self.section_data(bytes, idx)?);
+        let mut versions = vec![];
+        let mut offset = 0;
+        loop {
+            data.jump(offset)?;
+            let requirement: VersionRequirement = data.pull_via(self.endianness)?;
+            let file = strtab_entry(&strtab, requirement.file.into())?;
+            let mut aux_offset = offset + u64::from(requirement.aux);
+            for _ in 0..requirement.aux_count {
+                data.jump(aux_offset)?;
+                let aux: VersionRequirementAux = data.pull_via(self.endianness)?;
+                versions.push(Version {
+                    idx: aux.idx,
+                    name: strtab_entry(&strtab, aux.name.into())?,
+                    flags: aux.flags,
+                    refs: vec![file.clone()],
+                });
+                aux_offset += u64::from(aux.next);
+            }
+            if requirement.next == 0 {
+                break;
+            }
+            offset += u64::from(requirement.next);
+        }
+        Ok(versions)
+    }
+
+    fn versions(&self, bytes: &mut impl Bytes) -> Res<Vec<Version>> {
+        let mut versions = vec![];
+        for (i, sheader) in self.sheaders.iter().enumerate() {
+            match sheader.r#type {
+                SectionType::GnuVerDef => versions.extend(self.version_definitions(bytes, i)?),
+                SectionType::GnuVerNeed => versions.extend(self.version_requirements(bytes, i)?),
+                _ => {}
+            }
+        }
+        Ok(versions)
+    }
+
+    // version suffix (@VER or @@VER) of every symbol in a dynamic symbol table
+    fn symbol_versions(
+        &self,
+        bytes: &mut impl Bytes,
+        symbols: &[(String, Symbol)],
+        idx: usize,
+    ) -> Res<Vec<String>> {
+        let Some(versym_idx) = self.sheaders.iter().position(|sheader| {
+            sheader.r#type == SectionType::GnuVerSym && sheader.link as usize == idx
+        }) else {
+            return Ok(vec![]);
+        };
+        let versions = self.versions(bytes)?;
+        let mut versym = std::io::Cursor::new(self.section_data(bytes, versym_idx)?);
+        symbols
+            .iter()
+            .map(|(_, symbol)| {
+                let entry: u16 = versym.pull_via(self.endianness)?;
+                let version_idx = entry & VERSYM_VERSION;
+                if let VER_NDX_LOCAL | VER_NDX_GLOBAL = version_idx {
+                    return Ok(String::new());
+                }
+                let Some(version) = versions.iter().find(|version| version.idx == version_idx)
+                else {
+                    return Ok(format!("@<{version_idx}>"));
+                };
+                let is_default = entry & VERSYM_HIDDEN == 0 && symbol.shndx != SHN_UNDEF;
+                Ok(format!(
+                    "{}{}",
+                    if is_default { "@@" } else { "@" },
+                    version.name
+                ))
+            })
+            .collect()
+    }
+
+    fn version_table(&self, bytes: &mut impl Bytes, table: &mut Table, idx: usize) -> Res<()> {
+        let fmt_version_flags = |flags: u16| -> Str {
+            match flags & (VER_FLG_BASE | VER_FLG_WEAK | VER_FLG_INFO) {
+                0 => "".into(),
+                flags => format!(
+                    " ({})",
+                    fmt_flags(&[
+                        (flags & VER_FLG_BASE > 0, "Base"),
+                        (flags & VER_FLG_WEAK > 0, "Weak"),
+                        (flags & VER_FLG_INFO > 0, "Info"),
+                    ])
+                )
+                .into(),
+            }
+        };
+        if self.sheaders[idx].r#type == SectionType::GnuVerDef {
+            table.new_named_section(format!("Version Definitions '{}'", self.sh_names[idx]));
+            for version in self.version_definitions(bytes, idx)? {
+                let mut value = format!("{}{}", version.name, fmt_version_flags(version.flags));
+                if !version.refs.is_empty() {
+                    value.push_str(&format!(", parent {}", version.refs.join(", ")));
+                }
+                table.add_entry(version.idx.to_string(), value);
+            }
+            return Ok(());
+        }
+
+        table.new_named_section(format!("Version Requirements '{}'", self.sh_names[idx]));
+        let versions = self.version_requirements(bytes, idx)?;
+        let mut newest: Vec<(&str, &str, Vec<u32>)> = vec![];
+        for version in &versions {
+            // file names come from the string table, so they go in the value with the version
+            table.add_entry(
+                (version.idx & VERSYM_VERSION).to_string(),
+                format!(
+                    "{} from {}{}",
+                    version.name,
+                    version.refs[0],
+                    fmt_version_flags(version.flags)
+                ),
+            );
+            let Some((prefix, number)) = version_number(&version.name) else {
+                continue;
+            };
+            match newest
+                .iter_mut()
+                .find(|(newest_prefix, ..)| *newest_prefix == prefix)
+            {
+                Some(entry) if entry.2 < number => *entry = (prefix, &version.name, number),
+                Some(_) => {}
+                None => newest.push((prefix, &version.name, number)),
+            }
+        }
+        if !newest.is_empty() {
+            table.new_unnamed_section();
+            for (_, name, _) in newest {
+                table.add_entry("Newest Required", name.to_owned());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn note_groups(&self, bytes: &mut impl Bytes) -> Res<Vec<(String, Vec<Note>)>> {
+        let mut groups = vec![];
+        let note_sections: Vec<_> = (0..self.sheaders.len())
+            .filter(|&i| self.sheaders[i].r#type == SectionType::Note)
+            .collect();
+        if !note_sections.is_empty() {
+            for i in note_sections {
+                let sheader = &self.sheaders[i];
+                let data = self.section_data(bytes, i)?;
+                groups.push((
+                    format!("Notes in Section '{}'", self.sh_names[i]),
+                    read_notes(data, self.endianness, sheader.addralign)?,
+                ));
+            }
+        } else {
+            for (i, pheader) in self.pheaders.iter().enumerate() {
+                if pheader.r#type != SegmentType::Note {
+                    continue;
+                }
+                let data = segment_data(bytes, pheader)?;
+                groups.push((
+                    format!("Notes in Program Segment {}", i + 1),
+                    read_notes(data, self.endianness, pheader.align)?,
+                ));
+            }
+        }
+
+        Ok(groups)
+    }
+
+    fn notes(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        for (name, notes) in self.note_groups(bytes)? {
+            table.new_named_section(name);
+            self.note_entries(table, &notes)?;
+            if self.file_type == ET_CORE {
+                self.core_notes(bytes, table, &notes)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn note_entries(&self, table: &mut Table, notes: &[Note]) -> Res<()> {
+        for note in notes {
+            match (note.name.as_str(), note.r#type) {
+                (ELF_NOTE_GNU, NT_GNU_ABI_TAG) => {
+                    let mut desc = std::io::Cursor::new(&note.desc);
+                    let os = match desc.pull_via(self.endianness)? {
+                        ELF_NOTE_OS_LINUX => "Linux",
+                        ELF_NOTE_OS_GNU => "GNU",
+                        ELF_NOTE_OS_SOLARIS2 => "Solaris",
+                        ELF_NOTE_OS_FREEBSD => "FreeBsd",
+                        _ => "Unknown OS",
+                    };
+                    let [major, minor, patch]: [u32; 3] = [
+                        desc.pull_via(self.endianness)?,
+                        desc.pull_via(self.endianness)?,
+                        desc.pull_via(self.endianness)?,
+                    ];
+                    table.add_entry("GNU ABI Tag", format!("{os} {major}.{minor}.{patch}"));
+                }
+                (ELF_NOTE_GNU, NT_GNU_BUILD_ID) => {
+                    table.add_entry("GNU Build ID", fmt_hex(&note.desc));
+                }
+                (ELF_NOTE_GNU, NT_GNU_GOLD_VERSION) => {
+                    table.add_entry(
+                        "GNU Gold Version",
+                        String::from_utf8_lossy(&note.desc)
+                            .trim_end_matches('\0')
+                            .to_owned(),
+                    );
+                }
+                (ELF_NOTE_GNU, NT_GNU_PROPERTY_TYPE_0) => {
+                    self.gnu_property_entries(table, &note.desc)?
+                }
+                (ELF_NOTE_FDO, NT_FDO_PACKAGING_METADATA) => {
+                    table.add_entry(
+                        "Package Metadata",
+                        String::from_utf8_lossy(&note.desc)
+                            .trim_end_matches('\0')
+                            .to_owned(),
+                    );
+                }
+                (name @ (ELF_NOTE_CORE | ELF_NOTE_LINUX), r#type)
+                    if let Some(type_name) = coredump::note_type_name(r#type) =>
+                {
+                    table.add_entry(
+                        "Note",
+                        format!("{name} {type_name}, {} bytes", note.desc.len()),
+                    )
+                }
+                (name, r#type) => table.add_entry(
+                    "Note",
+                    format!("{name} type 0x{type:X}, {} bytes", note.desc.len()),
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn gnu_properties(&self, desc: &[u8]) -> Res<Vec<(u32, Vec<u8>)>> {
+        let align = match self.word_size.expect("word size assigned") {
+            WordSize::Four => 4,
+            WordSize::Eight => 8,
+        };
+        let len = desc.len() as u64;
+        let mut desc = std::io::Cursor::new(desc);
+        let mut properties = vec![];
+        while desc.position() < len {
+            let r#type: u32 = desc.pull_via(self.endianness)?;
+            let size: u32 = desc.pull_via(self.endianness)?;
+            let data_pos = desc.position();
+            properties.push((r#type, read_range(&mut desc, data_pos, size.into())?));
+            let padded_size = u64::from(size).div_ceil(align) * align;
+            desc.jump(data_pos + padded_size)?;
+        }
+
+        Ok(properties)
+    }
+
+    fn gnu_property_entries(&self, table: &mut Table, desc: &[u8]) -> Res<()> {
+        let is_x86 = matches!(self.machine, EM_386 | EM_X86_64);
+        for (r#type, data) in self.gnu_properties(desc)? {
+            let mut data = std::io::Cursor::new(data);
+            match r#type {
+                GNU_PROPERTY_STACK_SIZE => {
+                    let stack_size: u64 = match self.word_size.expect("word size assigned") {
+                        WordSize::Four => data.pull_via::<u32>(self.endianness)?.into(),
+                        WordSize::Eight => data.pull_via(self.endianness)?,
+                    };
+                    table.add_entry("Stack Size", format!("{stack_size} bytes"));
+                }
+                GNU_PROPERTY_NO_COPY_ON_PROTECTED => {
+                    table.add_entry("No Copy on Protected", "Yes");
+                }
+                GNU_PROPERTY_AARCH64_FEATURE_1_AND if self.machine == EM_AARCH64 => {
+                    let features: u32 = data.pull_via(self.endianness)?;
+                    table.add_entry(
+                        "AArch64 Features",
+                        fmt_flags(&[
+                            (features & GNU_PROPERTY_AARCH64_FEATURE_1_BTI > 0, "BTI"),
+                            (features & GNU_PROPERTY_AARCH64_FEATURE_1_PAC > 0, "PAC"),
+                        ]),
+                    );
+                }
+                GNU_PROPERTY_X86_FEATURE_1_AND if is_x86 => {
+                    let features: u32 = data.pull_via(self.endianness)?;
+                    table.add_entry(
+                        "x86 Features",
+                        fmt_flags(&[
+                            (features & GNU_PROPERTY_X86_FEATURE_1_IBT > 0, "IBT"),
+                            (features & GNU_PROPERTY_X86_FEATURE_1_SHSTK > 0, "SHSTK"),
+                        ]),
+                    );
+                }
+                GNU_PROPERTY_X86_ISA_1_NEEDED | GNU_PROPERTY_X86_ISA_1_USED if is_x86 => {
+                    let isa: u32 = data.pull_via(self.endianness)?;
+                    table.add_entry(
+                        if r#type == GNU_PROPERTY_X86_ISA_1_NEEDED {
+                            "x86 ISA Needed"
+                        } else {
+                            "x86 ISA Used"
+                        },
+                        fmt_flags(&[
+                            (isa & GNU_PROPERTY_X86_ISA_1_BASELINE > 0, "x86-64-baseline"),
+                            (isa & GNU_PROPERTY_X86_ISA_1_V2 > 0, "x86-64-v2"),
+                            (isa & GNU_PROPERTY_X86_ISA_1_V3 > 0, "x86-64-v3"),
+                            (isa & GNU_PROPERTY_X86_ISA_1_V4 > 0, "x86-64-v4"),
+                        ]),
+                    );
+                }
+                _ => table.add_entry(
+                    "GNU Property",
+                    format!("type 0x{type:X}, {} bytes", data.get_ref().len()),
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn security(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        let segment = |r#type: SegmentType| {
+            self.pheaders
+                .iter()
+                .find(|pheader| pheader.r#type == r#type)
+        };
+        let dynamic = match segment(SegmentType::Dynamic) {
+            Some(dynamic) => self.dynamic_entries(bytes, dynamic)?,
+            None => vec![],
+        };
+        let find = |tag: u32| {
+            dynamic
+                .iter()
+                .find(|entry| entry.tag == tag.into())
+                .map(|entry| entry.value)
+        };
+        let flags = find(DT_FLAGS).unwrap_or(0) as u32;
+        let flags_1 = find(DT_FLAGS_1).unwrap_or(0) as u32;
+        let mut symbol_names = vec![];
+        for (i, sheader) in self.sheaders.iter().enumerate() {
+            if matches!(sheader.r#type, SectionType::SymTab | SectionType::DynSym) {
+                symbol_names.extend(self.symbols(bytes, i)?.into_iter().map(|(name, _)| name));
+            }
+        }
+        symbol_names.sort_unstable();
+        symbol_names.dedup();
+
+        table.new_named_section("Security");
+        let bind_now =
+            find(DT_BIND_NOW).is_some() || flags & DF_BIND_NOW > 0 || flags_1 & DF_1_NOW > 0;
+        table.add_entry(
+            "RELRO",
+            match (segment(SegmentType::GnuRelRo), bind_now) {
+                (None, _) => "None",
+                (Some(_), false) => "Partial",
+                (Some(_), true) => "Full",
+            },
+        );
+        table.add_entry(
+            "NX",
+            match segment(SegmentType::GnuStack) {
+                Some(stack) if stack.flags & PF_X == 0 => "Enabled",
+                Some(_) => "Disabled (executable stack)",
+                None => "Disabled (no GNU_STACK segment)",
+            },
+        );
+        table.add_entry(
+            "PIE",
+            match self.file_type {
+                ET_EXEC => "No",
+                ET_DYN if flags_1 & DF_1_PIE > 0 || segment(SegmentType::Interp).is_some() => "Yes",
+                ET_DYN => "Shared Object",
+                ET_REL => "Relocatable",
+                _ => "N/A",
+            },
+        );
+        let has_symbol = |name: &str| symbol_names.iter().any(|symbol| symbol == name);
+        table.add_entry(
+            "Stack Canary",
+            if has_symbol("__stack_chk_fail") || has_symbol("__stack_chk_guard") {
+                "Yes"
+            } else {
+                "No"
+            },
+        );
+        let fortified: Vec<_> = symbol_names
+            .iter()
+            .filter(|name| name.starts_with("__") && name.ends_with("_chk"))
+            .map(String::as_str)
+            .collect();
+        table.add_entry(
+            "FORTIFY",
+            if fortified.is_empty() {
+                "No".into()
+            } else {
+                format!("Yes ({})", fortified.join(", "))
+            },
+        );
+
+        let strtab = self.dynamic_strtab(bytes, &dynamic)?;
+        for (tag, key) in [(DT_RPATH, "RPATH"), (DT_RUNPATH, "RUNPATH")] {
+            table.add_entry(
+                key,
+                match find(tag) {
+                    Some(offset) => strtab_entry(&strtab, offset)?,
+                    None => "None".into(),
+                },
+            );
+        }
+
+        let feature_property = match self.machine {
+            EM_386 | EM_X86_64 => Some(GNU_PROPERTY_X86_FEATURE_1_AND),
+            EM_AARCH64 => Some(GNU_PROPERTY_AARCH64_FEATURE_1_AND),
+            _ => None,
+        };
+        if let Some(feature_property) = feature_property {
+            let mut features = 0;
+            for (_, notes) in self.note_groups(bytes)? {
+                for note in notes {
+                    if (note.name.as_str(), note.r#type) != (ELF_NOTE_GNU, NT_GNU_PROPERTY_TYPE_0) {
+                        continue;
+                    }
+                    for (r#type, data) in self.gnu_properties(&note.desc)? {
+                        if r#type == feature_property {
+                            features |=
+                                std::io::Cursor::new(data).pull_via::<u32>(self.endianness)?;
+                        }
+                    }
+                }
+            }
+            let enabled = |bit: u32| {
+                if features & bit > 0 {
+                    "Enabled"
+                } else {
+                    "Disabled"
+                }
+            };
+            if self.machine == EM_AARCH64 {
+                table.add_entry("BTI", enabled(GNU_PROPERTY_AARCH64_FEATURE_1_BTI));
+                table.add_entry("PAC", enabled(GNU_PROPERTY_AARCH64_FEATURE_1_PAC));
+            } else {
+                table.add_entry("CET IBT", enabled(GNU_PROPERTY_X86_FEATURE_1_IBT));
+                table.add_entry("CET SHSTK", enabled(GNU_PROPERTY_X86_FEATURE_1_SHSTK));
+            }
+        }
+
         Ok(())
     }
+
+    fn relocation_info(&self, info: u64) -> (u32, u32) {
+        match self.word_size.expect("word size assigned") {
+            WordSize::Four => ((info >> 8) as u32, info as u8 as u32),
+            // MIPS64 stores r_info as sym, ssym, type3, type2 and type in file byte order
+            WordSize::Eight if self.machine == EM_MIPS => match self.endianness {
+                Endianness::Little => (info as u32, (info >> 56) as u32),
+                Endianness::Big => ((info >> 32) as u32, info as u8 as u32),
+            },
+            WordSize::Eight => ((info >> 32) as u32, info as u32),
+        }
+    }
+
+    fn read_relocations(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<Relocation>> {
+        let word_size = self.word_size.expect("word size assigned");
+        let sheader = &self.sheaders[idx];
+        let has_addend = sheader.r#type == SectionType::Rela;
+        let data = self.section_data(bytes, idx)?;
+        let entry_size = Relocation::size_of(word_size, has_addend).max(
+            sheader
+                .entsize
+                .try_into()
+                .expect("entsize is within usize::MAX"),
+        );
+        let count = data.len() / entry_size;
+        let mut data = std::io::Cursor::new(data);
+        (0..count)
+            .map(|i| {
+                data.jump((i * entry_size) as u64)?;
+                data.pull_via((self.encoding(), has_addend))
+            })
+            .collect()
+    }
+
+    // contents of a section with the relocations of a relocatable object applied,
+    // as far as debug information and unwind tables need them
+    fn relocated_section_data(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<u8>> {
+        let mut data = self.section_data(bytes, idx)?;
+        if self.file_type != ET_REL {
+            return Ok(data);
+        }
+        for (rel_idx, rel_header) in self.sheaders.iter().enumerate() {
+            if !matches!(rel_header.r#type, SectionType::Rel | SectionType::Rela)
+                || rel_header.info as usize != idx
+            {
+                continue;
+            }
+            let symbols = self.linked_symbols(bytes, rel_header)?;
+            for relocation in self.read_relocations(bytes, rel_idx)? {
+                let (symbol_idx, r#type) = self.relocation_info(relocation.info);
+                let (size, place) = if let Some(size) = reloc::absolute_size(self.machine, r#type) {
+                    (size, 0)
+                } else if let Some(size) = reloc::relative_size(self.machine, r#type) {
+                    (size, relocation.offset)
+                } else {
+                    continue;
+                };
+                let Some(target) = usize::try_from(relocation.offset)
+                    .ok()
+                    .and_then(|offset| data.get_mut(offset..offset.checked_add(size)?))
+                else {
+                    unknown!()
+                };
+                let addend = match relocation.addend {
+                    Some(addend) => addend as u64,
+                    None => read_sized(target, self.endianness),
+                };
+                let symbol_value = match symbols.get(symbol_idx as usize) {
+                    Some((_, symbol)) => symbol.value,
+                    None => unknown!(),
+                };
+                write_sized(
+                    target,
+                    symbol_value.wrapping_add(addend).wrapping_sub(place),
+                    self.endianness,
+                );
+            }
+        }
+        Ok(data)
+    }
+
+    // contents of a section, decompressed if it is SHF_COMPRESSED or a GNU style .zdebug section
+    fn section_data(&self, bytes: &mut impl Bytes, idx: usize) -> Res<Vec<u8>> {
+        let Some(sheader) = self.sheaders.get(idx) else {
+            unknown!()
+        };
+        let data = raw_section_data(bytes, sheader)?;
+        let Some((compression, size, header_size)) = self.compression(&data, idx)? else {
+            return Ok(data);
+        };
+        let mut data = std::io::Cursor::new(data);
+        data.jump(header_size)?;
+        let decompressed = match compression {
+            ELFCOMPRESS_ZLIB => inflate::zlib(&mut data)?,
+            ELFCOMPRESS_ZSTD => zstd::decompress(&mut data)?,
+            _ => unknown!(),
+        };
+        if decompressed.len() as u64 != size {
+            unknown!();
+        }
+        Ok(decompressed)
+    }
+
+    // compression type, uncompressed size and header size of a compressed section
+    fn compression(&self, data: &[u8], idx: usize) -> Res<Option<(u32, u64, u64)>> {
+        let mut data = std::io::Cursor::new(data);
+        if self.sheaders[idx].flags & SHF_COMPRESSED > 0 {
+            let header: CompressionHeader = data.pull_via(self.encoding())?;
+            return Ok(Some((header.r#type, header.size, data.position())));
+        }
+        if self.sh_names[idx].starts_with(".zdebug") && data.get_ref().starts_with(b"ZLIB") {
+            data.forward(4)?;
+            let size = data.pull_via(Endianness::Big)?;
+            return Ok(Some((ELFCOMPRESS_ZLIB, size, data.position())));
+        }
+        Ok(None)
+    }
+
+    // name of the section holding a file offset, and the offset within it
+    fn section_at_offset(&self, offset: u64) -> Option<String> {
+        self.sheaders
+            .iter()
+            .zip(&self.sh_names)
+            .skip(1)
+            .find(|(sheader, _)| {
+                sheader.r#type != SectionType::NoBits
+                    && (sheader.offset..sheader.offset.saturating_add(sheader.size))
+                        .contains(&offset)
+            })
+            .map(|(sheader, name)| format!("{name}+0x{:X}", offset - sheader.offset))
+    }
+
+    fn section_named(&self, name: &str) -> Option<usize> {
+        self.sh_names.iter().position(|sh_name| sh_name == name)
+    }
+
+    // signature, flags and member section indexes of a SHT_GROUP section
+    fn group_members(&self, bytes: &mut impl Bytes, idx: usize) -> Res<(String, u32, Vec<u32>)> {
+        let sheader = &self.sheaders[idx];
+        if self
+            .sheaders
+            .get(sheader.link as usize)
+            .is_none_or(|link| link.r#type != SectionType::SymTab)
+        {
+            unknown!();
+        }
+        let symbols = self.symbols(bytes, sheader.link as usize)?;
+        let signature = match symbols.get(sheader.info as usize) {
+            Some((name, symbol)) if name.is_empty() && symbol.r#type() == STT_SECTION => {
+                match self.sh_names.get(symbol.shndx as usize) {
+                    Some(name) => name.clone(),
+                    None => unknown!(),
+                }
+            }
+            Some((name, _)) => name.clone(),
+            None => unknown!(),
+        };
+        let mut data = std::io::Cursor::new(self.section_data(bytes, idx)?);
+        let flags: u32 = data.pull_via(self.endianness)?;
+        let members = (1..sheader.size / 4)
+            .map(|_| data.pull_via::<u32>(self.endianness))
+            .collect::<Res<Vec<_>>>()?;
+        Ok((signature, flags, members))
+    }
+
+    // SHT_GROUP sections of a relocatable object, which the linker keeps or discards as a
+    // whole and leaves out of its output, then the groups again from largest to smallest to
+    // show where COMDAT duplication costs the most
+    fn section_groups(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        if self.file_type != ET_REL {
+            return Ok(());
+        }
+        let group_idxs: Vec<_> = (0..self.sheaders.len())
+            .filter(|&i| self.sheaders[i].r#type == SectionType::Group)
+            .collect();
+        let mut sizes = vec![];
+        for (i, &idx) in group_idxs.iter().enumerate() {
+            table.new_named_section(format!(
+                "Section Group {}/{} '{}'",
+                i + 1,
+                group_idxs.len(),
+                self.sh_names[idx]
+            ));
+            let (signature, flags, members) = match self.group_members(bytes, idx) {
+                Ok(group) => group,
+                Err(err) => {
+                    table.add_entry("Error", err.kind());
+                    continue;
+                }
+            };
+            let size = members
+                .iter()
+                .filter_map(|&member| self.sheaders.get(member as usize))
+                .fold(0u64, |size, member| size.saturating_add(member.size));
+
+            table.add_entry("Signature", signature.clone());
+            table.add_entry("Flags", fmt_flags(&[(flags & GRP_COMDAT > 0, "COMDAT")]));
+            table.add_entry("Size", format!("{size} bytes"));
+            for &member in &members {
+                table.add_entry(
+                    format!("Section {member}"),
+                    match (
+                        self.sh_names.get(member as usize),
+                        self.sheaders.get(member as usize),
+                    ) {
+                        (Some(name), Some(member)) => format!("{name} ({} bytes)", member.size),
+                        _ => "Invalid section index".into(),
+                    },
+                );
+            }
+            sizes.push((i + 1, signature, size, members.len()));
+        }
+
+        if !sizes.is_empty() {
+            sizes.sort_by_key(|&(_, _, size, _)| std::cmp::Reverse(size));
+            table.new_named_section(format!(
+                "Section Groups by Size ({} groups, {} bytes)",
+                sizes.len(),
+                sizes
+                    .iter()
+                    .fold(0u64, |total, &(_, _, size, _)| total.saturating_add(size))
+            ));
+            for (number, signature, size, count) in sizes {
+                table.add_entry(
+                    format!("Group {number}"),
+                    format!("{size} bytes in {count} sections: {signature}"),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn relocations(&self, bytes: &mut impl Bytes, table: &mut Table, idx: usize) -> Res<()> {
+        let word_size = self.word_size.expect("word size assigned");
+        let sheader = &self.sheaders[idx];
+        let has_addend = sheader.r#type == SectionType::Rela;
+        let symbols = self.linked_symbols(bytes, sheader)?;
+        let relocations = self.read_relocations(bytes, idx)?;
+
+        table.new_named_section(format!(
+            "Relocation Section '{}' ({} entries)",
+            self.sh_names[idx],
+            relocations.len()
+        ));
+        let offset_width = match word_size {
+            WordSize::Four => 10,
+            WordSize::Eight => 18,
+        };
+        table.add_entry(
+            "Num",
+            format!(
+                "{:<offset_width$} {:<24} Symbol{}",
+                "Offset",
+                "Type",
+                if has_addend { " + Addend" } else { "" }
+            ),
+        );
+        for (i, relocation) in relocations.iter().enumerate() {
+            let (symbol_idx, r#type) = self.relocation_info(relocation.info);
+            let type_name: Str = match reloc::type_name(self.machine, r#type) {
+                Some(name) => name.into(),
+                None => format!("0x{type:X}").into(),
+            };
+            let invalid;
+            let symbol_name = match symbols.get(symbol_idx as usize) {
+                _ if symbol_idx == 0 => "",
+                Some((name, symbol)) if name.is_empty() && symbol.r#type() == STT_SECTION => self
+                    .sh_names
+                    .get(symbol.shndx as usize)
+                    .map_or("", String::as_str),
+                Some((name, _)) => name,
+                // one bad index should not hide the rest of the section
+                None => {
+                    invalid = format!("(invalid symbol index {symbol_idx})");
+                    &invalid
+                }
+            };
+            let mut row = format!(
+                "{:<offset_width$} {type_name:<24} {symbol_name}",
+                format!("0x{:0w$X}", relocation.offset, w = offset_width - 2),
+            );
+            if let Some(addend) = relocation.addend {
+                if !symbol_name.is_empty() {
+                    row.push(' ');
+                }
+                row.push_str(&fmt_addend(addend));
+            }
+            table.add_entry(i.to_string(), row);
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn fmt_addend(addend: i64) -> String {
+    if addend < 0 {
+        format!("- 0x{:X}", addend.unsigned_abs())
+    } else {
+        format!("+ 0x{addend:X}")
+    }
+}
+
+fn fmt_flags(flags: &[(bool, &'static str)]) -> Str {
+    let flags: String = flags
+        .iter()
+        .filter_map(|&(enabled, flag)| enabled.then_some(flag))
+        .enumerate()
+        .flat_map(|(i, flag)| [if i == 0 { "" } else { ", " }, flag])
+        .collect();
+    if flags.is_empty() {
+        "None".into()
+    } else {
+        flags.into()
+    }
+}
+
+fn read_notes(data: Vec<u8>, endianness: Endianness, align: u64) -> Res<Vec<Note>> {
+    let align = if align == 8 { 8 } else { 4 };
+    let len = data.len() as u64;
+    let mut data = std::io::Cursor::new(data);
+    let mut notes = vec![];
+    while data.position() < len {
+        notes.push(data.pull_via((endianness, align))?);
+    }
+    Ok(notes)
+}
+
+// mirrors binutils' ELF_SECTION_IN_SEGMENT_STRICT
+fn section_in_segment(sheader: &SectionHeader, pheader: &ProgramHeader) -> bool {
+    let is_tls = sheader.flags & SHF_TLS > 0;
+    let is_alloc = sheader.flags & SHF_ALLOC > 0;
+    let is_nobits = sheader.r#type == SectionType::NoBits;
+    // .tbss is only listed in the TLS segment
+    if is_tls && is_nobits && pheader.r#type != SegmentType::Tls {
+        return false;
+    }
+
+    let type_matches = if is_tls {
+        matches!(
+            pheader.r#type,
+            SegmentType::Tls | SegmentType::GnuRelRo | SegmentType::Load
+        )
+    } else {
+        !matches!(pheader.r#type, SegmentType::Tls | SegmentType::PHdr)
+    };
+    let alloc_matches = is_alloc
+        || !matches!(
+            pheader.r#type,
+            SegmentType::Load
+                | SegmentType::Dynamic
+                | SegmentType::GnuEhFrame
+                | SegmentType::GnuStack
+                | SegmentType::GnuRelRo
+        );
+    let offset_matches = is_nobits
+        || (sheader.offset >= pheader.offset
+            && sheader.offset - pheader.offset <= pheader.filesz.wrapping_sub(1)
+            && (sheader.offset - pheader.offset).saturating_add(sheader.size) <= pheader.filesz);
+    let addr_matches = !is_alloc
+        || (sheader.addr >= pheader.vaddr
+            && sheader.addr - pheader.vaddr <= pheader.memsz.wrapping_sub(1)
+            && (sheader.addr - pheader.vaddr).saturating_add(sheader.size) <= pheader.memsz);
+    // empty sections on the edges of DYNAMIC and NOTE segments belong elsewhere
+    let edge_matches = !matches!(pheader.r#type, SegmentType::Dynamic | SegmentType::Note)
+        || sheader.size != 0
+        || pheader.memsz == 0
+        || ((is_nobits
+            || (sheader.offset > pheader.offset
+                && sheader.offset - pheader.offset < pheader.filesz))
+            && (!is_alloc
+                || (sheader.addr > pheader.vaddr && sheader.addr - pheader.vaddr < pheader.memsz)));
+
+    type_matches && alloc_matches && offset_matches && addr_matches && edge_matches
+}
+
+fn pull_word(bytes: &mut impl Bytes, encoding: Encoding) -> Res<u64> {
+    Ok(match encoding.word_size {
+        WordSize::Four => bytes.pull_via::<u32>(encoding.endianness)?.into(),
+        WordSize::Eight => bytes.pull_via(encoding.endianness)?,
+    })
+}
+
+fn read_sized(bytes: &[u8], endianness: Endianness) -> u64 {
+    let mut value = [0; 8];
+    match endianness {
+        Endianness::Little => {
+            value[..bytes.len()].copy_from_slice(bytes);
+            u64::from_le_bytes(value)
+        }
+        Endianness::Big => {
+            value[8 - bytes.len()..].copy_from_slice(bytes);
+            u64::from_be_bytes(value)
+        }
+    }
+}
+
+fn write_sized(bytes: &mut [u8], value: u64, endianness: Endianness) {
+    let len = bytes.len();
+    match endianness {
+        Endianness::Little => bytes.copy_from_slice(&value.to_le_bytes()[..len]),
+        Endianness::Big => bytes.copy_from_slice(&value.to_be_bytes()[8 - len..]),
+    }
+}
+
+fn read_range(bytes: &mut impl Bytes, offset: u64, size: u64) -> Res<Vec<u8>> {
+    // sizes come from headers, so check them against the file before allocating
+    let len = bytes.seek(std::io::SeekFrom::End(0))?;
+    if offset.checked_add(size).is_none_or(|end| end > len) {
+        unknown!();
+    }
+    bytes.jump(offset)?;
+    let mut data = vec![0; size.try_into().expect("size is within usize::MAX")];
+    bytes.read_exact(&mut data)?;
+    Ok(data)
+}
+
+fn raw_section_data(bytes: &mut impl Bytes, sheader: &SectionHeader) -> Res<Vec<u8>> {
+    if sheader.r#type == SectionType::NoBits {
+        return Ok(vec![]);
+    }
+    read_range(bytes, sheader.offset, sheader.size)
+}
+
+fn segment_data(bytes: &mut impl Bytes, pheader: &ProgramHeader) -> Res<Vec<u8>> {
+    read_range(bytes, pheader.offset, pheader.filesz)
 }
@@ -0,0 +1,394 @@
+use super::{Note, Parser, SegmentType, WordSize, pull_word};
+use crate::{
+    elf_header::*,
+    error::Res,
+    parse::{Bytes, Str, Table},
+};
+
+const X86_64_REGISTERS: [&str; 27] = [
+    "r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8", "rax", "rcx", "rdx", "rsi",
+    "rdi", "orig_rax", "rip", "cs", "eflags", "rsp", "ss", "fs_base", "gs_base", "ds", "es", "fs",
+    "gs",
+];
+
+const I386_REGISTERS: [&str; 17] = [
+    "ebx", "ecx", "edx", "esi", "edi", "ebp", "eax", "ds", "es", "fs", "gs", "orig_eax", "eip",
+    "cs", "eflags", "esp", "ss",
+];
+
+const AARCH64_REGISTERS: [&str; 34] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "sp", "pc", "pstate",
+];
+
+pub fn note_type_name(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        NT_PRSTATUS => "NT_PRSTATUS",
+        NT_FPREGSET => "NT_FPREGSET",
+        NT_PRPSINFO => "NT_PRPSINFO",
+        NT_TASKSTRUCT => "NT_TASKSTRUCT",
+        NT_AUXV => "NT_AUXV",
+        NT_PRXFPREG => "NT_PRXFPREG",
+        NT_SIGINFO => "NT_SIGINFO",
+        NT_FILE => "NT_FILE",
+        NT_PPC_VMX => "NT_PPC_VMX",
+        NT_PPC_SPE => "NT_PPC_SPE",
+        NT_PPC_VSX => "NT_PPC_VSX",
+        NT_386_TLS => "NT_386_TLS",
+        NT_386_IOPERM => "NT_386_IOPERM",
+        NT_X86_XSTATE => "NT_X86_XSTATE",
+        _ => return None,
+    })
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let len = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+impl Parser {
+    pub(super) fn core_notes(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        notes: &[Note],
+    ) -> Res<()> {
+        let mut thread_count = 0;
+        for note in notes.iter().filter(|note| note.name == ELF_NOTE_CORE) {
+            match note.r#type {
+                NT_PRSTATUS => {
+                    thread_count += 1;
+                    self.prstatus(table, thread_count, &note.desc)?;
+                }
+                NT_PRPSINFO => self.prpsinfo(table, &note.desc)?,
+                NT_SIGINFO => self.siginfo(table, &note.desc)?,
+                NT_AUXV => self.auxv(bytes, table, &note.desc)?,
+                NT_FILE => self.mapped_files(table, &note.desc)?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn signal_name(&self, signal: u32) -> Str {
+        // MIPS, SPARC and Alpha use their own signal numbering
+        let name = match self.machine {
+            EM_MIPS | EM_MIPS_RS3_LE | EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 | EM_ALPHA
+            | EM_FAKE_ALPHA => None,
+            _ => [
+                "SIGHUP",
+                "SIGINT",
+                "SIGQUIT",
+                "SIGILL",
+                "SIGTRAP",
+                "SIGABRT",
+                "SIGBUS",
+                "SIGFPE",
+                "SIGKILL",
+                "SIGUSR1",
+                "SIGSEGV",
+                "SIGUSR2",
+                "SIGPIPE",
+                "SIGALRM",
+                "SIGTERM",
+                "SIGSTKFLT",
+                "SIGCHLD",
+                "SIGCONT",
+                "SIGSTOP",
+                "SIGTSTP",
+                "SIGTTIN",
+                "SIGTTOU",
+                "SIGURG",
+                "SIGXCPU",
+                "SIGXFSZ",
+                "SIGVTALRM",
+                "SIGPROF",
+                "SIGWINCH",
+                "SIGIO",
+                "SIGPWR",
+                "SIGSYS",
+            ]
+            .get((signal as usize).wrapping_sub(1)),
+        };
+        match name {
+            Some(name) => format!("{name} ({signal})").into(),
+            None => signal.to_string().into(),
+        }
+    }
+
+    fn prstatus(&self, table: &mut Table, thread: usize, desc: &[u8]) -> Res<()> {
+        let encoding = self.encoding();
+        let mut desc = std::io::Cursor::new(desc);
+        desc.forward(3 * size_of::<u32>())?; // pr_info
+        let signal: u16 = desc.pull_via(self.endianness)?;
+        desc.forward(size_of::<u16>())?; // padding
+        pull_word(&mut desc, encoding)?; // pending signals
+        pull_word(&mut desc, encoding)?; // held signals
+        let [pid, ppid, _pgrp, _sid]: [u32; 4] = [
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+        ];
+        for _ in 0..8 {
+            pull_word(&mut desc, encoding)?; // user and system times
+        }
+
+        table.new_named_section(format!("Thread {thread} (LWP {pid})"));
+        table.add_entry("Signal", self.signal_name(signal.into()));
+        table.add_entry("Parent PID", ppid.to_string());
+        let registers: &[&str] = match self.machine {
+            EM_X86_64 => &X86_64_REGISTERS,
+            EM_386 => &I386_REGISTERS,
+            EM_AARCH64 => &AARCH64_REGISTERS,
+            _ => &[],
+        };
+        for register in registers {
+            table.add_entry(*register, self.fmt_word(pull_word(&mut desc, encoding)?));
+        }
+
+        Ok(())
+    }
+
+    fn prpsinfo(&self, table: &mut Table, desc: &[u8]) -> Res<()> {
+        let encoding = self.encoding();
+        let word_size = encoding.word_size;
+        // 32-bit x86 and ARM store 16-bit ids, which shrinks the structure to 124 bytes
+        let narrow_ids = matches!(word_size, WordSize::Four) && desc.len() == 124;
+        let mut desc = std::io::Cursor::new(desc);
+        let state: u8 = desc.pull_via(self.endianness)?;
+        let state_name: u8 = desc.pull_via(self.endianness)?;
+        let _zombie: u8 = desc.pull_via(self.endianness)?;
+        let nice = desc.pull_via::<u8>(self.endianness)? as i8;
+        match word_size {
+            WordSize::Four => desc.forward(size_of::<u32>())?,
+            WordSize::Eight => desc.forward(size_of::<u32>() + size_of::<u64>())?,
+        } // padding and flags
+        let [uid, gid]: [u32; 2] = if narrow_ids {
+            [
+                desc.pull_via::<u16>(self.endianness)?.into(),
+                desc.pull_via::<u16>(self.endianness)?.into(),
+            ]
+        } else {
+            [
+                desc.pull_via(self.endianness)?,
+                desc.pull_via(self.endianness)?,
+            ]
+        };
+        let [pid, ppid, _pgrp, _sid]: [u32; 4] = [
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+        ];
+        let command: [u8; 16] = desc.pull()?;
+        let args: [u8; 80] = desc.pull()?;
+
+        table.new_named_section("Process Info");
+        table.add_entry("Command", c_string(&command));
+        table.add_entry("Arguments", c_string(&args).trim_end().to_owned());
+        table.add_entry("PID", pid.to_string());
+        table.add_entry("Parent PID", ppid.to_string());
+        table.add_entry("UID", uid.to_string());
+        table.add_entry("GID", gid.to_string());
+        table.add_entry("State", format!("{} ({state})", char::from(state_name)));
+        table.add_entry("Nice", nice.to_string());
+
+        Ok(())
+    }
+
+    fn siginfo(&self, table: &mut Table, desc: &[u8]) -> Res<()> {
+        let encoding = self.encoding();
+        let word_size = encoding.word_size;
+        let mut desc = std::io::Cursor::new(desc);
+        let [signal, errno, code]: [u32; 3] = [
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+            desc.pull_via(self.endianness)?,
+        ];
+        if let WordSize::Eight = word_size {
+            desc.forward(size_of::<u32>())?; // padding
+        }
+
+        table.new_named_section("Signal Info");
+        table.add_entry("Signal", self.signal_name(signal));
+        let code = code as i32;
+        table.add_entry(
+            "Code",
+            match code {
+                0 => "SI_USER (0)".into(),
+                0x80 => "SI_KERNEL (128)".into(),
+                -1 => "SI_QUEUE (-1)".into(),
+                -6 => "SI_TKILL (-6)".into(),
+                1 if signal == 11 => "SEGV_MAPERR (1)".into(),
+                2 if signal == 11 => "SEGV_ACCERR (2)".into(),
+                code => code.to_string(),
+            },
+        );
+        if errno != 0 {
+            table.add_entry("Errno", errno.to_string());
+        }
+        // SIGILL, SIGTRAP, SIGBUS, SIGFPE and SIGSEGV raised by the kernel carry the faulting address
+        if matches!(signal, 4 | 5 | 7 | 8 | 11) && code > 0 {
+            table.add_entry(
+                "Fault Address",
+                self.fmt_word(pull_word(&mut desc, encoding)?),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn memory_string(&self, bytes: &mut impl Bytes, vaddr: u64) -> Res<Option<String>> {
+        let Some(offset) = self.vaddr_to_offset(vaddr) else {
+            return Ok(None);
+        };
+        bytes.jump(offset)?;
+        Ok(Some(
+            bytes
+                .pull::<std::ffi::CString>()?
+                .to_string_lossy()
+                .into_owned(),
+        ))
+    }
+
+    fn auxv(&self, bytes: &mut impl Bytes, table: &mut Table, desc: &[u8]) -> Res<()> {
+        let encoding = self.encoding();
+        let len = desc.len() as u64;
+        let mut desc = std::io::Cursor::new(desc);
+        let mut entries = vec![];
+        while desc.position() < len {
+            let r#type = pull_word(&mut desc, encoding)?;
+            let value = pull_word(&mut desc, encoding)?;
+            if r#type == AT_NULL {
+                break;
+            }
+            entries.push((r#type, value));
+        }
+
+        table.new_named_section(format!("Auxiliary Vector ({} entries)", entries.len()));
+        for (r#type, value) in entries {
+            let addr = || self.fmt_word(value);
+            let string = |bytes: &mut _| -> Res<String> {
+                Ok(self.memory_string(bytes, value)?.unwrap_or_else(addr))
+            };
+            let (key, value): (Str, String) = match r#type {
+                AT_IGNORE => ("IGNORE".into(), addr()),
+                AT_EXECFD => ("EXECFD".into(), value.to_string()),
+                AT_PHDR => ("PHDR".into(), addr()),
+                AT_PHENT => ("PHENT".into(), format!("{value} bytes")),
+                AT_PHNUM => ("PHNUM".into(), value.to_string()),
+                AT_PAGESZ => ("PAGESZ".into(), format!("{value} bytes")),
+                AT_BASE => ("BASE".into(), addr()),
+                AT_FLAGS => ("FLAGS".into(), addr()),
+                AT_ENTRY => ("ENTRY".into(), addr()),
+                AT_NOTELF => ("NOTELF".into(), value.to_string()),
+                AT_UID => ("UID".into(), value.to_string()),
+                AT_EUID => ("EUID".into(), value.to_string()),
+                AT_GID => ("GID".into(), value.to_string()),
+                AT_EGID => ("EGID".into(), value.to_string()),
+                AT_PLATFORM => ("PLATFORM".into(), string(bytes)?),
+                AT_HWCAP => ("HWCAP".into(), addr()),
+                AT_CLKTCK => ("CLKTCK".into(), value.to_string()),
+                AT_FPUCW => ("FPUCW".into(), addr()),
+                AT_DCACHEBSIZE => ("DCACHEBSIZE".into(), format!("{value} bytes")),
+                AT_ICACHEBSIZE => ("ICACHEBSIZE".into(), format!("{value} bytes")),
+                AT_UCACHEBSIZE => ("UCACHEBSIZE".into(), format!("{value} bytes")),
+                AT_SECURE => ("SECURE".into(), value.to_string()),
+                AT_BASE_PLATFORM => ("BASE_PLATFORM".into(), string(bytes)?),
+                AT_RANDOM => ("RANDOM".into(), addr()),
+                AT_HWCAP2 => ("HWCAP2".into(), addr()),
+                AT_RSEQ_FEATURE_SIZE => ("RSEQ_FEATURE_SIZE".into(), format!("{value} bytes")),
+                AT_RSEQ_ALIGN => ("RSEQ_ALIGN".into(), format!("{value} bytes")),
+                AT_HWCAP3 => ("HWCAP3".into(), addr()),
+                AT_HWCAP4 => ("HWCAP4".into(), addr()),
+                AT_EXECFN => ("EXECFN".into(), string(bytes)?),
+                AT_SYSINFO => ("SYSINFO".into(), addr()),
+                AT_SYSINFO_EHDR => ("SYSINFO_EHDR".into(), addr()),
+                AT_L1I_CACHESHAPE => ("L1I_CACHESHAPE".into(), addr()),
+                AT_L1D_CACHESHAPE => ("L1D_CACHESHAPE".into(), addr()),
+                AT_L2_CACHESHAPE => ("L2_CACHESHAPE".into(), addr()),
+                AT_L3_CACHESHAPE => ("L3_CACHESHAPE".into(), addr()),
+                AT_MINSIGSTKSZ => ("MINSIGSTKSZ".into(), format!("{value} bytes")),
+                _ => (r#type.to_string().into(), addr()),
+            };
+            table.add_entry(key, value);
+        }
+
+        Ok(())
+    }
+
+    fn mapped_files(&self, table: &mut Table, desc: &[u8]) -> Res<()> {
+        let encoding = self.encoding();
+        let word_size = encoding.word_size;
+        let mut desc = std::io::Cursor::new(desc);
+        let count = pull_word(&mut desc, encoding)?;
+        let page_size = pull_word(&mut desc, encoding)?;
+        let mut ranges = vec![];
+        for _ in 0..count {
+            ranges.push([
+                pull_word(&mut desc, encoding)?,
+                pull_word(&mut desc, encoding)?,
+                pull_word(&mut desc, encoding)?,
+            ]);
+        }
+
+        table.new_named_section(format!("Mapped Files ({count} entries)"));
+        let width = match word_size {
+            WordSize::Four => 10,
+            WordSize::Eight => 18,
+        };
+        table.add_entry(
+            "Num",
+            format!(
+                "{:<width$} {:<width$} {:<width$} {:>7} {:<7} Path",
+                "Start", "End", "Offset", "Segment", "Dumped"
+            ),
+        );
+        for (i, [start, end, page_offset]) in ranges.into_iter().enumerate() {
+            let path = desc.pull::<std::ffi::CString>()?;
+            let (Some(size), Some(offset)) =
+                (end.checked_sub(start), page_offset.checked_mul(page_size))
+            else {
+                table.add_entry(
+                    i.to_string(),
+                    format!("Invalid mapping of {}", path.to_string_lossy()),
+                );
+                continue;
+            };
+            // the kernel writes one PT_LOAD per mapping, so the start addresses line up
+            let segment =
+                self.pheaders.iter().enumerate().find(|(_, pheader)| {
+                    pheader.r#type == SegmentType::Load && pheader.vaddr == start
+                });
+            let (segment, dumped): (Str, _) = match segment {
+                Some((idx, pheader)) => (
+                    (idx + 1).to_string().into(),
+                    match pheader.filesz {
+                        0 => "No",
+                        filesz if filesz < size => "Partial",
+                        _ => "Yes",
+                    },
+                ),
+                None => ("-".into(), "No"),
+            };
+            table.add_entry(
+                i.to_string(),
+                format!(
+                    "{:<width$} {:<width$} {:<width$} {segment:>7} {dumped:<7} {}",
+                    self.fmt_word(start),
+                    self.fmt_word(end),
+                    self.fmt_word(offset),
+                    path.to_string_lossy(),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
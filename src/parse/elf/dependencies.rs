@@ -0,0 +1,335 @@
+use super::{Parser, SegmentType, WordSize, matching_magic, strtab_entry};
+use crate::{
+    elf_header::*,
+    error::Res,
+    parse::{Bytes, Options, Table},
+};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+// nested includes deeper than this are assumed to be a cycle
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+// what the dynamic section of an object says about loading its dependencies
+#[derive(Default)]
+struct Object {
+    path: PathBuf,
+    needed: Vec<String>,
+    rpath: Option<String>,
+    runpath: Option<String>,
+    nodeflib: bool,
+}
+
+impl Object {
+    // search directories of RPATH or RUNPATH, with $ORIGIN and $LIB substituted
+    fn search_dirs(&self, list: &str, word_size: WordSize, sysroot: &Path) -> Vec<PathBuf> {
+        let origin = self.path.parent().unwrap_or(Path::new("."));
+        let lib = match word_size {
+            WordSize::Four => "lib",
+            WordSize::Eight => "lib64",
+        };
+        list.split(':')
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| {
+                let dir = dir.replace("${LIB}", lib).replace("$LIB", lib);
+                match dir
+                    .strip_prefix("${ORIGIN}")
+                    .or_else(|| dir.strip_prefix("$ORIGIN"))
+                {
+                    // the origin is where the object was found, which already includes the sysroot
+                    Some(rest) => origin.join(rest.trim_start_matches('/')),
+                    None => in_sysroot(sysroot, Path::new(&dir)),
+                }
+            })
+            .collect()
+    }
+}
+
+fn in_sysroot(sysroot: &Path, path: &Path) -> PathBuf {
+    match path.strip_prefix("/") {
+        Ok(relative) => sysroot.join(relative),
+        Err(_) => path.to_owned(),
+    }
+}
+
+// matches file names against a pattern with `*` wildcards, as used by ld.so.conf includes
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return false;
+    };
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<_> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
+
+// directories listed by ld.so.conf and the files it includes, which ldconfig puts in its cache
+fn ld_so_conf_dirs(sysroot: &Path, conf: &Path, depth: usize, dirs: &mut Vec<PathBuf>) {
+    let Ok(contents) = std::fs::read_to_string(in_sysroot(sysroot, conf)) else {
+        return;
+    };
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if let Some(pattern) = line.strip_prefix("include") {
+            if depth >= MAX_INCLUDE_DEPTH {
+                continue;
+            }
+            for pattern in pattern.split_whitespace() {
+                let pattern = conf.parent().unwrap_or(Path::new("/")).join(pattern);
+                let (Some(dir), Some(file_pattern)) = (
+                    pattern.parent(),
+                    pattern.file_name().and_then(|name| name.to_str()),
+                ) else {
+                    continue;
+                };
+                let Ok(entries) = std::fs::read_dir(in_sysroot(sysroot, dir)) else {
+                    continue;
+                };
+                let mut names: Vec<_> = entries
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .filter(|name| wildcard_match(file_pattern, name))
+                    .collect();
+                names.sort();
+                for name in names {
+                    ld_so_conf_dirs(sysroot, &dir.join(name), depth + 1, dirs);
+                }
+            }
+        } else if !line.is_empty() && !line.starts_with("hwcap") {
+            dirs.extend(
+                line.split([' ', '\t', ',', ':'])
+                    .filter(|dir| !dir.is_empty())
+                    .map(|dir| in_sysroot(sysroot, Path::new(dir))),
+            );
+        }
+    }
+}
+
+struct Search<'a> {
+    sysroot: &'a Path,
+    library_path: Vec<PathBuf>,
+    system_dirs: Vec<PathBuf>,
+    default_dirs: Vec<PathBuf>,
+    missing: usize,
+    visited: HashSet<PathBuf>,
+}
+
+impl Parser {
+    fn dependency_object(&self, bytes: &mut impl Bytes, path: PathBuf) -> Res<Object> {
+        let mut object = Object {
+            path,
+            ..Default::default()
+        };
+        let Some(dynamic) = self
+            .pheaders
+            .iter()
+            .find(|pheader| pheader.r#type == SegmentType::Dynamic)
+        else {
+            return Ok(object);
+        };
+        let entries = self.dynamic_entries(bytes, dynamic)?;
+        let strtab = self.dynamic_strtab(bytes, &entries)?;
+        for entry in entries {
+            match u32::try_from(entry.tag) {
+                Ok(DT_NEEDED) => object.needed.push(strtab_entry(&strtab, entry.value)?),
+                Ok(DT_RPATH) => object.rpath = Some(strtab_entry(&strtab, entry.value)?),
+                Ok(DT_RUNPATH) => object.runpath = Some(strtab_entry(&strtab, entry.value)?),
+                Ok(DT_FLAGS_1) => object.nodeflib = entry.value & u64::from(DF_1_NODEFLIB) > 0,
+                _ => {}
+            }
+        }
+        Ok(object)
+    }
+
+    // the dynamic loader skips files that are not ELF or are built for another machine
+    fn load_dependency(&self, path: &Path) -> Option<Object> {
+        let file = std::fs::File::open(path).ok()?;
+        if !file.metadata().ok()?.is_file() {
+            return None;
+        }
+        let mut bytes = std::io::BufReader::new(file);
+        if !matching_magic(&mut bytes).ok()? {
+            return None;
+        }
+        bytes.jump(0).ok()?;
+        let mut parser = Parser::default();
+        parser.header(&mut bytes, &mut Table::default()).ok()?;
+        if parser.word_size != self.word_size
+            || parser.endianness != self.endianness
+            || parser.machine != self.machine
+        {
+            return None;
+        }
+        parser.read_pheaders(&mut bytes).ok()?;
+        parser.dependency_object(&mut bytes, path.to_owned()).ok()
+    }
+
+    // follows the order of the glibc dynamic loader: RPATH of the requesting object and its
+    // loaders unless it has a RUNPATH, the library path, RUNPATH, then the cache and defaults
+    fn find_dependency(
+        &self,
+        name: &str,
+        chain: &[Object],
+        search: &Search,
+    ) -> Option<(Object, &'static str)> {
+        let word_size = self.word_size.expect("word size assigned");
+        let requester = chain.last().expect("chain starts with the inspected file");
+        if name.contains('/') {
+            return self
+                .load_dependency(&in_sysroot(search.sysroot, Path::new(name)))
+                .map(|object| (object, "path"));
+        }
+
+        let mut candidates: Vec<(PathBuf, &'static str)> = vec![];
+        if requester.runpath.is_none() {
+            for object in chain.iter().rev() {
+                if let (Some(rpath), None) = (&object.rpath, &object.runpath) {
+                    candidates.extend(
+                        object
+                            .search_dirs(rpath, word_size, search.sysroot)
+                            .into_iter()
+                            .map(|dir| (dir, "RPATH")),
+                    );
+                }
+            }
+        }
+        candidates.extend(
+            search
+                .library_path
+                .iter()
+                .map(|dir| (dir.clone(), "library path")),
+        );
+        if let Some(runpath) = &requester.runpath {
+            candidates.extend(
+                requester
+                    .search_dirs(runpath, word_size, search.sysroot)
+                    .into_iter()
+                    .map(|dir| (dir, "RUNPATH")),
+            );
+        }
+        if !requester.nodeflib {
+            candidates.extend(
+                search
+                    .system_dirs
+                    .iter()
+                    .map(|dir| (dir.clone(), "ld.so.conf")),
+            );
+            candidates.extend(
+                search
+                    .default_dirs
+                    .iter()
+                    .map(|dir| (dir.clone(), "default directory")),
+            );
+        }
+
+        candidates.into_iter().find_map(|(dir, how)| {
+            self.load_dependency(&dir.join(name))
+                .map(|object| (object, how))
+        })
+    }
+
+    fn dependency_entries(&self, table: &mut Table, chain: &mut Vec<Object>, search: &mut Search) {
+        let needed = chain.last().expect("chain is not empty").needed.clone();
+        for name in needed {
+            // names come from the file, so they stay out of the key and the depth is spelled
+            // out next to the indented tree
+            let key = format!("Level {}", chain.len());
+            let tree = format!("{}{name}", "  ".repeat(chain.len() - 1));
+            let Some((object, how)) = self.find_dependency(&name, chain, search) else {
+                search.missing += 1;
+                table.add_entry(key, format!("{tree}: Missing"));
+                continue;
+            };
+            if !search.visited.insert(object.path.clone()) {
+                table.add_entry(
+                    key,
+                    format!("{tree}: {} (already listed)", object.path.display()),
+                );
+                continue;
+            }
+            table.add_entry(key, format!("{tree}: {} ({how})", object.path.display()));
+            chain.push(object);
+            self.dependency_entries(table, chain, search);
+            chain.pop();
+        }
+    }
+
+    pub(super) fn dependencies(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        path: &Path,
+        options: &Options,
+    ) -> Res<()> {
+        let sysroot = options.sysroot.as_deref().unwrap_or(Path::new("/"));
+        let root = self.dependency_object(bytes, path.canonicalize()?)?;
+
+        let mut system_dirs = vec![];
+        ld_so_conf_dirs(sysroot, Path::new("/etc/ld.so.conf"), 0, &mut system_dirs);
+        let default_dirs = match self.word_size.expect("word size assigned") {
+            WordSize::Four => &["/lib", "/usr/lib"][..],
+            WordSize::Eight => &["/lib64", "/usr/lib64", "/lib", "/usr/lib"],
+        };
+        let mut search = Search {
+            sysroot,
+            library_path: options
+                .library_path
+                .iter()
+                .map(|dir| in_sysroot(sysroot, dir))
+                .collect(),
+            system_dirs,
+            default_dirs: default_dirs
+                .iter()
+                .map(|dir| in_sysroot(sysroot, Path::new(dir)))
+                .collect(),
+            missing: 0,
+            visited: HashSet::from([root.path.clone()]),
+        };
+
+        table.new_named_section("Dependencies");
+        table.add_entry("Sysroot", sysroot.display().to_string());
+        if let Some(interp) = self
+            .pheaders
+            .iter()
+            .find(|pheader| pheader.r#type == SegmentType::Interp)
+        {
+            bytes.jump(interp.offset)?;
+            let interpreter = bytes.pull::<std::ffi::CString>()?;
+            let interpreter = interpreter.to_string_lossy();
+            let resolved = in_sysroot(sysroot, Path::new(interpreter.as_ref()));
+            table.add_entry(
+                "Interpreter",
+                if self.load_dependency(&resolved).is_some() {
+                    resolved.display().to_string()
+                } else {
+                    search.missing += 1;
+                    format!("{interpreter} (missing)")
+                },
+            );
+        }
+        if root.needed.is_empty() {
+            table.add_entry("Needed Libraries", "None");
+            return Ok(());
+        }
+
+        table.new_named_section("Dependency Tree");
+        let mut chain = vec![root];
+        self.dependency_entries(table, &mut chain, &mut search);
+        table.new_unnamed_section();
+        table.add_entry("Missing Libraries", search.missing.to_string());
+
+        Ok(())
+    }
+}
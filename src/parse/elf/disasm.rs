@@ -0,0 +1,192 @@
+mod aarch64;
+mod x86_64;
+
+use super::{Parser, Symbol, lookup::nearest_symbol, raw_section_data, segment_data};
+use crate::{
+    elf_header::*,
+    error::{Error, Res},
+    parse::{Bytes, Table, Target},
+};
+
+// decoding stops after this many instructions when no symbol says where the code ends
+const MAX_INSTRUCTIONS: usize = 64;
+// bytes shown before the instruction text, which longer x86 encodings run past
+const ENCODING_WIDTH: usize = 24;
+
+struct Instruction {
+    len: usize,
+    text: String,
+    reference: Option<Reference>,
+}
+
+// an address an instruction branches to or loads from, either written out as an operand
+// or computed from a register such as x86-64's RIP
+enum Reference {
+    Operand(u64),
+    Relative(u64),
+}
+
+impl Parser {
+    // PLT stubs named after the functions they call, as the linker lays them out in the
+    // order of .rela.plt after a header, or in .plt.sec without one when IBT is enabled
+    fn plt_symbols(&self, bytes: &mut impl Bytes) -> Res<Vec<(String, Symbol)>> {
+        let (Some(rela_idx), Some(plt_idx)) = (
+            self.section_named(".rela.plt")
+                .or_else(|| self.section_named(".rel.plt")),
+            self.section_named(".plt"),
+        ) else {
+            return Ok(vec![]);
+        };
+        let (stubs_idx, first) = match (self.machine, self.section_named(".plt.sec")) {
+            (EM_X86_64, Some(idx)) => (idx, 0),
+            (EM_X86_64, None) => (plt_idx, 16),
+            (EM_AARCH64, _) => (plt_idx, 32),
+            _ => return Ok(vec![]),
+        };
+        let symbols = self.symbols(bytes, self.sheaders[rela_idx].link as usize)?;
+        let base = self.sheaders[stubs_idx].addr + first;
+        Ok(self
+            .read_relocations(bytes, rela_idx)?
+            .iter()
+            .enumerate()
+            .map(|(i, relocation)| {
+                let (symbol, _) = self.relocation_info(relocation.info);
+                let name = match symbols.get(symbol as usize) {
+                    Some((name, _)) if symbol > 0 => format!("{name}@plt"),
+                    _ => format!("*ABS*+0x{:X}@plt", relocation.addend.unwrap_or_default()),
+                };
+                let symbol = Symbol {
+                    name: 0,
+                    value: base + 16 * i as u64,
+                    size: 16,
+                    info: STT_FUNC,
+                    other: 0,
+                    shndx: stubs_idx as u32,
+                };
+                (name, symbol)
+            })
+            .collect())
+    }
+
+    pub(super) fn disassemble(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        targets: &[Target],
+    ) -> Res<()> {
+        let decode = match self.machine {
+            EM_X86_64 => x86_64::decode,
+            EM_AARCH64 => aarch64::decode,
+            _ => {
+                return Err(Error::Cli(
+                    "Disassembly is only supported for x86-64 and AArch64".into(),
+                ));
+            }
+        };
+        let mut symbols = self.lookup_symbols(bytes)?;
+        // mapping symbols like $x and $d.1 mark code and data on Arm and name nothing
+        symbols
+            .retain(|(name, _)| !matches!(name.split('.').next(), Some("$x" | "$d" | "$a" | "$t")));
+        symbols.extend(self.plt_symbols(bytes)?);
+
+        for target in targets {
+            // where to start, the section holding the code if known, and where it ends
+            let (label, start, section, end) = match target {
+                Target::Symbol(name) => {
+                    let Some((_, symbol)) = symbols
+                        .iter()
+                        .filter(|(symbol_name, _)| symbol_name == name)
+                        .max_by_key(|(_, symbol)| (symbol.r#type() == STT_FUNC, symbol.size))
+                    else {
+                        return Err(Error::Cli(format!("No symbol named {name}")));
+                    };
+                    let section = (symbol.shndx < SHN_LORESERVE).then_some(symbol.shndx as usize);
+                    let end = (symbol.size > 0).then_some(symbol.value + symbol.size);
+                    (name.clone(), symbol.value, section, end)
+                }
+                Target::Address(_) | Target::Entry => {
+                    let address = match target {
+                        Target::Address(address) => *address,
+                        _ => self.entry,
+                    };
+                    let section = self.containing_section(address);
+                    let end = nearest_symbol(&symbols, address, section)
+                        .map(|(_, symbol)| symbol.value + symbol.size)
+                        .filter(|&end| address < end);
+                    let label = match self.describe_address(&symbols, address) {
+                        Some(description) => format!("0x{address:X} ({description})"),
+                        None => format!("0x{address:X}"),
+                    };
+                    (label, address, section, end)
+                }
+            };
+
+            let not_code = || Error::Cli(format!("{label} is not in an executable section"));
+            let (data, base) = match section {
+                Some(idx) => {
+                    let sheader = &self.sheaders[idx];
+                    if sheader.flags & SHF_EXECINSTR == 0 {
+                        return Err(not_code());
+                    }
+                    (raw_section_data(bytes, sheader)?, sheader.addr)
+                }
+                None => match self.containing_segment(start) {
+                    Some((_, pheader)) if pheader.flags & PF_X > 0 => {
+                        (segment_data(bytes, pheader)?, pheader.vaddr)
+                    }
+                    _ => return Err(not_code()),
+                },
+            };
+            if start < base {
+                return Err(not_code());
+            }
+            let limit = end.map_or(data.len() as u64, |end| (end - base).min(data.len() as u64));
+
+            table.new_named_section(format!("Disassembly of {label}"));
+            let mut address = start;
+            let mut count = 0;
+            while address - base < limit {
+                // without a known end, stop where the next symbol starts
+                if end.is_none()
+                    && (count == MAX_INSTRUCTIONS
+                        || count > 0
+                            && symbols.iter().any(|(_, symbol)| {
+                                symbol.value == address && Some(symbol.shndx as usize) == section
+                            }))
+                {
+                    break;
+                }
+                let code = &data[(address - base) as usize..limit as usize];
+                let instruction = decode(code, address);
+                let encoding: Vec<_> = code[..instruction.len.min(code.len())]
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect();
+                let mut value = format!(
+                    "{:<ENCODING_WIDTH$} {}",
+                    encoding.join(" "),
+                    instruction.text
+                );
+                match instruction.reference {
+                    Some(Reference::Operand(target)) => {
+                        if let Some(description) = self.describe_address(&symbols, target) {
+                            value += &format!(" <{description}>");
+                        }
+                    }
+                    Some(Reference::Relative(target)) => {
+                        value += &format!("  # 0x{target:X}");
+                        if let Some(description) = self.describe_address(&symbols, target) {
+                            value += &format!(" <{description}>");
+                        }
+                    }
+                    None => {}
+                }
+                table.add_entry(format!("0x{address:X}"), value);
+                address += instruction.len as u64;
+                count += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2139 @@
+use super::{Instruction, Reference};
+
+const CONDITIONS: [&str; 16] = [
+    "eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al", "nv",
+];
+const SHIFTS: [&str; 4] = ["lsl", "lsr", "asr", "ror"];
+const EXTENDS: [&str; 8] = [
+    "uxtb", "uxth", "uxtw", "uxtx", "sxtb", "sxth", "sxtw", "sxtx",
+];
+// vector arrangements by size, and whether the full 128 bits are used
+const ARRANGEMENTS: [[&str; 2]; 4] = [["8b", "16b"], ["4h", "8h"], ["2s", "4s"], ["1d", "2d"]];
+
+fn bits(word: u32, low: u32, len: u32) -> u32 {
+    (word >> low) & ((1 << len) - 1)
+}
+
+fn sign_extend(value: u32, len: u32) -> i64 {
+    (i64::from(value) << (64 - len)) >> (64 - len)
+}
+
+// general purpose register, where 31 is the zero register
+fn reg(n: u32, wide: bool) -> String {
+    match (n, wide) {
+        (31, true) => "xzr".into(),
+        (31, false) => "wzr".into(),
+        (n, true) => format!("x{n}"),
+        (n, false) => format!("w{n}"),
+    }
+}
+
+// general purpose register, where 31 is the stack pointer
+fn reg_sp(n: u32, wide: bool) -> String {
+    match (n, wide) {
+        (31, true) => "sp".into(),
+        (31, false) => "wsp".into(),
+        _ => reg(n, wide),
+    }
+}
+
+fn fmt_unsigned(value: u64) -> String {
+    format!("#0x{value:X}")
+}
+
+fn fmt_signed(value: i64) -> String {
+    if value < 0 {
+        format!("#-0x{:X}", value.unsigned_abs())
+    } else {
+        format!("#0x{value:X}")
+    }
+}
+
+fn vector(n: u32, size: u32, q: u32) -> String {
+    format!("v{n}.{}", ARRANGEMENTS[size as usize][q as usize])
+}
+
+// the repeating bit pattern encoded by the N, immr and imms fields of logical immediates
+fn bitmask(n: u32, immr: u32, imms: u32, wide: bool) -> Option<u64> {
+    let combined = (n << 6) | (!imms & 0x3F);
+    if combined < 2 || (!wide && n == 1) {
+        return None;
+    }
+    let size = 1u32 << (31 - combined.leading_zeros());
+    let levels = size - 1;
+    let ones = imms & levels;
+    let rotation = immr & levels;
+    if ones == levels {
+        return None;
+    }
+    let element_mask = if size == 64 {
+        u64::MAX
+    } else {
+        (1 << size) - 1
+    };
+    let element = (1u64 << (ones + 1)) - 1;
+    let element = match rotation {
+        0 => element,
+        _ => ((element >> rotation) | (element << (size - rotation))) & element_mask,
+    };
+    let mut value = element;
+    let mut width = size;
+    while width < 64 {
+        value |= value << width;
+        width *= 2;
+    }
+    Some(if wide { value } else { value & 0xFFFF_FFFF })
+}
+
+// whether a logical immediate is better shown as the move wide immediate it also encodes
+fn move_wide_preferred(wide: bool, n: u32, imms: u32, immr: u32) -> bool {
+    let width = if wide { 64 } else { 32 };
+    if (wide && n != 1) || (!wide && (n != 0 || imms & 0x20 != 0)) {
+        return false;
+    }
+    if imms < 16 {
+        return (16 - immr % 16) % 16 <= 15 - imms;
+    }
+    if imms >= width - 15 {
+        return immr % 16 <= imms - (width - 15);
+    }
+    false
+}
+
+// the 8 bit floating point immediate of fmov
+fn fp_immediate(imm8: u32) -> f64 {
+    let sign = if imm8 & 0x80 > 0 { -1.0 } else { 1.0 };
+    let exponent = ((imm8 >> 4) & 7) as i32;
+    let exponent = if exponent & 4 > 0 {
+        exponent - 8
+    } else {
+        exponent
+    } + 1;
+    let fraction = f64::from(imm8 & 0xF) / 16.0 + 1.0;
+    sign * fraction * 2f64.powi(exponent)
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    W,
+    X,
+    B,
+    H,
+    S,
+    D,
+    Q,
+}
+
+impl Kind {
+    fn register(self, n: u32) -> String {
+        match self {
+            Self::W => reg(n, false),
+            Self::X => reg(n, true),
+            Self::B => format!("b{n}"),
+            Self::H => format!("h{n}"),
+            Self::S => format!("s{n}"),
+            Self::D => format!("d{n}"),
+            Self::Q => format!("q{n}"),
+        }
+    }
+
+    // scalar floating point registers by the type field
+    fn fp(r#type: u32) -> Option<Self> {
+        match r#type {
+            0 => Some(Self::S),
+            1 => Some(Self::D),
+            3 => Some(Self::H),
+            _ => None,
+        }
+    }
+}
+
+// prefetch operations of prfm, like pldl1keep
+fn prefetch(rt: u32) -> String {
+    match ["pld", "pli", "pst"].get((rt >> 3) as usize) {
+        Some(r#type) if (rt >> 1) & 3 < 3 => format!(
+            "{type}l{}{}",
+            ((rt >> 1) & 3) + 1,
+            if rt & 1 > 0 { "strm" } else { "keep" }
+        ),
+        _ => format!("#0x{rt:X}"),
+    }
+}
+
+fn system_register(op0: u32, op1: u32, crn: u32, crm: u32, op2: u32) -> String {
+    match (op0, op1, crn, crm, op2) {
+        (3, 3, 13, 0, 2) => "tpidr_el0".into(),
+        (3, 3, 13, 0, 3) => "tpidrro_el0".into(),
+        (3, 3, 4, 4, 0) => "fpcr".into(),
+        (3, 3, 4, 4, 1) => "fpsr".into(),
+        (3, 3, 4, 2, 0) => "nzcv".into(),
+        (3, 3, 14, 0, 0) => "cntfrq_el0".into(),
+        (3, 3, 14, 0, 1) => "cntpct_el0".into(),
+        (3, 3, 14, 0, 2) => "cntvct_el0".into(),
+        (3, 3, 0, 0, 1) => "ctr_el0".into(),
+        (3, 3, 0, 0, 7) => "dczid_el0".into(),
+        (3, 0, 0, 0, 0) => "midr_el1".into(),
+        (3, 0, 0, 0, 5) => "mpidr_el1".into(),
+        _ => format!("s{op0}_{op1}_c{crn}_c{crm}_{op2}"),
+    }
+}
+
+struct Decoder {
+    address: u64,
+    reference: Option<Reference>,
+}
+
+impl Decoder {
+    fn branch(&mut self, offset: i64) -> String {
+        self.target(self.address.wrapping_add(offset as u64))
+    }
+
+    fn target(&mut self, target: u64) -> String {
+        self.reference = Some(Reference::Operand(target));
+        format!("0x{target:X}")
+    }
+
+    fn instruction(&mut self, word: u32) -> Option<String> {
+        if word >> 16 == 0 {
+            return Some(format!("udf {}", fmt_unsigned(word.into())));
+        }
+        match bits(word, 25, 4) {
+            0b1000 | 0b1001 => self.immediate(word),
+            0b1010 | 0b1011 => self.branch_system(word),
+            0b0100 | 0b0110 | 0b1100 | 0b1110 => load_store(word).or_else(|| self.literal(word)),
+            0b0101 | 0b1101 => register(word),
+            0b0111 | 0b1111 => simd_fp(word),
+            _ => None,
+        }
+    }
+
+    fn immediate(&mut self, word: u32) -> Option<String> {
+        let wide = word >> 31 == 1;
+        let rd = bits(word, 0, 5);
+        let rn = bits(word, 5, 5);
+        Some(match bits(word, 23, 3) {
+            0b000 | 0b001 => {
+                let offset = sign_extend(bits(word, 5, 19) << 2 | bits(word, 29, 2), 21);
+                match word >> 31 {
+                    0 => {
+                        let target = self.address.wrapping_add(offset as u64);
+                        format!("adr {}, {}", reg(rd, true), self.target(target))
+                    }
+                    _ => {
+                        let target = (self.address & !0xFFF).wrapping_add((offset << 12) as u64);
+                        format!("adrp {}, {}", reg(rd, true), self.target(target))
+                    }
+                }
+            }
+            0b010 => {
+                let subtract = bits(word, 30, 1) == 1;
+                let flags = bits(word, 29, 1) == 1;
+                let shift = bits(word, 22, 1) * 12;
+                let imm = u64::from(bits(word, 10, 12));
+                let operand = match shift {
+                    0 => fmt_unsigned(imm),
+                    _ => format!("{}, lsl #12", fmt_unsigned(imm)),
+                };
+                match (subtract, flags) {
+                    (false, false) if imm == 0 && shift == 0 && (rd == 31 || rn == 31) => {
+                        format!("mov {}, {}", reg_sp(rd, wide), reg_sp(rn, wide))
+                    }
+                    (_, true) if rd == 31 => {
+                        let mnemonic = if subtract { "cmp" } else { "cmn" };
+                        format!("{mnemonic} {}, {operand}", reg_sp(rn, wide))
+                    }
+                    _ => {
+                        let mnemonic = match (subtract, flags) {
+                            (false, false) => "add",
+                            (false, true) => "adds",
+                            (true, false) => "sub",
+                            (true, true) => "subs",
+                        };
+                        let rd = if flags {
+                            reg(rd, wide)
+                        } else {
+                            reg_sp(rd, wide)
+                        };
+                        format!("{mnemonic} {rd}, {}, {operand}", reg_sp(rn, wide))
+                    }
+                }
+            }
+            0b100 => {
+                let n = bits(word, 22, 1);
+                let immr = bits(word, 16, 6);
+                let imms = bits(word, 10, 6);
+                let imm = fmt_unsigned(bitmask(n, immr, imms, wide)?);
+                match bits(word, 29, 2) {
+                    0b01 if rn == 31 && !move_wide_preferred(wide, n, imms, immr) => {
+                        format!("mov {}, {imm}", reg_sp(rd, wide))
+                    }
+                    0b11 if rd == 31 => format!("tst {}, {imm}", reg(rn, wide)),
+                    opc => {
+                        let mnemonic = ["and", "orr", "eor", "ands"][opc as usize];
+                        let rd = if opc == 0b11 {
+                            reg(rd, wide)
+                        } else {
+                            reg_sp(rd, wide)
+                        };
+                        format!("{mnemonic} {rd}, {}, {imm}", reg(rn, wide))
+                    }
+                }
+            }
+            0b101 => {
+                let hw = bits(word, 21, 2);
+                if !wide && hw > 1 {
+                    return None;
+                }
+                let imm16 = u64::from(bits(word, 5, 16));
+                let shift = hw * 16;
+                let rd = reg(rd, wide);
+                match bits(word, 29, 2) {
+                    0b00 if !(imm16 == 0 && hw != 0) && (wide || imm16 != 0xFFFF) => {
+                        let value = !(imm16 << shift);
+                        let value = if wide { value } else { value & 0xFFFF_FFFF };
+                        format!("mov {rd}, {}", fmt_unsigned(value))
+                    }
+                    0b10 if !(imm16 == 0 && hw != 0) => {
+                        format!("mov {rd}, {}", fmt_unsigned(imm16 << shift))
+                    }
+                    0b01 => return None,
+                    opc => {
+                        let mnemonic = ["movn", "", "movz", "movk"][opc as usize];
+                        match shift {
+                            0 => format!("{mnemonic} {rd}, {}", fmt_unsigned(imm16)),
+                            _ => format!("{mnemonic} {rd}, {}, lsl #{shift}", fmt_unsigned(imm16)),
+                        }
+                    }
+                }
+            }
+            0b110 => bitfield(word)?,
+            0b111 => {
+                let lsb = bits(word, 10, 6);
+                if bits(word, 21, 1) == 1
+                    || bits(word, 29, 2) != 0
+                    || bits(word, 22, 1) != word >> 31
+                    || !wide && lsb >= 32
+                {
+                    return None;
+                }
+                let rm = bits(word, 16, 5);
+                if rn == rm {
+                    format!("ror {}, {}, #{lsb}", reg(rd, wide), reg(rn, wide))
+                } else {
+                    format!(
+                        "extr {}, {}, {}, #{lsb}",
+                        reg(rd, wide),
+                        reg(rn, wide),
+                        reg(rm, wide)
+                    )
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    fn branch_system(&mut self, word: u32) -> Option<String> {
+        let rt = bits(word, 0, 5);
+        Some(if word & 0x7C00_0000 == 0x1400_0000 {
+            let mnemonic = if word >> 31 == 1 { "bl" } else { "b" };
+            format!(
+                "{mnemonic} {}",
+                self.branch(sign_extend(bits(word, 0, 26), 26) << 2)
+            )
+        } else if word & 0x7E00_0000 == 0x3400_0000 {
+            let mnemonic = if bits(word, 24, 1) == 1 {
+                "cbnz"
+            } else {
+                "cbz"
+            };
+            let target = self.branch(sign_extend(bits(word, 5, 19), 19) << 2);
+            format!("{mnemonic} {}, {target}", reg(rt, word >> 31 == 1))
+        } else if word & 0x7E00_0000 == 0x3600_0000 {
+            let mnemonic = if bits(word, 24, 1) == 1 {
+                "tbnz"
+            } else {
+                "tbz"
+            };
+            let bit = (word >> 31) << 5 | bits(word, 19, 5);
+            let target = self.branch(sign_extend(bits(word, 5, 14), 14) << 2);
+            format!("{mnemonic} {}, #{bit}, {target}", reg(rt, word >> 31 == 1))
+        } else if word & 0xFF00_0010 == 0x5400_0000 {
+            let target = self.branch(sign_extend(bits(word, 5, 19), 19) << 2);
+            format!("b.{} {target}", CONDITIONS[bits(word, 0, 4) as usize])
+        } else if word & 0xFF00_0000 == 0xD400_0000 {
+            let imm = fmt_unsigned(bits(word, 5, 16).into());
+            match (bits(word, 21, 3), bits(word, 0, 5)) {
+                (0b000, 1) => format!("svc {imm}"),
+                (0b000, 2) => format!("hvc {imm}"),
+                (0b000, 3) => format!("smc {imm}"),
+                (0b001, 0) => format!("brk {imm}"),
+                (0b010, 0) => format!("hlt {imm}"),
+                _ => return None,
+            }
+        } else if word & 0xFFFF_F01F == 0xD503_201F {
+            match bits(word, 5, 7) {
+                0 => "nop".into(),
+                1 => "yield".into(),
+                2 => "wfe".into(),
+                3 => "wfi".into(),
+                4 => "sev".into(),
+                5 => "sevl".into(),
+                7 => "xpaclri".into(),
+                8 => "pacia1716".into(),
+                10 => "pacib1716".into(),
+                12 => "autia1716".into(),
+                14 => "autib1716".into(),
+                20 => "csdb".into(),
+                24 => "paciaz".into(),
+                25 => "paciasp".into(),
+                26 => "pacibz".into(),
+                27 => "pacibsp".into(),
+                28 => "autiaz".into(),
+                29 => "autiasp".into(),
+                30 => "autibz".into(),
+                31 => "autibsp".into(),
+                32 => "bti".into(),
+                34 => "bti c".into(),
+                36 => "bti j".into(),
+                38 => "bti jc".into(),
+                hint => format!("hint #{hint}"),
+            }
+        } else if word & 0xFFFF_F01F == 0xD503_301F {
+            let crm = bits(word, 8, 4);
+            let option = match crm {
+                15 => "sy".into(),
+                14 => "st".into(),
+                13 => "ld".into(),
+                11 => "ish".into(),
+                10 => "ishst".into(),
+                9 => "ishld".into(),
+                7 => "nsh".into(),
+                6 => "nshst".into(),
+                5 => "nshld".into(),
+                3 => "osh".into(),
+                2 => "oshst".into(),
+                1 => "oshld".into(),
+                _ => format!("#{crm}"),
+            };
+            match bits(word, 5, 3) {
+                2 if crm == 15 => "clrex".into(),
+                2 => format!("clrex #{crm}"),
+                4 => format!("dsb {option}"),
+                5 => format!("dmb {option}"),
+                6 if crm == 15 => "isb".into(),
+                6 => format!("isb #{crm}"),
+                _ => return None,
+            }
+        } else if word & 0xFFD0_0000 == 0xD510_0000 {
+            let register = system_register(
+                2 + bits(word, 19, 1),
+                bits(word, 16, 3),
+                bits(word, 12, 4),
+                bits(word, 8, 4),
+                bits(word, 5, 3),
+            );
+            match bits(word, 21, 1) {
+                1 => format!("mrs {}, {register}", reg(rt, true)),
+                _ => format!("msr {register}, {}", reg(rt, true)),
+            }
+        } else if word & 0xFFF8_0000 == 0xD508_0000 {
+            let operation = match (
+                bits(word, 16, 3),
+                bits(word, 12, 4),
+                bits(word, 8, 4),
+                bits(word, 5, 3),
+            ) {
+                (3, 7, 4, 1) => "dc zva",
+                (3, 7, 10, 1) => "dc cvac",
+                (3, 7, 11, 1) => "dc cvau",
+                (3, 7, 14, 1) => "dc civac",
+                (3, 7, 5, 1) => "ic ivau",
+                (0, 7, 5, 0) => return Some("ic iallu".into()),
+                (op1, crn, crm, op2) => {
+                    let operation = format!("sys #{op1}, c{crn}, c{crm}, #{op2}");
+                    return Some(match rt {
+                        31 => operation,
+                        _ => format!("{operation}, {}", reg(rt, true)),
+                    });
+                }
+            };
+            format!("{operation}, {}", reg(rt, true))
+        } else if word & 0xFE00_0000 == 0xD600_0000 {
+            let rn = bits(word, 5, 5);
+            if bits(word, 16, 5) != 31 {
+                return None;
+            }
+            match (bits(word, 21, 4), bits(word, 10, 6), rn, rt) {
+                (0, 0, _, 0) => format!("br {}", reg(rn, true)),
+                (1, 0, _, 0) => format!("blr {}", reg(rn, true)),
+                (2, 0, 30, 0) => "ret".into(),
+                (2, 0, _, 0) => format!("ret {}", reg(rn, true)),
+                (2, 2, 31, 31) => "retaa".into(),
+                (2, 3, 31, 31) => "retab".into(),
+                (4, 0, 31, 0) => "eret".into(),
+                (0, 2, _, 31) => format!("braaz {}", reg(rn, true)),
+                (0, 3, _, 31) => format!("brabz {}", reg(rn, true)),
+                (1, 2, _, 31) => format!("blraaz {}", reg(rn, true)),
+                (1, 3, _, 31) => format!("blrabz {}", reg(rn, true)),
+                (8, 2, _, _) => format!("braa {}, {}", reg(rn, true), reg_sp(rt, true)),
+                (8, 3, _, _) => format!("brab {}, {}", reg(rn, true), reg_sp(rt, true)),
+                (9, 2, _, _) => format!("blraa {}, {}", reg(rn, true), reg_sp(rt, true)),
+                (9, 3, _, _) => format!("blrab {}, {}", reg(rn, true), reg_sp(rt, true)),
+                _ => return None,
+            }
+        } else {
+            return None;
+        })
+    }
+
+    // loads relative to the instruction
+    fn literal(&mut self, word: u32) -> Option<String> {
+        if word & 0x3B00_0000 != 0x1800_0000 {
+            return None;
+        }
+        let target = self
+            .address
+            .wrapping_add((sign_extend(bits(word, 5, 19), 19) << 2) as u64);
+        let rt = bits(word, 0, 5);
+        let (mnemonic, rt) = match (bits(word, 26, 1), bits(word, 30, 2)) {
+            (0, 0) => ("ldr", reg(rt, false)),
+            (0, 1) => ("ldr", reg(rt, true)),
+            (0, 2) => ("ldrsw", reg(rt, true)),
+            (0, _) => ("prfm", prefetch(rt)),
+            (_, 0) => ("ldr", Kind::S.register(rt)),
+            (_, 1) => ("ldr", Kind::D.register(rt)),
+            (_, 2) => ("ldr", Kind::Q.register(rt)),
+            _ => return None,
+        };
+        Some(format!("{mnemonic} {rt}, {}", self.target(target)))
+    }
+}
+
+fn bitfield(word: u32) -> Option<String> {
+    let wide = word >> 31 == 1;
+    if bits(word, 22, 1) != word >> 31 {
+        return None;
+    }
+    let size = if wide { 64 } else { 32 };
+    let rd = reg(bits(word, 0, 5), wide);
+    let rn_number = bits(word, 5, 5);
+    let rn = reg(rn_number, wide);
+    let immr = bits(word, 16, 6);
+    let imms = bits(word, 10, 6);
+    if immr >= size || imms >= size {
+        return None;
+    }
+    Some(match bits(word, 29, 2) {
+        0b00 => {
+            if imms == size - 1 {
+                format!("asr {rd}, {rn}, #{immr}")
+            } else if immr == 0 && matches!(imms, 7 | 15) || (wide && immr == 0 && imms == 31) {
+                let mnemonic = match imms {
+                    7 => "sxtb",
+                    15 => "sxth",
+                    _ => "sxtw",
+                };
+                format!("{mnemonic} {rd}, {}", reg(rn_number, false))
+            } else if imms < immr {
+                format!("sbfiz {rd}, {rn}, #{}, #{}", size - immr, imms + 1)
+            } else {
+                format!("sbfx {rd}, {rn}, #{immr}, #{}", imms - immr + 1)
+            }
+        }
+        0b01 => {
+            if imms < immr {
+                match rn_number {
+                    31 => format!("bfc {rd}, #{}, #{}", size - immr, imms + 1),
+                    _ => format!("bfi {rd}, {rn}, #{}, #{}", size - immr, imms + 1),
+                }
+            } else {
+                format!("bfxil {rd}, {rn}, #{immr}, #{}", imms - immr + 1)
+            }
+        }
+        0b10 => {
+            if imms != size - 1 && imms + 1 == immr {
+                format!("lsl {rd}, {rn}, #{}", size - 1 - imms)
+            } else if imms == size - 1 {
+                format!("lsr {rd}, {rn}, #{immr}")
+            } else if !wide && immr == 0 && matches!(imms, 7 | 15) {
+                let mnemonic = if imms == 7 { "uxtb" } else { "uxth" };
+                format!("{mnemonic} {rd}, {rn}")
+            } else if imms < immr {
+                format!("ubfiz {rd}, {rn}, #{}, #{}", size - immr, imms + 1)
+            } else {
+                format!("ubfx {rd}, {rn}, #{immr}, #{}", imms - immr + 1)
+            }
+        }
+        _ => return None,
+    })
+}
+
+// [base], [base, #imm], [base, #imm]! or [base], #imm
+fn address(rn: u32, imm: i64, index: u32) -> String {
+    let base = reg_sp(rn, true);
+    match index {
+        0b01 => format!("[{base}], {}", fmt_signed(imm)),
+        0b11 => format!("[{base}, {}]!", fmt_signed(imm)),
+        _ if imm == 0 => format!("[{base}]"),
+        _ => format!("[{base}, {}]", fmt_signed(imm)),
+    }
+}
+
+fn load_store(word: u32) -> Option<String> {
+    let rt = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let simd = bits(word, 26, 1) == 1;
+
+    if word & 0x3F00_0000 == 0x0800_0000 {
+        return exclusive(word);
+    }
+
+    if word & 0x3A00_0000 == 0x2800_0000 {
+        let load = bits(word, 22, 1) == 1;
+        let (kind, scale, signed) = match (simd, bits(word, 30, 2), load) {
+            (false, 0, _) => (Kind::W, 2, false),
+            (false, 1, true) => (Kind::X, 2, true),
+            (false, 2, _) => (Kind::X, 3, false),
+            (true, 0, _) => (Kind::S, 2, false),
+            (true, 1, _) => (Kind::D, 3, false),
+            (true, 2, _) => (Kind::Q, 4, false),
+            _ => return None,
+        };
+        let index = bits(word, 23, 2);
+        let mnemonic = match (index, load, signed) {
+            (0, false, _) => "stnp",
+            (0, true, false) => "ldnp",
+            (0, true, true) => return None,
+            (_, false, _) => "stp",
+            (_, true, false) => "ldp",
+            (_, true, true) => "ldpsw",
+        };
+        let imm = sign_extend(bits(word, 15, 7), 7) << scale;
+        return Some(format!(
+            "{mnemonic} {}, {}, {}",
+            kind.register(rt),
+            kind.register(bits(word, 10, 5)),
+            address(rn, imm, index)
+        ));
+    }
+
+    if word & 0x3A00_0000 != 0x3800_0000 {
+        return simd_structures(word);
+    }
+    // atomics reuse the bits giving the size and signedness of other loads for ordering
+    if !simd && word & 0x0120_0C00 == 0x0020_0000 {
+        return atomic(word);
+    }
+    let size = bits(word, 30, 2);
+    let opc = bits(word, 22, 2);
+    // the mnemonic is the root, then the form, then the size suffix
+    let (root, suffix, kind, scale) = if simd {
+        let root = if opc & 1 == 0 { "st" } else { "ld" };
+        match (size, opc >> 1) {
+            (0, 0) => (root, "", Kind::B, 0),
+            (1, 0) => (root, "", Kind::H, 1),
+            (2, 0) => (root, "", Kind::S, 2),
+            (3, 0) => (root, "", Kind::D, 3),
+            (0, 1) => (root, "", Kind::Q, 4),
+            _ => return None,
+        }
+    } else {
+        match (size, opc) {
+            (0, 0) => ("st", "b", Kind::W, 0),
+            (0, 1) => ("ld", "b", Kind::W, 0),
+            (0, 2) => ("ld", "sb", Kind::X, 0),
+            (0, 3) => ("ld", "sb", Kind::W, 0),
+            (1, 0) => ("st", "h", Kind::W, 1),
+            (1, 1) => ("ld", "h", Kind::W, 1),
+            (1, 2) => ("ld", "sh", Kind::X, 1),
+            (1, 3) => ("ld", "sh", Kind::W, 1),
+            (2, 0) => ("st", "", Kind::W, 2),
+            (2, 1) => ("ld", "", Kind::W, 2),
+            (2, 2) => ("ld", "sw", Kind::X, 2),
+            (3, 0) => ("st", "", Kind::X, 3),
+            (3, 1) => ("ld", "", Kind::X, 3),
+            (3, 2) => ("pr", "", Kind::X, 3),
+            _ => return None,
+        }
+    };
+    let prefetch_op = root == "pr";
+    let target = if prefetch_op {
+        prefetch(rt)
+    } else {
+        kind.register(rt)
+    };
+    let mnemonic = |form: &str| match prefetch_op {
+        true => format!("prf{}m", form.trim_end_matches('r')),
+        false => format!("{root}{form}{suffix}"),
+    };
+
+    if bits(word, 24, 1) == 1 {
+        let imm = i64::from(bits(word, 10, 12)) << scale;
+        return Some(format!(
+            "{} {target}, {}",
+            mnemonic("r"),
+            address(rn, imm, 0b10)
+        ));
+    }
+    if bits(word, 21, 1) == 0 {
+        let imm = sign_extend(bits(word, 12, 9), 9);
+        let index = bits(word, 10, 2);
+        let form = match index {
+            0b00 => "ur",
+            0b10 if prefetch_op || simd => return None,
+            0b10 => "tr",
+            _ if prefetch_op => return None,
+            _ => "r",
+        };
+        return Some(format!(
+            "{} {target}, {}",
+            mnemonic(form),
+            address(rn, imm, index)
+        ));
+    }
+    match bits(word, 10, 2) {
+        0b10 => {
+            let rm = bits(word, 16, 5);
+            let option = bits(word, 13, 3);
+            let shift = if bits(word, 12, 1) == 1 {
+                format!(" #{scale}")
+            } else {
+                String::new()
+            };
+            let offset = match option {
+                0b011 if shift.is_empty() => reg(rm, true),
+                0b011 => format!("{}, lsl{shift}", reg(rm, true)),
+                0b010 | 0b110 => format!("{}, {}{shift}", reg(rm, false), EXTENDS[option as usize]),
+                0b111 => format!("{}, sxtx{shift}", reg(rm, true)),
+                _ => return None,
+            };
+            Some(format!(
+                "{} {target}, [{}, {offset}]",
+                mnemonic("r"),
+                reg_sp(rn, true)
+            ))
+        }
+        _ => None,
+    }
+}
+
+// exclusive, acquire and release, and compare and swap forms
+fn exclusive(word: u32) -> Option<String> {
+    let size = bits(word, 30, 2);
+    let rs = bits(word, 16, 5);
+    let rt2 = bits(word, 10, 5);
+    let rn = reg_sp(bits(word, 5, 5), true);
+    let rt = bits(word, 0, 5);
+    let load = bits(word, 22, 1) == 1;
+    let ordered = bits(word, 15, 1) == 1;
+    let (suffix, wide) = match size {
+        0 => ("b", false),
+        1 => ("h", false),
+        2 => ("", false),
+        _ => ("", true),
+    };
+    Some(match (bits(word, 23, 1), bits(word, 21, 1)) {
+        (0, 0) => match (load, ordered) {
+            (false, false) => format!("stxr{suffix} {}, {}, [{rn}]", reg(rs, false), reg(rt, wide)),
+            (false, true) => format!(
+                "stlxr{suffix} {}, {}, [{rn}]",
+                reg(rs, false),
+                reg(rt, wide)
+            ),
+            (true, false) => format!("ldxr{suffix} {}, [{rn}]", reg(rt, wide)),
+            (true, true) => format!("ldaxr{suffix} {}, [{rn}]", reg(rt, wide)),
+        },
+        (1, 0) => match (load, ordered) {
+            (false, false) => format!("stllr{suffix} {}, [{rn}]", reg(rt, wide)),
+            (false, true) => format!("stlr{suffix} {}, [{rn}]", reg(rt, wide)),
+            (true, false) => format!("ldlar{suffix} {}, [{rn}]", reg(rt, wide)),
+            (true, true) => format!("ldar{suffix} {}, [{rn}]", reg(rt, wide)),
+        },
+        (0, 1) if size >= 2 => {
+            let mnemonic = match (load, ordered) {
+                (false, false) => "stxp",
+                (false, true) => "stlxp",
+                (true, false) => "ldxp",
+                (true, true) => "ldaxp",
+            };
+            match load {
+                false => format!(
+                    "{mnemonic} {}, {}, {}, [{rn}]",
+                    reg(rs, false),
+                    reg(rt, wide),
+                    reg(rt2, wide)
+                ),
+                true => format!("{mnemonic} {}, {}, [{rn}]", reg(rt, wide), reg(rt2, wide)),
+            }
+        }
+        (0, 1) => {
+            let wide = size == 1;
+            let acquire = if load { "a" } else { "" };
+            let release = if ordered { "l" } else { "" };
+            format!(
+                "casp{acquire}{release} {}, {}, {}, {}, [{rn}]",
+                reg(rs, wide),
+                reg(rs + 1, wide),
+                reg(rt, wide),
+                reg(rt + 1, wide)
+            )
+        }
+        _ => {
+            let acquire = if load { "a" } else { "" };
+            let release = if ordered { "l" } else { "" };
+            format!(
+                "cas{acquire}{release}{suffix} {}, {}, [{rn}]",
+                reg(rs, wide),
+                reg(rt, wide)
+            )
+        }
+    })
+}
+
+// atomic memory operations like ldadd and swp
+fn atomic(word: u32) -> Option<String> {
+    let size = bits(word, 30, 2);
+    let acquire = bits(word, 23, 1) == 1;
+    let release = bits(word, 22, 1) == 1;
+    let rs = bits(word, 16, 5);
+    let rn = reg_sp(bits(word, 5, 5), true);
+    let rt = bits(word, 0, 5);
+    let (suffix, wide) = match size {
+        0 => ("b", false),
+        1 => ("h", false),
+        2 => ("", false),
+        _ => ("", true),
+    };
+    let operation = match (bits(word, 15, 1), bits(word, 12, 3)) {
+        (0, opc) => ["add", "clr", "eor", "set", "smax", "smin", "umax", "umin"][opc as usize],
+        (_, 0) => "swp",
+        _ => return None,
+    };
+    let ordering = match (acquire, release) {
+        (false, false) => "",
+        (true, false) => "a",
+        (false, true) => "l",
+        (true, true) => "al",
+    };
+    Some(if operation != "swp" && rt == 31 && !acquire {
+        format!("st{operation}{ordering}{suffix} {}, [{rn}]", reg(rs, wide))
+    } else if operation == "swp" {
+        format!(
+            "swp{ordering}{suffix} {}, {}, [{rn}]",
+            reg(rs, wide),
+            reg(rt, wide)
+        )
+    } else {
+        format!(
+            "ld{operation}{ordering}{suffix} {}, {}, [{rn}]",
+            reg(rs, wide),
+            reg(rt, wide)
+        )
+    })
+}
+
+// ld1 to ld4 and st1 to st4 of whole registers
+fn simd_structures(word: u32) -> Option<String> {
+    if word & 0xBF00_0000 == 0x0D00_0000 {
+        return simd_single_structure(word);
+    }
+    // bit 23 selects the post-indexed form
+    if word & 0xBF20_0000 != 0x0C00_0000 {
+        return None;
+    }
+    let q = bits(word, 30, 1);
+    let load = bits(word, 22, 1) == 1;
+    let post_index = bits(word, 23, 1) == 1;
+    let size = bits(word, 10, 2);
+    let (mnemonic, registers) = match bits(word, 12, 4) {
+        0b0000 => ("4", 4),
+        0b0010 => ("1", 4),
+        0b0100 => ("3", 3),
+        0b0110 => ("1", 3),
+        0b0111 => ("1", 1),
+        0b1000 => ("2", 2),
+        0b1010 => ("1", 2),
+        _ => return None,
+    };
+    if size == 3 && q == 0 && mnemonic != "1" {
+        return None;
+    }
+    let rt = bits(word, 0, 5);
+    let list: Vec<_> = (0..registers)
+        .map(|i| vector((rt + i) % 32, size, q))
+        .collect();
+    let root = if load { "ld" } else { "st" };
+    let base = reg_sp(bits(word, 5, 5), true);
+    let rm = bits(word, 16, 5);
+    let address = match (post_index, rm) {
+        (false, 0) => format!("[{base}]"),
+        (false, _) => return None,
+        (true, 31) => format!(
+            "[{base}], {}",
+            fmt_unsigned(u64::from(registers * (8 << q)))
+        ),
+        (true, _) => format!("[{base}], {}", reg(rm, true)),
+    };
+    Some(format!(
+        "{root}{mnemonic} {{{}}}, {address}",
+        list.join(", ")
+    ))
+}
+
+// ld1 to ld4 and st1 to st4 of single elements, and the replicating ld1r to ld4r
+fn simd_single_structure(word: u32) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let load = bits(word, 22, 1) == 1;
+    let post_index = bits(word, 23, 1) == 1;
+    let opcode = bits(word, 13, 3);
+    let s = bits(word, 12, 1);
+    let size = bits(word, 10, 2);
+    let registers = (opcode & 1) << 1 | bits(word, 21, 1);
+    let registers = registers + 1;
+    let rt = bits(word, 0, 5);
+    let root = if load { "ld" } else { "st" };
+    let (list, mnemonic, element) = match opcode >> 1 {
+        3 if load && s == 0 => {
+            let list: Vec<_> = (0..registers)
+                .map(|i| vector((rt + i) % 32, size, q))
+                .collect();
+            (
+                format!("{{{}}}", list.join(", ")),
+                format!("ld{registers}r"),
+                size,
+            )
+        }
+        selector => {
+            let (element, index) = match (selector, size) {
+                (0, _) => (0, q << 3 | s << 2 | size),
+                (1, 0 | 2) => (1, q << 2 | s << 1 | size >> 1),
+                (2, 0) => (2, q << 1 | s),
+                (2, 1) if s == 0 => (3, q),
+                _ => return None,
+            };
+            let name = ["b", "h", "s", "d"][element as usize];
+            let list: Vec<_> = (0..registers)
+                .map(|i| format!("v{}.{name}", (rt + i) % 32))
+                .collect();
+            (
+                format!("{{{}}}[{index}]", list.join(", ")),
+                format!("{root}{registers}"),
+                element,
+            )
+        }
+    };
+    let base = reg_sp(bits(word, 5, 5), true);
+    let rm = bits(word, 16, 5);
+    let address = match (post_index, rm) {
+        (false, 0) => format!("[{base}]"),
+        (false, _) => return None,
+        (true, 31) => format!(
+            "[{base}], {}",
+            fmt_unsigned(u64::from(registers << element))
+        ),
+        (true, _) => format!("[{base}], {}", reg(rm, true)),
+    };
+    Some(format!("{mnemonic} {list}, {address}"))
+}
+
+fn register(word: u32) -> Option<String> {
+    let wide = word >> 31 == 1;
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let rm = bits(word, 16, 5);
+    let imm6 = bits(word, 10, 6);
+    let shift = bits(word, 22, 2);
+    let shifted = |rm: u32| match imm6 {
+        0 if shift == 0 => reg(rm, wide),
+        _ => format!("{}, {} #{imm6}", reg(rm, wide), SHIFTS[shift as usize]),
+    };
+    Some(if word & 0x1F00_0000 == 0x0A00_0000 {
+        if !wide && imm6 >= 32 {
+            return None;
+        }
+        let opc = bits(word, 29, 2);
+        let negate = bits(word, 21, 1) == 1;
+        match (opc, negate) {
+            (0b01, false) if rn == 31 && imm6 == 0 && shift == 0 => {
+                format!("mov {}, {}", reg(rd, wide), reg(rm, wide))
+            }
+            (0b01, true) if rn == 31 => format!("mvn {}, {}", reg(rd, wide), shifted(rm)),
+            (0b11, false) if rd == 31 => format!("tst {}, {}", reg(rn, wide), shifted(rm)),
+            _ => {
+                let mnemonic = [
+                    ["and", "bic"],
+                    ["orr", "orn"],
+                    ["eor", "eon"],
+                    ["ands", "bics"],
+                ][opc as usize][usize::from(negate)];
+                format!(
+                    "{mnemonic} {}, {}, {}",
+                    reg(rd, wide),
+                    reg(rn, wide),
+                    shifted(rm)
+                )
+            }
+        }
+    } else if word & 0x1F20_0000 == 0x0B00_0000 {
+        if shift == 3 || (!wide && imm6 >= 32) {
+            return None;
+        }
+        let subtract = bits(word, 30, 1) == 1;
+        let flags = bits(word, 29, 1) == 1;
+        match (subtract, flags) {
+            (_, true) if rd == 31 => {
+                let mnemonic = if subtract { "cmp" } else { "cmn" };
+                format!("{mnemonic} {}, {}", reg(rn, wide), shifted(rm))
+            }
+            (true, _) if rn == 31 => {
+                let mnemonic = if flags { "negs" } else { "neg" };
+                format!("{mnemonic} {}, {}", reg(rd, wide), shifted(rm))
+            }
+            _ => {
+                let mnemonic =
+                    ["add", "adds", "sub", "subs"][usize::from(subtract) * 2 + usize::from(flags)];
+                format!(
+                    "{mnemonic} {}, {}, {}",
+                    reg(rd, wide),
+                    reg(rn, wide),
+                    shifted(rm)
+                )
+            }
+        }
+    } else if word & 0x1F20_0000 == 0x0B20_0000 {
+        let option = bits(word, 13, 3);
+        let amount = bits(word, 10, 3);
+        if shift != 0 || amount > 4 {
+            return None;
+        }
+        let subtract = bits(word, 30, 1) == 1;
+        let flags = bits(word, 29, 1) == 1;
+        let rm_text = reg(rm, wide && option & 3 == 3);
+        let default_extend = option == if wide { 0b011 } else { 0b010 };
+        let extend = match (amount, default_extend && (rn == 31 || !flags && rd == 31)) {
+            (0, true) => String::new(),
+            (_, true) => format!(", lsl #{amount}"),
+            (0, false) => format!(", {}", EXTENDS[option as usize]),
+            (_, false) => format!(", {} #{amount}", EXTENDS[option as usize]),
+        };
+        if flags && rd == 31 {
+            let mnemonic = if subtract { "cmp" } else { "cmn" };
+            format!("{mnemonic} {}, {rm_text}{extend}", reg_sp(rn, wide))
+        } else {
+            let mnemonic =
+                ["add", "adds", "sub", "subs"][usize::from(subtract) * 2 + usize::from(flags)];
+            let rd = if flags {
+                reg(rd, wide)
+            } else {
+                reg_sp(rd, wide)
+            };
+            format!("{mnemonic} {rd}, {}, {rm_text}{extend}", reg_sp(rn, wide))
+        }
+    } else if word & 0x1FE0_FC00 == 0x1A00_0000 {
+        let subtract = bits(word, 30, 1) == 1;
+        let flags = bits(word, 29, 1) == 1;
+        if subtract && rn == 31 {
+            let mnemonic = if flags { "ngcs" } else { "ngc" };
+            format!("{mnemonic} {}, {}", reg(rd, wide), reg(rm, wide))
+        } else {
+            let mnemonic =
+                ["adc", "adcs", "sbc", "sbcs"][usize::from(subtract) * 2 + usize::from(flags)];
+            format!(
+                "{mnemonic} {}, {}, {}",
+                reg(rd, wide),
+                reg(rn, wide),
+                reg(rm, wide)
+            )
+        }
+    } else if word & 0x3FE0_0410 == 0x3A40_0000 {
+        let mnemonic = if bits(word, 30, 1) == 1 {
+            "ccmp"
+        } else {
+            "ccmn"
+        };
+        let operand = match bits(word, 11, 1) {
+            1 => format!("#{rm}"),
+            _ => reg(rm, wide),
+        };
+        format!(
+            "{mnemonic} {}, {operand}, #{}, {}",
+            reg(rn, wide),
+            bits(word, 0, 4),
+            CONDITIONS[bits(word, 12, 4) as usize]
+        )
+    } else if word & 0x3FE0_0800 == 0x1A80_0000 {
+        let condition = bits(word, 12, 4);
+        let inverted = CONDITIONS[(condition ^ 1) as usize];
+        let alias = rm == rn && condition < 14;
+        match (bits(word, 30, 1), bits(word, 10, 1)) {
+            (0, 0) => format!(
+                "csel {}, {}, {}, {}",
+                reg(rd, wide),
+                reg(rn, wide),
+                reg(rm, wide),
+                CONDITIONS[condition as usize]
+            ),
+            (0, _) if alias && rn == 31 => format!("cset {}, {inverted}", reg(rd, wide)),
+            (0, _) if alias => format!("cinc {}, {}, {inverted}", reg(rd, wide), reg(rn, wide)),
+            (1, 0) if alias && rn == 31 => format!("csetm {}, {inverted}", reg(rd, wide)),
+            (1, 0) if alias => format!("cinv {}, {}, {inverted}", reg(rd, wide), reg(rn, wide)),
+            (1, _) if alias => format!("cneg {}, {}, {inverted}", reg(rd, wide), reg(rn, wide)),
+            (op, op2) => format!(
+                "{} {}, {}, {}, {}",
+                ["csinc", "csinv", "csneg"][(op * 2 + op2 - 1) as usize],
+                reg(rd, wide),
+                reg(rn, wide),
+                reg(rm, wide),
+                CONDITIONS[condition as usize]
+            ),
+        }
+    } else if word & 0x7FE0_0000 == 0x1AC0_0000 {
+        let mnemonic = match bits(word, 10, 6) {
+            0b000010 => "udiv",
+            0b000011 => "sdiv",
+            0b001000 => "lsl",
+            0b001001 => "lsr",
+            0b001010 => "asr",
+            0b001011 => "ror",
+            opcode @ 0b010000..=0b010111 => {
+                let size = opcode & 3;
+                if (size == 3) != wide {
+                    return None;
+                }
+                let c = if opcode & 4 > 0 { "c" } else { "" };
+                return Some(format!(
+                    "crc32{c}{} {}, {}, {}",
+                    ["b", "h", "w", "x"][size as usize],
+                    reg(rd, false),
+                    reg(rn, false),
+                    reg(rm, size == 3)
+                ));
+            }
+            _ => return None,
+        };
+        format!(
+            "{mnemonic} {}, {}, {}",
+            reg(rd, wide),
+            reg(rn, wide),
+            reg(rm, wide)
+        )
+    } else if word & 0x7FFF_0000 == 0x5AC0_0000 {
+        let mnemonic = match (bits(word, 10, 6), wide) {
+            (0b000000, _) => "rbit",
+            (0b000001, _) => "rev16",
+            (0b000010, false) => "rev",
+            (0b000010, true) => "rev32",
+            (0b000011, true) => "rev",
+            (0b000100, _) => "clz",
+            (0b000101, _) => "cls",
+            _ => return None,
+        };
+        format!("{mnemonic} {}, {}", reg(rd, wide), reg(rn, wide))
+    } else if word & 0x7F00_0000 == 0x1B00_0000 {
+        let ra = bits(word, 10, 5);
+        let subtract = bits(word, 15, 1) == 1;
+        let (mnemonic, alias, long) = match (bits(word, 21, 3), subtract) {
+            (0b000, false) => ("madd", "mul", false),
+            (0b000, true) => ("msub", "mneg", false),
+            (0b001, false) => ("smaddl", "smull", true),
+            (0b001, true) => ("smsubl", "smnegl", true),
+            (0b101, false) => ("umaddl", "umull", true),
+            (0b101, true) => ("umsubl", "umnegl", true),
+            (0b010, false) if wide => {
+                return Some(format!(
+                    "smulh {}, {}, {}",
+                    reg(rd, true),
+                    reg(rn, true),
+                    reg(rm, true)
+                ));
+            }
+            (0b110, false) if wide => {
+                return Some(format!(
+                    "umulh {}, {}, {}",
+                    reg(rd, true),
+                    reg(rn, true),
+                    reg(rm, true)
+                ));
+            }
+            _ => return None,
+        };
+        if long && !wide {
+            return None;
+        }
+        let sources = !long && wide;
+        if ra == 31 {
+            format!(
+                "{alias} {}, {}, {}",
+                reg(rd, wide),
+                reg(rn, sources),
+                reg(rm, sources)
+            )
+        } else {
+            format!(
+                "{mnemonic} {}, {}, {}, {}",
+                reg(rd, wide),
+                reg(rn, sources),
+                reg(rm, sources),
+                reg(ra, wide)
+            )
+        }
+    } else {
+        return None;
+    })
+}
+
+fn simd_fp(word: u32) -> Option<String> {
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let rm = bits(word, 16, 5);
+    let r#type = bits(word, 22, 2);
+    let q = bits(word, 30, 1);
+    let size = bits(word, 22, 2);
+    Some(if word & 0x7F20_FC00 == 0x1E20_0000 {
+        let wide = word >> 31 == 1;
+        let fp = Kind::fp(r#type)?;
+        let gp = reg(rd, wide);
+        match (bits(word, 19, 2), bits(word, 16, 3)) {
+            (0b00, 0b110) => format!("fmov {gp}, {}", fp.register(rn)),
+            (0b00, 0b111) => format!("fmov {}, {}", fp.register(rd), reg(rn, wide)),
+            (0b00, 0b010) => format!("scvtf {}, {}", fp.register(rd), reg(rn, wide)),
+            (0b00, 0b011) => format!("ucvtf {}, {}", fp.register(rd), reg(rn, wide)),
+            (rmode, opcode @ (0b000 | 0b001)) => {
+                let rounding = ["n", "p", "m", "z"][rmode as usize];
+                let sign = if opcode == 0 { "s" } else { "u" };
+                format!("fcvt{rounding}{sign} {gp}, {}", fp.register(rn))
+            }
+            (0b00, opcode @ (0b100 | 0b101)) => {
+                let sign = if opcode == 0b100 { "s" } else { "u" };
+                format!("fcvta{sign} {gp}, {}", fp.register(rn))
+            }
+            _ => return None,
+        }
+    } else if word & 0xFF20_7C00 == 0x1E20_4000 {
+        let fp = Kind::fp(r#type)?;
+        let opcode = bits(word, 15, 6);
+        let (mnemonic, destination) = match opcode {
+            0b000000 => ("fmov", fp),
+            0b000001 => ("fabs", fp),
+            0b000010 => ("fneg", fp),
+            0b000011 => ("fsqrt", fp),
+            0b000100 => ("fcvt", Kind::S),
+            0b000101 => ("fcvt", Kind::D),
+            0b000111 => ("fcvt", Kind::H),
+            0b001000 => ("frintn", fp),
+            0b001001 => ("frintp", fp),
+            0b001010 => ("frintm", fp),
+            0b001011 => ("frintz", fp),
+            0b001100 => ("frinta", fp),
+            0b001110 => ("frintx", fp),
+            0b001111 => ("frinti", fp),
+            _ => return None,
+        };
+        format!(
+            "{mnemonic} {}, {}",
+            destination.register(rd),
+            fp.register(rn)
+        )
+    } else if word & 0xFF20_0C00 == 0x1E20_0800 {
+        let fp = Kind::fp(r#type)?;
+        let mnemonic = [
+            "fmul", "fdiv", "fadd", "fsub", "fmax", "fmin", "fmaxnm", "fminnm", "fnmul",
+        ]
+        .get(bits(word, 12, 4) as usize)?;
+        format!(
+            "{mnemonic} {}, {}, {}",
+            fp.register(rd),
+            fp.register(rn),
+            fp.register(rm)
+        )
+    } else if word & 0xFF20_FC07 == 0x1E20_2000 {
+        let fp = Kind::fp(r#type)?;
+        let mnemonic = if bits(word, 4, 1) == 1 {
+            "fcmpe"
+        } else {
+            "fcmp"
+        };
+        match bits(word, 3, 1) {
+            1 => format!("{mnemonic} {}, #0.0", fp.register(rn)),
+            _ => format!("{mnemonic} {}, {}", fp.register(rn), fp.register(rm)),
+        }
+    } else if word & 0xFF20_0C00 == 0x1E20_0400 {
+        let fp = Kind::fp(r#type)?;
+        let mnemonic = if bits(word, 4, 1) == 1 {
+            "fccmpe"
+        } else {
+            "fccmp"
+        };
+        format!(
+            "{mnemonic} {}, {}, #{}, {}",
+            fp.register(rn),
+            fp.register(rm),
+            bits(word, 0, 4),
+            CONDITIONS[bits(word, 12, 4) as usize]
+        )
+    } else if word & 0xFF20_0C00 == 0x1E20_0C00 {
+        let fp = Kind::fp(r#type)?;
+        format!(
+            "fcsel {}, {}, {}, {}",
+            fp.register(rd),
+            fp.register(rn),
+            fp.register(rm),
+            CONDITIONS[bits(word, 12, 4) as usize]
+        )
+    } else if word & 0xFF20_1FE0 == 0x1E20_1000 {
+        let fp = Kind::fp(r#type)?;
+        format!(
+            "fmov {}, #{:?}",
+            fp.register(rd),
+            fp_immediate(bits(word, 13, 8))
+        )
+    } else if word & 0xFF00_0000 == 0x1F00_0000 {
+        let fp = Kind::fp(r#type)?;
+        let mnemonic = ["fmadd", "fmsub", "fnmadd", "fnmsub"]
+            [(bits(word, 21, 1) * 2 + bits(word, 15, 1)) as usize];
+        format!(
+            "{mnemonic} {}, {}, {}, {}",
+            fp.register(rd),
+            fp.register(rn),
+            fp.register(rm),
+            fp.register(bits(word, 10, 5))
+        )
+    } else if word & 0x7F20_0000 == 0x1E00_0000 {
+        // conversions between floating point and fixed point
+        let wide = word >> 31 == 1;
+        let fp = Kind::fp(r#type)?;
+        let scale = bits(word, 10, 6);
+        if !wide && scale < 32 {
+            return None;
+        }
+        let fbits = 64 - scale;
+        match (bits(word, 19, 2), bits(word, 16, 3)) {
+            (0b00, opcode @ (0b010 | 0b011)) => {
+                let mnemonic = if opcode == 0b010 { "scvtf" } else { "ucvtf" };
+                format!(
+                    "{mnemonic} {}, {}, #{fbits}",
+                    fp.register(rd),
+                    reg(rn, wide)
+                )
+            }
+            (0b11, opcode @ (0b000 | 0b001)) => {
+                let mnemonic = if opcode == 0b000 { "fcvtzs" } else { "fcvtzu" };
+                format!(
+                    "{mnemonic} {}, {}, #{fbits}",
+                    reg(rd, wide),
+                    fp.register(rn)
+                )
+            }
+            _ => return None,
+        }
+    } else if word & 0x9FF8_0400 == 0x0F00_0400 {
+        modified_immediate(word)?
+    } else if word & 0x9F80_0400 == 0x0F00_0400 {
+        shift_immediate(word, false)?
+    } else if word & 0xDF80_0400 == 0x5F00_0400 {
+        shift_immediate(word, true)?
+    } else if word & 0x9F00_0400 == 0x0F00_0000 {
+        indexed_element(word, false)?
+    } else if word & 0xDF00_0400 == 0x5F00_0000 {
+        indexed_element(word, true)?
+    } else if word & 0x9F20_C400 == 0x0E20_C400 {
+        fp_three_same(word, false)?
+    } else if word & 0xDF20_C400 == 0x5E20_C400 {
+        fp_three_same(word, true)?
+    } else if word & 0xDF20_0400 == 0x5E20_0400 {
+        // scalar integer operations, which are only common on doublewords
+        let mnemonic = match (bits(word, 29, 1), bits(word, 11, 5)) {
+            (0, 0b10000) => "add",
+            (1, 0b10000) => "sub",
+            (0, 0b10001) => "cmtst",
+            (1, 0b10001) => "cmeq",
+            (0, 0b00110) => "cmgt",
+            (0, 0b00111) => "cmge",
+            (1, 0b00110) => "cmhi",
+            (1, 0b00111) => "cmhs",
+            (0, 0b01000) => "sshl",
+            (1, 0b01000) => "ushl",
+            _ => return None,
+        };
+        if size != 3 {
+            return None;
+        }
+        format!(
+            "{mnemonic} {}, {}, {}",
+            Kind::D.register(rd),
+            Kind::D.register(rn),
+            Kind::D.register(rm)
+        )
+    } else if word & 0x9F3E_0C00 == 0x0E20_0800 && fp_misc(bits(word, 12, 5)) {
+        fp_two_register(word, false)?
+    } else if word & 0xDF3E_0C00 == 0x5E20_0800 && fp_misc(bits(word, 12, 5)) {
+        fp_two_register(word, true)?
+    } else if word & 0xDF3E_0C00 == 0x5E20_0800 {
+        let unsigned = bits(word, 29, 1) == 1;
+        let (mnemonic, zero) = match (unsigned, bits(word, 12, 5)) {
+            (false, 0b01000) => ("cmgt", ", #0"),
+            (true, 0b01000) => ("cmge", ", #0"),
+            (false, 0b01001) => ("cmeq", ", #0"),
+            (true, 0b01001) => ("cmle", ", #0"),
+            (false, 0b01010) => ("cmlt", ", #0"),
+            (false, 0b01011) => ("abs", ""),
+            (true, 0b01011) => ("neg", ""),
+            _ => return None,
+        };
+        if size != 3 {
+            return None;
+        }
+        format!(
+            "{mnemonic} {}, {}{zero}",
+            Kind::D.register(rd),
+            Kind::D.register(rn)
+        )
+    } else if word & 0xFFE0_FC00 == 0x5E00_0400 {
+        let imm5 = bits(word, 16, 5);
+        let size = imm5.trailing_zeros();
+        let kind = [Kind::B, Kind::H, Kind::S, Kind::D].get(size as usize)?;
+        let element = ["b", "h", "s", "d"][size as usize];
+        format!(
+            "mov {}, v{rn}.{element}[{}]",
+            kind.register(rd),
+            imm5 >> (size + 1)
+        )
+    } else if word & 0xBFE0_8C00 == 0x0E00_0000 {
+        let mnemonic = if bits(word, 12, 1) == 1 { "tbx" } else { "tbl" };
+        let list: Vec<_> = (0..=bits(word, 13, 2))
+            .map(|i| vector((rn + i) % 32, 0, 1))
+            .collect();
+        format!(
+            "{mnemonic} {}, {{{}}}, {}",
+            vector(rd, 0, q),
+            list.join(", "),
+            vector(rm, 0, q)
+        )
+    } else if word & 0x9F20_0C00 == 0x0E20_0000 {
+        three_different(word)?
+    } else if word & 0x9F20_0400 == 0x0E20_0400 {
+        let unsigned = bits(word, 29, 1) == 1;
+        let mnemonic = match (unsigned, bits(word, 11, 5), size) {
+            (false, 0b00011, 0) => "and",
+            (false, 0b00011, 1) => "bic",
+            (false, 0b00011, 2) if rn == rm => {
+                return Some(format!("mov {}, {}", vector(rd, 0, q), vector(rn, 0, q)));
+            }
+            (false, 0b00011, 2) => "orr",
+            (false, 0b00011, 3) => "orn",
+            (true, 0b00011, 0) => "eor",
+            (true, 0b00011, 1) => "bsl",
+            (true, 0b00011, 2) => "bit",
+            (true, 0b00011, 3) => "bif",
+            (false, 0b10000, _) => "add",
+            (true, 0b10000, _) => "sub",
+            (false, 0b10001, _) => "cmtst",
+            (true, 0b10001, _) => "cmeq",
+            (false, 0b00110, _) => "cmgt",
+            (false, 0b00111, _) => "cmge",
+            (true, 0b00110, _) => "cmhi",
+            (true, 0b00111, _) => "cmhs",
+            (false, 0b10011, _) => "mul",
+            (false, 0b10111, _) => "addp",
+            (false, 0b01100, _) => "smax",
+            (false, 0b01101, _) => "smin",
+            (true, 0b01100, _) => "umax",
+            (true, 0b01101, _) => "umin",
+            (false, 0b10100, _) => "smaxp",
+            (false, 0b10101, _) => "sminp",
+            (true, 0b10100, _) => "umaxp",
+            (true, 0b10101, _) => "uminp",
+            (false, 0b00000, _) => "shadd",
+            (true, 0b00000, _) => "uhadd",
+            (false, 0b00001, _) => "sqadd",
+            (true, 0b00001, _) => "uqadd",
+            (false, 0b00010, _) => "srhadd",
+            (true, 0b00010, _) => "urhadd",
+            (false, 0b00100, _) => "shsub",
+            (true, 0b00100, _) => "uhsub",
+            (false, 0b00101, _) => "sqsub",
+            (true, 0b00101, _) => "uqsub",
+            (false, 0b01000, _) => "sshl",
+            (true, 0b01000, _) => "ushl",
+            (false, 0b01010, _) => "srshl",
+            (true, 0b01010, _) => "urshl",
+            (false, 0b01110, _) => "sabd",
+            (true, 0b01110, _) => "uabd",
+            (false, 0b01111, _) => "saba",
+            (true, 0b01111, _) => "uaba",
+            (false, 0b10010, _) => "mla",
+            (true, 0b10010, _) => "mls",
+            (true, 0b10011, 0) => "pmul",
+            (false, 0b10110, 1 | 2) => "sqdmulh",
+            (true, 0b10110, 1 | 2) => "sqrdmulh",
+            _ => return None,
+        };
+        // the logical operations work on bytes whatever the size field says
+        let size = if bits(word, 11, 5) == 0b00011 {
+            0
+        } else {
+            size
+        };
+        // only the additions, subtractions, compares and shifts have 64-bit lanes
+        let no_doublewords = !matches!(
+            bits(word, 11, 5),
+            0b00001 | 0b00101 | 0b00110 | 0b00111 | 0b01000 | 0b01010 | 0b10000 | 0b10001
+        );
+        if size == 3 && (q == 0 || no_doublewords) {
+            return None;
+        }
+        format!(
+            "{mnemonic} {}, {}, {}",
+            vector(rd, size, q),
+            vector(rn, size, q),
+            vector(rm, size, q)
+        )
+    } else if word & 0x9F3E_0C00 == 0x0E30_0800 {
+        let unsigned = bits(word, 29, 1) == 1;
+        let mnemonic = match (unsigned, bits(word, 12, 5)) {
+            (false, 0b11011) => "addv",
+            (false, 0b01010) => "smaxv",
+            (true, 0b01010) => "umaxv",
+            (false, 0b11010) => "sminv",
+            (true, 0b11010) => "uminv",
+            _ => return None,
+        };
+        let scalar = [Kind::B, Kind::H, Kind::S].get(size as usize)?;
+        if size == 2 && q == 0 {
+            return None;
+        }
+        format!(
+            "{mnemonic} {}, {}",
+            scalar.register(rd),
+            vector(rn, size, q)
+        )
+    } else if word & 0x9F3E_0C00 == 0x0E20_0800 {
+        let unsigned = bits(word, 29, 1) == 1;
+        let source = vector(rn, size, q);
+        let destination = vector(rd, size, q);
+        match (unsigned, bits(word, 12, 5), size) {
+            (false, 0b00000, 0..=2) => format!("rev64 {destination}, {source}"),
+            (true, 0b00000, 0..=1) => format!("rev32 {destination}, {source}"),
+            (false, 0b00001, 0) => format!("rev16 {destination}, {source}"),
+            (false, 0b00101, 0) => format!("cnt {destination}, {source}"),
+            (true, 0b00101, 0) => format!("mvn {destination}, {source}"),
+            (false, 0b00100, 0..=2) => format!("cls {destination}, {source}"),
+            (true, 0b00100, 0..=2) => format!("clz {destination}, {source}"),
+            (true, 0b00101, 1) => format!("rbit {}, {}", vector(rd, 0, q), vector(rn, 0, q)),
+            (_, 0b01000..=0b01010, _) if size < 3 || q == 1 => {
+                let mnemonic = match (unsigned, bits(word, 12, 5)) {
+                    (false, 0b01000) => "cmgt",
+                    (true, 0b01000) => "cmge",
+                    (false, 0b01001) => "cmeq",
+                    (true, 0b01001) => "cmle",
+                    (false, _) => "cmlt",
+                    (true, _) => return None,
+                };
+                format!("{mnemonic} {destination}, {source}, #0")
+            }
+            (false, 0b01011, _) if size < 3 || q == 1 => format!("abs {destination}, {source}"),
+            (true, 0b01011, _) if size < 3 || q == 1 => format!("neg {destination}, {source}"),
+            (false, 0b10010, 0..=2) => {
+                let mnemonic = if q == 1 { "xtn2" } else { "xtn" };
+                format!(
+                    "{mnemonic} {}, {}",
+                    vector(rd, size, q),
+                    vector(rn, size + 1, 1)
+                )
+            }
+            _ => return None,
+        }
+    } else if word & 0xBFE0_8400 == 0x2E00_0000 {
+        let index = bits(word, 11, 4);
+        if q == 0 && index > 7 {
+            return None;
+        }
+        format!(
+            "ext {}, {}, {}, #{index}",
+            vector(rd, 0, q),
+            vector(rn, 0, q),
+            vector(rm, 0, q)
+        )
+    } else if word & 0xBF20_8C00 == 0x0E00_0800 {
+        let mnemonic = match bits(word, 12, 3) {
+            0b001 => "uzp1",
+            0b010 => "trn1",
+            0b011 => "zip1",
+            0b101 => "uzp2",
+            0b110 => "trn2",
+            0b111 => "zip2",
+            _ => return None,
+        };
+        if size == 3 && q == 0 {
+            return None;
+        }
+        format!(
+            "{mnemonic} {}, {}, {}",
+            vector(rd, size, q),
+            vector(rn, size, q),
+            vector(rm, size, q)
+        )
+    } else if word & 0x9FE0_8400 == 0x0E00_0400 {
+        copy(word)?
+    } else {
+        return None;
+    })
+}
+
+// dup, ins, umov and smov between vector elements and general purpose registers
+fn copy(word: u32) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let op = bits(word, 29, 1);
+    let imm5 = bits(word, 16, 5);
+    let imm4 = bits(word, 11, 4);
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let size = imm5.trailing_zeros();
+    if size > 3 {
+        return None;
+    }
+    let element = ["b", "h", "s", "d"][size as usize];
+    let index = imm5 >> (size + 1);
+    Some(match (op, imm4) {
+        (0, 0b0000) => {
+            if size == 3 && q == 0 {
+                return None;
+            }
+            format!("dup {}, v{rn}.{element}[{index}]", vector(rd, size, q))
+        }
+        (0, 0b0001) => {
+            if size == 3 && q == 0 {
+                return None;
+            }
+            format!("dup {}, {}", vector(rd, size, q), reg(rn, size == 3))
+        }
+        (0, 0b0011) if q == 1 => {
+            format!("mov v{rd}.{element}[{index}], {}", reg(rn, size == 3))
+        }
+        (0, 0b0101) => format!("smov {}, v{rn}.{element}[{index}]", reg(rd, q == 1)),
+        (0, 0b0111) => match (size, q) {
+            (2, 0) | (3, 1) => format!("mov {}, v{rn}.{element}[{index}]", reg(rd, q == 1)),
+            (0 | 1, 0) => format!("umov {}, v{rn}.{element}[{index}]", reg(rd, false)),
+            _ => return None,
+        },
+        (1, _) if q == 1 => {
+            let source = imm4 >> size;
+            format!("mov v{rd}.{element}[{index}], v{rn}.{element}[{source}]")
+        }
+        _ => return None,
+    })
+}
+
+// a vector of singles or doubles for floating point instructions, or a single register
+// of either size for their scalar forms
+fn fp_operand(n: u32, sz: u32, q: u32, scalar: bool) -> Option<String> {
+    match (scalar, sz, q) {
+        (true, 0, _) => Some(Kind::S.register(n)),
+        (true, _, _) => Some(Kind::D.register(n)),
+        (false, 1, 0) => None,
+        _ => Some(vector(n, 2 + sz, q)),
+    }
+}
+
+fn fp_three_same(word: u32, scalar: bool) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let sz = bits(word, 22, 1);
+    let mnemonic = match (bits(word, 29, 1), bits(word, 23, 1), bits(word, 11, 5)) {
+        (0, 0, 0b11000) => "fmaxnm",
+        (0, 0, 0b11001) => "fmla",
+        (0, 0, 0b11010) => "fadd",
+        (0, 0, 0b11011) => "fmulx",
+        (0, 0, 0b11100) => "fcmeq",
+        (0, 0, 0b11110) => "fmax",
+        (0, 0, 0b11111) => "frecps",
+        (0, 1, 0b11000) => "fminnm",
+        (0, 1, 0b11001) => "fmls",
+        (0, 1, 0b11010) => "fsub",
+        (0, 1, 0b11110) => "fmin",
+        (0, 1, 0b11111) => "frsqrts",
+        (1, 0, 0b11000) => "fmaxnmp",
+        (1, 0, 0b11010) => "faddp",
+        (1, 0, 0b11011) => "fmul",
+        (1, 0, 0b11100) => "fcmge",
+        (1, 0, 0b11101) => "facge",
+        (1, 0, 0b11110) => "fmaxp",
+        (1, 0, 0b11111) => "fdiv",
+        (1, 1, 0b11000) => "fminnmp",
+        (1, 1, 0b11010) => "fabd",
+        (1, 1, 0b11100) => "fcmgt",
+        (1, 1, 0b11101) => "facgt",
+        (1, 1, 0b11110) => "fminp",
+        _ => return None,
+    };
+    // the scalar forms are the compares, fabd and the reciprocal steps
+    if scalar
+        && !matches!(
+            mnemonic,
+            "fmulx"
+                | "fcmeq"
+                | "fcmge"
+                | "fcmgt"
+                | "facge"
+                | "facgt"
+                | "fabd"
+                | "frecps"
+                | "frsqrts"
+        )
+    {
+        return None;
+    }
+    let operand = |low| fp_operand(bits(word, low, 5), sz, q, scalar);
+    Some(format!(
+        "{mnemonic} {}, {}, {}",
+        operand(0)?,
+        operand(5)?,
+        operand(16)?
+    ))
+}
+
+// the opcodes of two register operations that work on floating point values
+fn fp_misc(opcode: u32) -> bool {
+    matches!(opcode, 0b01100..=0b01111 | 0b10110..=0b11111)
+}
+
+fn fp_two_register(word: u32, scalar: bool) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let sz = bits(word, 22, 1);
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let (mnemonic, zero) = match (bits(word, 29, 1), bits(word, 23, 1), bits(word, 12, 5)) {
+        (0, 0, opcode @ (0b10110 | 0b10111)) if !scalar => {
+            // fcvtn narrows into half the lanes and fcvtl widens from them
+            let high = if q == 1 { "2" } else { "" };
+            let narrow = |n| vector(n, 1 + sz, q);
+            let wide = |n| vector(n, 2 + sz, 1);
+            return Some(match opcode {
+                0b10110 => format!("fcvtn{high} {}, {}", narrow(rd), wide(rn)),
+                _ => format!("fcvtl{high} {}, {}", wide(rd), narrow(rn)),
+            });
+        }
+        (0, 0, 0b11000) if !scalar => ("frintn", ""),
+        (0, 0, 0b11001) if !scalar => ("frintm", ""),
+        (0, 1, 0b11000) if !scalar => ("frintp", ""),
+        (0, 1, 0b11001) if !scalar => ("frintz", ""),
+        (1, 0, 0b11000) if !scalar => ("frinta", ""),
+        (1, 0, 0b11001) if !scalar => ("frintx", ""),
+        (1, 1, 0b11001) if !scalar => ("frinti", ""),
+        (0, 1, 0b01111) if !scalar => ("fabs", ""),
+        (1, 1, 0b01111) if !scalar => ("fneg", ""),
+        (1, 1, 0b11111) if !scalar => ("fsqrt", ""),
+        (0, 0, 0b11010) => ("fcvtns", ""),
+        (0, 0, 0b11011) => ("fcvtms", ""),
+        (0, 0, 0b11100) => ("fcvtas", ""),
+        (0, 0, 0b11101) => ("scvtf", ""),
+        (0, 1, 0b11010) => ("fcvtps", ""),
+        (0, 1, 0b11011) => ("fcvtzs", ""),
+        (0, 1, 0b11101) => ("frecpe", ""),
+        (1, 0, 0b11010) => ("fcvtnu", ""),
+        (1, 0, 0b11011) => ("fcvtmu", ""),
+        (1, 0, 0b11100) => ("fcvtau", ""),
+        (1, 0, 0b11101) => ("ucvtf", ""),
+        (1, 1, 0b11010) => ("fcvtpu", ""),
+        (1, 1, 0b11011) => ("fcvtzu", ""),
+        (1, 1, 0b11101) => ("frsqrte", ""),
+        (0, 1, 0b01100) => ("fcmgt", ", #0.0"),
+        (0, 1, 0b01101) => ("fcmeq", ", #0.0"),
+        (0, 1, 0b01110) => ("fcmlt", ", #0.0"),
+        (1, 1, 0b01100) => ("fcmge", ", #0.0"),
+        (1, 1, 0b01101) => ("fcmle", ", #0.0"),
+        _ => return None,
+    };
+    Some(format!(
+        "{mnemonic} {}, {}{zero}",
+        fp_operand(rd, sz, q, scalar)?,
+        fp_operand(rn, sz, q, scalar)?
+    ))
+}
+
+// multiplies by one element of a vector, for whole vectors or with `scalar` single registers
+fn indexed_element(word: u32, scalar: bool) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let size = bits(word, 22, 2);
+    let (h, l, m) = (bits(word, 11, 1), bits(word, 21, 1), bits(word, 20, 1));
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    // the long forms widen into the next size up
+    let (mnemonic, long, fp) = match (bits(word, 29, 1), bits(word, 12, 4)) {
+        (0, 0b0001) => ("fmla", false, true),
+        (0, 0b0101) => ("fmls", false, true),
+        (0, 0b1001) => ("fmul", false, true),
+        (1, 0b1001) => ("fmulx", false, true),
+        (1, 0b0000) if !scalar => ("mla", false, false),
+        (1, 0b0100) if !scalar => ("mls", false, false),
+        (0, 0b1000) if !scalar => ("mul", false, false),
+        (0, 0b1100) => ("sqdmulh", false, false),
+        (0, 0b1101) => ("sqrdmulh", false, false),
+        (0, 0b0010) if !scalar => ("smlal", true, false),
+        (1, 0b0010) if !scalar => ("umlal", true, false),
+        (0, 0b0110) if !scalar => ("smlsl", true, false),
+        (1, 0b0110) if !scalar => ("umlsl", true, false),
+        (0, 0b1010) if !scalar => ("smull", true, false),
+        (1, 0b1010) if !scalar => ("umull", true, false),
+        (0, 0b0011) => ("sqdmlal", true, false),
+        (0, 0b0111) => ("sqdmlsl", true, false),
+        (0, 0b1011) => ("sqdmull", true, false),
+        _ => return None,
+    };
+    let (index, rm) = match (fp, size) {
+        (false, 1) => (h << 2 | l << 1 | m, bits(word, 16, 4)),
+        (_, 2) => (h << 1 | l, bits(word, 16, 5)),
+        (true, 3) if l == 0 => (h, bits(word, 16, 5)),
+        _ => return None,
+    };
+    let element = ["b", "h", "s", "d"][size as usize];
+    let scalars = [Kind::B, Kind::H, Kind::S, Kind::D, Kind::Q];
+    let operand = |n, size: u32, q| match scalar {
+        true => scalars[size as usize].register(n),
+        false => vector(n, size, q),
+    };
+    if fp && size == 3 && q == 0 && !scalar {
+        return None;
+    }
+    let destination = match long {
+        true => operand(rd, size + 1, 1),
+        false => operand(rd, size, q),
+    };
+    let high = if long && q == 1 && !scalar { "2" } else { "" };
+    Some(format!(
+        "{mnemonic}{high} {destination}, {}, v{rm}.{element}[{index}]",
+        operand(rn, size, q)
+    ))
+}
+
+// operations that widen or narrow between a vector and one with elements twice the size
+fn three_different(word: u32) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let size = bits(word, 22, 2);
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    let rm = bits(word, 16, 5);
+    let unsigned = bits(word, 29, 1) == 1;
+    let opcode = bits(word, 12, 4);
+    let sign = if unsigned { "u" } else { "s" };
+    let root = match (unsigned, opcode) {
+        (_, 0b0000) => "addl",
+        (_, 0b0001) => "addw",
+        (_, 0b0010) => "subl",
+        (_, 0b0011) => "subw",
+        (false, 0b0100) => "addhn",
+        (true, 0b0100) => "raddhn",
+        (_, 0b0101) => "abal",
+        (false, 0b0110) => "subhn",
+        (true, 0b0110) => "rsubhn",
+        (_, 0b0111) => "abdl",
+        (_, 0b1000) => "mlal",
+        (_, 0b1010) => "mlsl",
+        (_, 0b1100) => "mull",
+        (false, 0b1001) => "qdmlal",
+        (false, 0b1011) => "qdmlsl",
+        (false, 0b1101) => "qdmull",
+        (false, 0b1110) if size == 0 => "pmull",
+        _ => return None,
+    };
+    // the saturating doubling forms have no byte elements
+    if size == 3 || size == 0 && matches!(opcode, 0b1001 | 0b1011 | 0b1101) {
+        return None;
+    }
+    let high = if q == 1 { "2" } else { "" };
+    let mnemonic = match opcode {
+        0b0100 | 0b0110 | 0b1110 => format!("{root}{high}"),
+        _ => format!("{sign}{root}{high}"),
+    };
+    let wide = |n| vector(n, size + 1, 1);
+    let narrow = |n| vector(n, size, q);
+    Some(match opcode {
+        0b0001 | 0b0011 => format!("{mnemonic} {}, {}, {}", wide(rd), wide(rn), narrow(rm)),
+        0b0100 | 0b0110 => format!("{mnemonic} {}, {}, {}", narrow(rd), wide(rn), wide(rm)),
+        _ => format!("{mnemonic} {}, {}, {}", wide(rd), narrow(rn), narrow(rm)),
+    })
+}
+
+// movi, mvni and fmov of an immediate into a vector
+fn modified_immediate(word: u32) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let op = bits(word, 29, 1);
+    let cmode = bits(word, 12, 4);
+    let rd = bits(word, 0, 5);
+    let imm8 = bits(word, 16, 3) << 5 | bits(word, 5, 5);
+    if bits(word, 11, 1) != 0 {
+        return None;
+    }
+    Some(match (op, cmode) {
+        (0, 0b1110) => format!("movi {}, {}", vector(rd, 0, q), fmt_unsigned(imm8.into())),
+        (1, 0b1110) => {
+            let value = (0..8).fold(0u64, |value, bit| match imm8 >> bit & 1 {
+                1 => value | 0xFF << (bit * 8),
+                _ => value,
+            });
+            match q {
+                0 => format!("movi d{rd}, {}", fmt_unsigned(value)),
+                _ => format!("movi v{rd}.2d, {}", fmt_unsigned(value)),
+            }
+        }
+        (_, 0b1100 | 0b1101) => {
+            let mnemonic = if op == 0 { "movi" } else { "mvni" };
+            format!(
+                "{mnemonic} {}, {}, msl #{}",
+                vector(rd, 2, q),
+                fmt_unsigned(imm8.into()),
+                ((cmode & 1) + 1) * 8
+            )
+        }
+        (_, 0b0000..=0b1011) => {
+            // even modes move the shifted immediate, odd ones or it in or clear it
+            let mnemonic = match (cmode & 1, op) {
+                (0, 0) => "movi",
+                (0, _) => "mvni",
+                (_, 0) => "orr",
+                _ => "bic",
+            };
+            let (size, shift) = match cmode {
+                0b1000..=0b1011 => (1, (cmode >> 1 & 1) * 8),
+                _ => (2, (cmode >> 1 & 3) * 8),
+            };
+            match shift {
+                0 => format!(
+                    "{mnemonic} {}, {}",
+                    vector(rd, size, q),
+                    fmt_unsigned(imm8.into())
+                ),
+                _ => format!(
+                    "{mnemonic} {}, {}, lsl #{shift}",
+                    vector(rd, size, q),
+                    fmt_unsigned(imm8.into())
+                ),
+            }
+        }
+        (0, 0b1111) => format!("fmov {}, #{:?}", vector(rd, 2, q), fp_immediate(imm8)),
+        (1, 0b1111) if q == 1 => format!("fmov v{rd}.2d, #{:?}", fp_immediate(imm8)),
+        _ => return None,
+    })
+}
+
+// shifts by an immediate of vectors, or with `scalar` of doublewords, including the widening
+// sshll and ushll and conversions to and from fixed point
+fn shift_immediate(word: u32, scalar: bool) -> Option<String> {
+    let q = bits(word, 30, 1);
+    let unsigned = bits(word, 29, 1) == 1;
+    let immh = bits(word, 19, 4);
+    let immhb = bits(word, 16, 7);
+    let rd = bits(word, 0, 5);
+    let rn = bits(word, 5, 5);
+    if immh == 0 {
+        return None;
+    }
+    let size = 31 - immh.leading_zeros();
+    let element_bits = 8 << size;
+    if size == 3 && q == 0 {
+        return None;
+    }
+    let right = 2 * element_bits - immhb;
+    let left = immhb - element_bits;
+    let operand = |n| match scalar {
+        true => [Kind::B, Kind::H, Kind::S, Kind::D][size as usize].register(n),
+        false => vector(n, size, q),
+    };
+    let opcode = bits(word, 11, 5);
+    let mnemonic = match (unsigned, opcode) {
+        (false, 0b00000) => "sshr",
+        (true, 0b00000) => "ushr",
+        (false, 0b00010) => "ssra",
+        (true, 0b00010) => "usra",
+        (false, 0b00100) => "srshr",
+        (true, 0b00100) => "urshr",
+        (false, 0b00110) => "srsra",
+        (true, 0b00110) => "ursra",
+        (true, 0b01000) => "sri",
+        (false, 0b01010) => "shl",
+        (true, 0b01010) => "sli",
+        (false, 0b11100) => "scvtf",
+        (true, 0b11100) => "ucvtf",
+        (false, 0b11111) => "fcvtzs",
+        (true, 0b11111) => "fcvtzu",
+        (false, 0b10000) if size < 3 && !scalar => {
+            let mnemonic = if q == 1 { "shrn2" } else { "shrn" };
+            return Some(format!(
+                "{mnemonic} {}, {}, #{right}",
+                vector(rd, size, q),
+                vector(rn, size + 1, 1)
+            ));
+        }
+        (_, 0b10100) if size < 3 && !scalar => {
+            let sign = if unsigned { "u" } else { "s" };
+            let high = if q == 1 { "2" } else { "" };
+            let destination = vector(rd, size + 1, 1);
+            let source = vector(rn, size, q);
+            return Some(match left {
+                0 => format!("{sign}xtl{high} {destination}, {source}"),
+                _ => format!("{sign}shll{high} {destination}, {source}, #{left}"),
+            });
+        }
+        _ => return None,
+    };
+    // the conversions work on singles and doubles, and the scalar shifts only on doublewords
+    let conversion = opcode >= 0b11100;
+    if conversion && size < 2 || !conversion && scalar && size != 3 {
+        return None;
+    }
+    let amount = match opcode {
+        0b01010 => left,
+        _ => right,
+    };
+    Some(format!(
+        "{mnemonic} {}, {}, #{amount}",
+        operand(rd),
+        operand(rn)
+    ))
+}
+
+// instructions are always little endian, even in big endian images
+pub(super) fn decode(code: &[u8], address: u64) -> Instruction {
+    let Some(&word) = code.first_chunk::<4>() else {
+        return Instruction {
+            len: code.len().max(1),
+            text: "(bad)".into(),
+            reference: None,
+        };
+    };
+    let word = u32::from_le_bytes(word);
+    let mut decoder = Decoder {
+        address,
+        reference: None,
+    };
+    let text = decoder
+        .instruction(word)
+        .unwrap_or_else(|| "(unknown)".into());
+    Instruction {
+        len: 4,
+        text,
+        reference: decoder.reference,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // encodings assembled by llvm-mc and checked against llvm-objdump, which writes the
+    // immediates in decimal
+    #[test]
+    fn instructions() {
+        let cases = [
+            (0x9100_4020, "add x0, x1, #0x10"),
+            (0x1140_0483, "add w3, w4, #0x1, lsl #12"),
+            (0x2B23_C841, "adds w1, w2, w3, sxtw #2"),
+            (0x9100_03E0, "mov x0, sp"),
+            (0x1280_0000, "mov w0, #0xFFFFFFFF"),
+            (0xD2A2_4683, "mov x3, #0x12340000"),
+            (0x9240_1C20, "and x0, x1, #0xFF"),
+            (0x3204_CC62, "orr w2, w3, #0xF0F0F0F0"),
+            (0xAA21_03E0, "mvn x0, x1"),
+            (0x937F_FCA4, "asr x4, x5, #63"),
+            (0xB378_3C20, "bfi x0, x1, #8, #16"),
+            (0x5300_1C20, "uxtb w0, w1"),
+            (0x93C2_3020, "extr x0, x1, x2, #12"),
+            (0x9B02_8C20, "msub x0, x1, x2, x3"),
+            (0x9AC2_0C20, "sdiv x0, x1, x2"),
+            (0x5AC0_0420, "rev16 w0, w1"),
+            (0x1A82_1420, "csinc w0, w1, w2, ne"),
+            (0xDA81_5420, "cneg x0, x1, mi"),
+            (0xFA43_1804, "ccmp x0, #3, #4, ne"),
+            (0xD65F_03C0, "ret"),
+            (0xD65F_0060, "ret x3"),
+            (0xF940_0020, "ldr x0, [x1]"),
+            (0xB940_0420, "ldr w0, [x1, #0x4]"),
+            (0xF840_8C20, "ldr x0, [x1, #0x8]!"),
+            (0xF840_8420, "ldr x0, [x1], #0x8"),
+            (0xF85F_D020, "ldur x0, [x1, #-0x3]"),
+            (0x78A2_D820, "ldrsh x0, [x1, w2, sxtw #1]"),
+            (0x3900_0420, "strb w0, [x1, #0x1]"),
+            (0xA8C1_7BFD, "ldp x29, x30, [sp], #0x10"),
+            (0xA9BE_7BFD, "stp x29, x30, [sp, #-0x20]!"),
+            (0x6940_0440, "ldpsw x0, x1, [x2]"),
+            (0x3DC0_0000, "ldr q0, [x0]"),
+            (0xFD00_07E1, "str d1, [sp, #0x8]"),
+            (0xBC62_7822, "ldr s2, [x1, x2, lsl #2]"),
+            (0xC89F_FC20, "stlr x0, [x1]"),
+            (0xC8E0_FC41, "casal x0, x1, [x2]"),
+            (0xB820_0041, "ldadd w0, w1, [x2]"),
+            (0xF8E0_8041, "swpal x0, x1, [x2]"),
+            (0xF980_0000, "prfm pldl1keep, [x0]"),
+            (0xD503_201F, "nop"),
+            (0xD503_3BBF, "dmb ish"),
+            (0xD400_0001, "svc #0x0"),
+            (0xD503_245F, "bti c"),
+            (0xD53B_D040, "mrs x0, tpidr_el0"),
+            (0xD51B_D041, "msr tpidr_el0, x1"),
+            (0x1E22_0820, "fmul s0, s1, s2"),
+            (0x1E62_3820, "fsub d0, d1, d2"),
+            (0x1F42_0C20, "fmadd d0, d1, d2, d3"),
+            (0x1E21_4020, "fneg s0, s1"),
+            (0x1E20_2008, "fcmp s0, #0.0"),
+            (0x1E62_CC20, "fcsel d0, d1, d2, gt"),
+            (0x1E6E_1000, "fmov d0, #1.0"),
+            (0x9E67_0020, "fmov d0, x1"),
+            (0x9E78_0020, "fcvtzs x0, d1"),
+            (0x1E64_4020, "frintn d0, d1"),
+            (0x4E62_9C20, "mul v0.8h, v1.8h, v2.8h"),
+            (0x4EA1_1C20, "mov v0.16b, v1.16b"),
+            (0x6EA2_8C20, "cmeq v0.4s, v1.4s, v2.4s"),
+            (0x4EB1_B820, "addv s0, v1.4s"),
+            (0x4E04_0C20, "dup v0.4s, w1"),
+            (0x4E18_0420, "dup v0.2d, v1.d[1]"),
+            (0x4E18_3C20, "mov x0, v1.d[1]"),
+            (0x6F00_E400, "movi v0.2d, #0x0"),
+            (0x4F07_27E0, "movi v0.4s, #0xFF, lsl #8"),
+            (0x4E22_D420, "fadd v0.4s, v1.4s, v2.4s"),
+            (0x6F79_0420, "ushr v0.2d, v1.2d, #7"),
+            (0x4C40_7000, "ld1 {v0.16b}, [x0]"),
+            (0x4C9F_A820, "st1 {v0.4s, v1.4s}, [x1], #0x20"),
+            (0x0D40_9000, "ld1 {v0.s}[1], [x0]"),
+            (0x4E02_0020, "tbl v0.16b, {v1.16b}, v2.16b"),
+            (0x4E42_5820, "uzp2 v0.8h, v1.8h, v2.8h"),
+            (0x6EA2_C020, "umull2 v0.2d, v1.4s, v2.4s"),
+            (0x4FA2_8820, "mul v0.4s, v1.4s, v2.s[3]"),
+            (0x4EA0_0820, "rev64 v0.4s, v1.4s"),
+            (0x0000_0000, "udf #0x0"),
+        ];
+        for (word, text) in cases {
+            let instruction = decode(&u32::to_le_bytes(word), 0);
+            assert_eq!(instruction.text, text, "0x{word:08X}");
+            assert_eq!(instruction.len, 4);
+            assert!(instruction.reference.is_none(), "0x{word:08X}");
+        }
+    }
+
+    #[test]
+    fn branches() {
+        let cases = [
+            (0x1400_0004, "b 0x1010", 0x1010),
+            (0x97FF_FFFF, "bl 0xFFC", 0xFFC),
+            (0x5400_0081, "b.ne 0x1010", 0x1010),
+            (0xB400_0040, "cbz x0, 0x1008", 0x1008),
+            (0x36F8_0041, "tbz w1, #31, 0x1008", 0x1008),
+            (0x1000_0040, "adr x0, 0x1008", 0x1008),
+            (0xB000_0001, "adrp x1, 0x2000", 0x2000),
+            (0x5800_0040, "ldr x0, 0x1008", 0x1008),
+        ];
+        for (word, text, target) in cases {
+            let instruction = decode(&u32::to_le_bytes(word), 0x1000);
+            assert_eq!(instruction.text, text, "0x{word:08X}");
+            assert!(
+                matches!(instruction.reference, Some(Reference::Operand(address)) if address == target),
+                "0x{word:08X}"
+            );
+        }
+    }
+
+    #[test]
+    fn undecoded() {
+        assert_eq!(decode(&u32::to_le_bytes(0x6E30_3820), 0).text, "(unknown)");
+        let truncated = decode(&[0x1F, 0x20], 0);
+        assert_eq!((truncated.len, truncated.text.as_str()), (2, "(bad)"));
+    }
+}
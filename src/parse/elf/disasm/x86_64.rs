@@ -0,0 +1,1712 @@
+use super::{Instruction, Reference};
+
+// longest encoding the processor accepts, prefixes included
+const MAX_LENGTH: usize = 15;
+
+const REGS_64: [&str; 16] = [
+    "rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi", "r8", "r9", "r10", "r11", "r12", "r13",
+    "r14", "r15",
+];
+const REGS_32: [&str; 16] = [
+    "eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi", "r8d", "r9d", "r10d", "r11d", "r12d",
+    "r13d", "r14d", "r15d",
+];
+const REGS_16: [&str; 16] = [
+    "ax", "cx", "dx", "bx", "sp", "bp", "si", "di", "r8w", "r9w", "r10w", "r11w", "r12w", "r13w",
+    "r14w", "r15w",
+];
+const REGS_8: [&str; 16] = [
+    "al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil", "r8b", "r9b", "r10b", "r11b", "r12b",
+    "r13b", "r14b", "r15b",
+];
+// without a REX prefix, byte registers 4 to 7 are the high bytes of the first four registers
+const REGS_8_LEGACY: [&str; 8] = ["al", "cl", "dl", "bl", "ah", "ch", "dh", "bh"];
+const CONDITIONS: [&str; 16] = [
+    "o", "no", "b", "ae", "e", "ne", "be", "a", "s", "ns", "p", "np", "l", "ge", "le", "g",
+];
+const ARITHMETIC: [&str; 8] = ["add", "or", "adc", "sbb", "and", "sub", "xor", "cmp"];
+const SHIFTS: [&str; 8] = ["rol", "ror", "rcl", "rcr", "shl", "shr", "sal", "sar"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Size {
+    Byte,
+    Word,
+    Dword,
+    Qword,
+    Xmm,
+    Ymm,
+}
+
+impl Size {
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::Byte => "byte",
+            Self::Word => "word",
+            Self::Dword => "dword",
+            Self::Qword => "qword",
+            Self::Xmm => "xmmword",
+            Self::Ymm => "ymmword",
+        }
+    }
+
+    // bytes of an immediate operand, which are at most four apart from `mov r64, imm64`
+    fn immediate_len(self) -> usize {
+        match self {
+            Self::Byte => 1,
+            Self::Word => 2,
+            _ => 4,
+        }
+    }
+}
+
+enum Operand {
+    Register(usize),
+    Memory(String),
+}
+
+struct ModRm {
+    mode: u8,
+    reg: usize,
+    rm: Operand,
+}
+
+// the mandatory prefix selecting between forms of SSE instructions
+#[derive(Clone, Copy, PartialEq)]
+enum Mandatory {
+    None,
+    OperandSize,
+    Rep,
+    Repne,
+}
+
+#[derive(Default)]
+struct Decoder<'a> {
+    code: &'a [u8],
+    address: u64,
+    pos: usize,
+    operand_size: bool,
+    address_size: bool,
+    rep: bool,
+    repne: bool,
+    lock: bool,
+    segment: Option<&'static str>,
+    rex: u8,
+    rip_displacement: Option<i64>,
+    branch: Option<u64>,
+}
+
+fn fmt_immediate(value: i64, size: Size) -> String {
+    let value = value as u64;
+    match size {
+        Size::Byte => format!("0x{:X}", value as u8),
+        Size::Word => format!("0x{:X}", value as u16),
+        Size::Dword => format!("0x{:X}", value as u32),
+        _ => format!("0x{value:X}"),
+    }
+}
+
+// packed integer operations taking `xmm, xmm/m128` in their 0x66 prefixed form
+fn packed_integer(opcode: u8) -> Option<&'static str> {
+    Some(match opcode {
+        0x60 => "punpcklbw",
+        0x61 => "punpcklwd",
+        0x62 => "punpckldq",
+        0x63 => "packsswb",
+        0x64 => "pcmpgtb",
+        0x65 => "pcmpgtw",
+        0x66 => "pcmpgtd",
+        0x67 => "packuswb",
+        0x68 => "punpckhbw",
+        0x69 => "punpckhwd",
+        0x6A => "punpckhdq",
+        0x6B => "packssdw",
+        0x6C => "punpcklqdq",
+        0x6D => "punpckhqdq",
+        0x74 => "pcmpeqb",
+        0x75 => "pcmpeqw",
+        0x76 => "pcmpeqd",
+        0xD1 => "psrlw",
+        0xD2 => "psrld",
+        0xD3 => "psrlq",
+        0xD4 => "paddq",
+        0xD5 => "pmullw",
+        0xD8 => "psubusb",
+        0xD9 => "psubusw",
+        0xDA => "pminub",
+        0xDB => "pand",
+        0xDC => "paddusb",
+        0xDD => "paddusw",
+        0xDE => "pmaxub",
+        0xDF => "pandn",
+        0xE0 => "pavgb",
+        0xE1 => "psraw",
+        0xE2 => "psrad",
+        0xE3 => "pavgw",
+        0xE4 => "pmulhuw",
+        0xE5 => "pmulhw",
+        0xE8 => "psubsb",
+        0xE9 => "psubsw",
+        0xEA => "pminsw",
+        0xEB => "por",
+        0xEC => "paddsb",
+        0xED => "paddsw",
+        0xEE => "pmaxsw",
+        0xEF => "pxor",
+        0xF1 => "psllw",
+        0xF2 => "pslld",
+        0xF3 => "psllq",
+        0xF4 => "pmuludq",
+        0xF5 => "pmaddwd",
+        0xF6 => "psadbw",
+        0xF8 => "psubb",
+        0xF9 => "psubw",
+        0xFA => "psubd",
+        0xFB => "psubq",
+        0xFC => "paddb",
+        0xFD => "paddw",
+        0xFE => "paddd",
+        _ => return None,
+    })
+}
+
+// the same for the 0x0F 0x38 opcode map
+fn packed_integer_38(opcode: u8) -> Option<&'static str> {
+    Some(match opcode {
+        0x00 => "pshufb",
+        0x01 => "phaddw",
+        0x02 => "phaddd",
+        0x04 => "pmaddubsw",
+        0x05 => "phsubw",
+        0x06 => "phsubd",
+        0x08 => "psignb",
+        0x09 => "psignw",
+        0x0A => "psignd",
+        0x0B => "pmulhrsw",
+        0x1C => "pabsb",
+        0x1D => "pabsw",
+        0x1E => "pabsd",
+        0x20 => "pmovsxbw",
+        0x21 => "pmovsxbd",
+        0x22 => "pmovsxbq",
+        0x23 => "pmovsxwd",
+        0x24 => "pmovsxwq",
+        0x25 => "pmovsxdq",
+        0x28 => "pmuldq",
+        0x29 => "pcmpeqq",
+        0x2B => "packusdw",
+        0x30 => "pmovzxbw",
+        0x31 => "pmovzxbd",
+        0x32 => "pmovzxbq",
+        0x33 => "pmovzxwd",
+        0x34 => "pmovzxwq",
+        0x35 => "pmovzxdq",
+        0x37 => "pcmpgtq",
+        0x38 => "pminsb",
+        0x39 => "pminsd",
+        0x3A => "pminuw",
+        0x3B => "pminud",
+        0x3C => "pmaxsb",
+        0x3D => "pmaxsd",
+        0x3E => "pmaxuw",
+        0x3F => "pmaxud",
+        0x40 => "pmulld",
+        _ => return None,
+    })
+}
+
+// scalar and packed floating point operations of opcodes 0x51 to 0x5F, without their suffix
+fn floating_point(opcode: u8) -> Option<&'static str> {
+    Some(match opcode {
+        0x51 => "sqrt",
+        0x58 => "add",
+        0x59 => "mul",
+        0x5C => "sub",
+        0x5D => "min",
+        0x5E => "div",
+        0x5F => "max",
+        _ => return None,
+    })
+}
+
+impl Decoder<'_> {
+    fn byte(&mut self) -> Option<u8> {
+        if self.pos >= MAX_LENGTH {
+            return None;
+        }
+        let byte = *self.code.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    // little endian and sign extended
+    fn immediate(&mut self, len: usize) -> Option<i64> {
+        let mut value = 0u64;
+        for i in 0..len {
+            value |= u64::from(self.byte()?) << (8 * i);
+        }
+        let shift = 64 - 8 * len;
+        Some(((value << shift) as i64) >> shift)
+    }
+
+    fn mandatory(&self) -> Mandatory {
+        if self.repne {
+            Mandatory::Repne
+        } else if self.rep {
+            Mandatory::Rep
+        } else if self.operand_size {
+            Mandatory::OperandSize
+        } else {
+            Mandatory::None
+        }
+    }
+
+    fn rex_w(&self) -> bool {
+        self.rex & 8 > 0
+    }
+
+    fn operand(&self) -> Size {
+        if self.rex_w() {
+            Size::Qword
+        } else if self.operand_size {
+            Size::Word
+        } else {
+            Size::Dword
+        }
+    }
+
+    // operands of push, pop and indirect branches default to 64 bits
+    fn stack_operand(&self) -> Size {
+        if self.operand_size {
+            Size::Word
+        } else {
+            Size::Qword
+        }
+    }
+
+    // general purpose operands of SSE instructions, where a 0x66 prefix selects the form
+    fn sse_operand(&self) -> Size {
+        if self.rex_w() {
+            Size::Qword
+        } else {
+            Size::Dword
+        }
+    }
+
+    fn register(&self, n: usize, size: Size) -> String {
+        match size {
+            Size::Byte if self.rex == 0 => REGS_8_LEGACY[n & 7].into(),
+            Size::Byte => REGS_8[n].into(),
+            Size::Word => REGS_16[n].into(),
+            Size::Dword => REGS_32[n].into(),
+            Size::Qword => REGS_64[n].into(),
+            Size::Xmm => format!("xmm{n}"),
+            Size::Ymm => format!("ymm{n}"),
+        }
+    }
+
+    fn rm(&self, operand: &Operand, register_size: Size, memory_size: Size) -> String {
+        match operand {
+            Operand::Register(n) => self.register(*n, register_size),
+            Operand::Memory(address) => format!("{} ptr {address}", memory_size.keyword()),
+        }
+    }
+
+    fn modrm(&mut self) -> Option<ModRm> {
+        let byte = self.byte()?;
+        let mode = byte >> 6;
+        let reg = usize::from((byte >> 3) & 7) | usize::from(self.rex & 4) << 1;
+        let low = byte & 7;
+        let rm = if mode == 3 {
+            Operand::Register(usize::from(low) | usize::from(self.rex & 1) << 3)
+        } else {
+            Operand::Memory(self.memory(mode, low)?)
+        };
+        Some(ModRm { mode, reg, rm })
+    }
+
+    fn memory(&mut self, mode: u8, low: u8) -> Option<String> {
+        let registers = if self.address_size {
+            &REGS_32
+        } else {
+            &REGS_64
+        };
+        let extend_base = usize::from(self.rex & 1) << 3;
+        let mut parts = vec![];
+        let mut no_base = false;
+        let mut rip = false;
+        if low == 4 {
+            let sib = self.byte()?;
+            let base = sib & 7;
+            if mode == 0 && base == 5 {
+                no_base = true;
+            } else {
+                parts.push(registers[usize::from(base) | extend_base].to_owned());
+            }
+            let index = usize::from((sib >> 3) & 7) | usize::from(self.rex & 2) << 2;
+            if index != 4 {
+                parts.push(format!("{}*{}", registers[index], 1 << (sib >> 6)));
+            }
+        } else if mode == 0 && low == 5 {
+            rip = true;
+            parts.push(if self.address_size { "eip" } else { "rip" }.to_owned());
+        } else {
+            parts.push(registers[usize::from(low) | extend_base].to_owned());
+        }
+        let displacement = match mode {
+            0 if rip || no_base => self.immediate(4)?,
+            0 => 0,
+            1 => self.immediate(1)?,
+            _ => self.immediate(4)?,
+        };
+        if rip {
+            self.rip_displacement = Some(displacement);
+        }
+
+        let mut address = parts.join("+");
+        if parts.is_empty() {
+            address = format!("0x{:X}", displacement as u64);
+        } else if displacement > 0 {
+            address += &format!("+0x{displacement:X}");
+        } else if displacement < 0 {
+            address += &format!("-0x{:X}", displacement.unsigned_abs());
+        }
+        let segment = self
+            .segment
+            .map(|segment| format!("{segment}:"))
+            .unwrap_or_default();
+        Some(format!("{segment}[{address}]"))
+    }
+
+    fn relative(&mut self, mnemonic: &str, len: usize) -> Option<String> {
+        let offset = self.immediate(len)?;
+        let target = self
+            .address
+            .wrapping_add(self.pos as u64)
+            .wrapping_add(offset as u64);
+        self.branch = Some(target);
+        let bnd = if self.repne { "bnd " } else { "" };
+        Some(format!("{bnd}{mnemonic} 0x{target:X}"))
+    }
+
+    // `mnemonic rm, reg`, or `mnemonic reg, rm` when reversed
+    fn binary(&mut self, mnemonic: &str, size: Size, reversed: bool) -> Option<String> {
+        let modrm = self.modrm()?;
+        let reg = self.register(modrm.reg, size);
+        let rm = self.rm(&modrm.rm, size, size);
+        Some(if reversed {
+            format!("{mnemonic} {reg}, {rm}")
+        } else {
+            format!("{mnemonic} {rm}, {reg}")
+        })
+    }
+
+    // `mnemonic reg, rm` with differently sized operands, like movzx
+    fn widening(&mut self, mnemonic: &str, size: Size, rm_size: Size) -> Option<String> {
+        let modrm = self.modrm()?;
+        Some(format!(
+            "{mnemonic} {}, {}",
+            self.register(modrm.reg, size),
+            self.rm(&modrm.rm, rm_size, rm_size)
+        ))
+    }
+
+    // `mnemonic xmm, xmm/m`, or `mnemonic xmm/m, xmm` when stored
+    fn sse(&mut self, mnemonic: &str, memory_size: Size, store: bool) -> Option<String> {
+        let modrm = self.modrm()?;
+        let reg = self.register(modrm.reg, Size::Xmm);
+        let rm = self.rm(&modrm.rm, Size::Xmm, memory_size);
+        Some(if store {
+            format!("{mnemonic} {rm}, {reg}")
+        } else {
+            format!("{mnemonic} {reg}, {rm}")
+        })
+    }
+
+    fn sse_immediate(&mut self, mnemonic: &str) -> Option<String> {
+        let text = self.sse(mnemonic, Size::Xmm, false)?;
+        let immediate = self.immediate(1)?;
+        Some(format!("{text}, {}", fmt_immediate(immediate, Size::Byte)))
+    }
+
+    // x87 instructions, where the ModRM reg field picks the operation on a memory operand
+    // and the whole byte does for operations on the register stack
+    fn x87(&mut self, opcode: u8) -> Option<String> {
+        const ARITHMETIC: [&str; 8] = [
+            "fadd", "fmul", "fcom", "fcomp", "fsub", "fsubr", "fdiv", "fdivr",
+        ];
+        const INTEGER: [&str; 8] = [
+            "fiadd", "fimul", "ficom", "ficomp", "fisub", "fisubr", "fidiv", "fidivr",
+        ];
+        const CMOV: [&str; 8] = [
+            "fcmovb", "fcmove", "fcmovbe", "fcmovu", "fcmovnb", "fcmovne", "fcmovnbe", "fcmovnu",
+        ];
+        let modrm = self.modrm()?;
+        let reg = modrm.reg & 7;
+        let (i, address) = match modrm.rm {
+            Operand::Register(n) => (n & 7, None),
+            Operand::Memory(address) => (0, Some(address)),
+        };
+        if let Some(address) = address {
+            let (mnemonic, size) = match (opcode, reg) {
+                (0xD8, _) => (ARITHMETIC[reg], "dword"),
+                (0xDC, _) => (ARITHMETIC[reg], "qword"),
+                (0xDA, _) => (INTEGER[reg], "dword"),
+                (0xDE, _) => (INTEGER[reg], "word"),
+                (0xD9, 0) => ("fld", "dword"),
+                (0xD9, 2) => ("fst", "dword"),
+                (0xD9, 3) => ("fstp", "dword"),
+                (0xD9, 4) => ("fldenv", ""),
+                (0xD9, 5) => ("fldcw", "word"),
+                (0xD9, 6) => ("fnstenv", ""),
+                (0xD9, 7) => ("fnstcw", "word"),
+                (0xDB, 0) => ("fild", "dword"),
+                (0xDB, 1) => ("fisttp", "dword"),
+                (0xDB, 2) => ("fist", "dword"),
+                (0xDB, 3) => ("fistp", "dword"),
+                (0xDB, 5) => ("fld", "tbyte"),
+                (0xDB, 7) => ("fstp", "tbyte"),
+                (0xDD, 0) => ("fld", "qword"),
+                (0xDD, 1) => ("fisttp", "qword"),
+                (0xDD, 2) => ("fst", "qword"),
+                (0xDD, 3) => ("fstp", "qword"),
+                (0xDD, 4) => ("frstor", ""),
+                (0xDD, 6) => ("fnsave", ""),
+                (0xDD, 7) => ("fnstsw", "word"),
+                (0xDF, 0) => ("fild", "word"),
+                (0xDF, 1) => ("fisttp", "word"),
+                (0xDF, 2) => ("fist", "word"),
+                (0xDF, 3) => ("fistp", "word"),
+                (0xDF, 4) => ("fbld", "tbyte"),
+                (0xDF, 5) => ("fild", "qword"),
+                (0xDF, 6) => ("fbstp", "tbyte"),
+                (0xDF, 7) => ("fistp", "qword"),
+                _ => return Some("(bad)".into()),
+            };
+            return Some(match size {
+                "" => format!("{mnemonic} {address}"),
+                size => format!("{mnemonic} {size} ptr {address}"),
+            });
+        }
+
+        let st = format!("st({i})");
+        Some(match (opcode, reg) {
+            (0xD8, 2 | 3) => format!("{} {st}", ARITHMETIC[reg]),
+            (0xD8, _) => format!("{} st, {st}", ARITHMETIC[reg]),
+            // the destination is st(i), and the reversed forms swap places compared to 0xD8
+            (0xDC, 0 | 1) => format!("{} {st}, st", ARITHMETIC[reg]),
+            (0xDC, 4..=7) => format!("{} {st}, st", ARITHMETIC[reg ^ 1]),
+            (0xDE, 0 | 1) => format!("{}p {st}, st", ARITHMETIC[reg]),
+            (0xDE, 4..=7) => format!("{}p {st}, st", ARITHMETIC[reg ^ 1]),
+            (0xDE, 3) if i == 1 => "fcompp".into(),
+            (0xD9, 0) => format!("fld {st}"),
+            (0xD9, 1) => format!("fxch {st}"),
+            (0xD9, 2) if i == 0 => "fnop".into(),
+            (0xD9, 4..=7) => [
+                "fchs", "fabs", "", "", "ftst", "fxam", "", "", "fld1", "fldl2t", "fldl2e",
+                "fldpi", "fldlg2", "fldln2", "fldz", "", "f2xm1", "fyl2x", "fptan", "fpatan",
+                "fxtract", "fprem1", "fdecstp", "fincstp", "fprem", "fyl2xp1", "fsqrt", "fsincos",
+                "frndint", "fscale", "fsin", "fcos",
+            ][(reg - 4) * 8 + i]
+                .into(),
+            (0xDA, 0..=3) => format!("{} st, {st}", CMOV[reg]),
+            (0xDA, 5) if i == 1 => "fucompp".into(),
+            (0xDB, 0..=3) => format!("{} st, {st}", CMOV[reg + 4]),
+            (0xDB, 4) if i == 2 => "fnclex".into(),
+            (0xDB, 4) if i == 3 => "fninit".into(),
+            (0xDB, 5) => format!("fucomi st, {st}"),
+            (0xDB, 6) => format!("fcomi st, {st}"),
+            (0xDD, 0) => format!("ffree {st}"),
+            (0xDD, 2) => format!("fst {st}"),
+            (0xDD, 3) => format!("fstp {st}"),
+            (0xDD, 4) => format!("fucom {st}"),
+            (0xDD, 5) => format!("fucomp {st}"),
+            (0xDF, 0) => format!("ffreep {st}"),
+            (0xDF, 4) if i == 0 => "fnstsw ax".into(),
+            (0xDF, 5) => format!("fucomip st, {st}"),
+            (0xDF, 6) => format!("fcomip st, {st}"),
+            _ => "".into(),
+        })
+        // undefined forms still take their ModRM byte, so decoding carries on after them
+        .map(|text: String| {
+            if text.is_empty() {
+                "(bad)".into()
+            } else {
+                text
+            }
+        })
+    }
+
+    fn instruction(&mut self) -> Option<String> {
+        let mut byte = self.byte()?;
+        loop {
+            match byte {
+                0x66 => self.operand_size = true,
+                0x67 => self.address_size = true,
+                0xF0 => self.lock = true,
+                0xF2 => (self.repne, self.rep) = (true, false),
+                0xF3 => (self.rep, self.repne) = (true, false),
+                0x26 => self.segment = Some("es"),
+                0x2E => self.segment = Some("cs"),
+                0x36 => self.segment = Some("ss"),
+                0x3E => self.segment = Some("ds"),
+                0x64 => self.segment = Some("fs"),
+                0x65 => self.segment = Some("gs"),
+                _ => break,
+            }
+            byte = self.byte()?;
+        }
+        if (0x40..=0x4F).contains(&byte) {
+            self.rex = byte;
+            byte = self.byte()?;
+        }
+        let text = match byte {
+            0x0F => self.two_byte()?,
+            0xC4 | 0xC5 => self.vex(byte)?,
+            0x62 => self.evex()?,
+            _ => self.one_byte(byte)?,
+        };
+        Some(if self.lock {
+            format!("lock {text}")
+        } else {
+            text
+        })
+    }
+
+    fn one_byte(&mut self, opcode: u8) -> Option<String> {
+        Some(match opcode {
+            0x00..=0x3F if opcode & 7 < 6 => {
+                let mnemonic = ARITHMETIC[usize::from(opcode >> 3)];
+                match opcode & 7 {
+                    0 => self.binary(mnemonic, Size::Byte, false)?,
+                    1 => self.binary(mnemonic, self.operand(), false)?,
+                    2 => self.binary(mnemonic, Size::Byte, true)?,
+                    3 => self.binary(mnemonic, self.operand(), true)?,
+                    4 => {
+                        let immediate = self.immediate(1)?;
+                        format!("{mnemonic} al, {}", fmt_immediate(immediate, Size::Byte))
+                    }
+                    _ => {
+                        let size = self.operand();
+                        let immediate = self.immediate(size.immediate_len())?;
+                        format!(
+                            "{mnemonic} {}, {}",
+                            self.register(0, size),
+                            fmt_immediate(immediate, size)
+                        )
+                    }
+                }
+            }
+            0x50..=0x5F => {
+                let register = usize::from(opcode & 7) | usize::from(self.rex & 1) << 3;
+                let mnemonic = if opcode < 0x58 { "push" } else { "pop" };
+                format!(
+                    "{mnemonic} {}",
+                    self.register(register, self.stack_operand())
+                )
+            }
+            0x63 => self.widening("movsxd", self.operand(), Size::Dword)?,
+            0x68 | 0x6A => {
+                let immediate = self.immediate(if opcode == 0x68 { 4 } else { 1 })?;
+                format!("push {}", fmt_immediate(immediate, self.stack_operand()))
+            }
+            0x69 | 0x6B => {
+                let text = self.binary("imul", self.operand(), true)?;
+                let len = if opcode == 0x69 {
+                    self.operand().immediate_len()
+                } else {
+                    1
+                };
+                let immediate = self.immediate(len)?;
+                format!("{text}, {}", fmt_immediate(immediate, self.operand()))
+            }
+            0x70..=0x7F => {
+                self.relative(&format!("j{}", CONDITIONS[usize::from(opcode & 0xF)]), 1)?
+            }
+            0x80 | 0x81 | 0x83 => {
+                let size = if opcode == 0x80 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                let modrm = self.modrm()?;
+                let len = if opcode == 0x81 {
+                    size.immediate_len()
+                } else {
+                    1
+                };
+                let immediate = self.immediate(len)?;
+                format!(
+                    "{} {}, {}",
+                    ARITHMETIC[modrm.reg & 7],
+                    self.rm(&modrm.rm, size, size),
+                    fmt_immediate(immediate, size)
+                )
+            }
+            0x84 => self.binary("test", Size::Byte, false)?,
+            0x85 => self.binary("test", self.operand(), false)?,
+            0x86 => self.binary("xchg", Size::Byte, false)?,
+            0x87 => self.binary("xchg", self.operand(), false)?,
+            0x88 => self.binary("mov", Size::Byte, false)?,
+            0x89 => self.binary("mov", self.operand(), false)?,
+            0x8A => self.binary("mov", Size::Byte, true)?,
+            0x8B => self.binary("mov", self.operand(), true)?,
+            0x8D => {
+                let modrm = self.modrm()?;
+                let Operand::Memory(address) = &modrm.rm else {
+                    return None;
+                };
+                format!(
+                    "lea {}, {address}",
+                    self.register(modrm.reg, self.operand())
+                )
+            }
+            0x8F => {
+                let modrm = self.modrm()?;
+                if modrm.reg & 7 != 0 {
+                    return None;
+                }
+                let size = self.stack_operand();
+                format!("pop {}", self.rm(&modrm.rm, size, size))
+            }
+            0x90 if self.rex & 1 == 0 && self.rep => "pause".into(),
+            0x90 if self.rex & 1 == 0 => "nop".into(),
+            0x90..=0x97 => {
+                let size = self.operand();
+                let register = usize::from(opcode & 7) | usize::from(self.rex & 1) << 3;
+                format!(
+                    "xchg {}, {}",
+                    self.register(register, size),
+                    self.register(0, size)
+                )
+            }
+            0x98 => match self.operand() {
+                Size::Word => "cbw",
+                Size::Dword => "cwde",
+                _ => "cdqe",
+            }
+            .into(),
+            0x99 => match self.operand() {
+                Size::Word => "cwd",
+                Size::Dword => "cdq",
+                _ => "cqo",
+            }
+            .into(),
+            0x9C => "pushfq".into(),
+            0x9D => "popfq".into(),
+            0x9E => "sahf".into(),
+            0x9F => "lahf".into(),
+            0xA4..=0xA7 | 0xAA..=0xAF => {
+                let mnemonic = match opcode & !1 {
+                    0xA4 => "movs",
+                    0xA6 => "cmps",
+                    0xAA => "stos",
+                    0xAC => "lods",
+                    _ => "scas",
+                };
+                let suffix = match (opcode & 1, self.operand()) {
+                    (0, _) => "b",
+                    (_, Size::Word) => "w",
+                    (_, Size::Dword) => "d",
+                    _ => "q",
+                };
+                let prefix = match (self.rep, self.repne, mnemonic) {
+                    (true, _, "cmps" | "scas") => "repe ",
+                    (true, _, _) => "rep ",
+                    (_, true, _) => "repne ",
+                    _ => "",
+                };
+                format!("{prefix}{mnemonic}{suffix}")
+            }
+            0xA8 => {
+                let immediate = self.immediate(1)?;
+                format!("test al, {}", fmt_immediate(immediate, Size::Byte))
+            }
+            0xA9 => {
+                let size = self.operand();
+                let immediate = self.immediate(size.immediate_len())?;
+                format!(
+                    "test {}, {}",
+                    self.register(0, size),
+                    fmt_immediate(immediate, size)
+                )
+            }
+            0xB0..=0xBF => {
+                let register = usize::from(opcode & 7) | usize::from(self.rex & 1) << 3;
+                let size = if opcode < 0xB8 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                match size {
+                    Size::Qword => {
+                        let immediate = self.immediate(8)?;
+                        format!(
+                            "movabs {}, {}",
+                            self.register(register, size),
+                            fmt_immediate(immediate, size)
+                        )
+                    }
+                    _ => {
+                        let immediate = self.immediate(size.immediate_len())?;
+                        format!(
+                            "mov {}, {}",
+                            self.register(register, size),
+                            fmt_immediate(immediate, size)
+                        )
+                    }
+                }
+            }
+            0xC0 | 0xC1 | 0xD0..=0xD3 => {
+                let size = if opcode & 1 == 0 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                let modrm = self.modrm()?;
+                let count = match opcode {
+                    0xC0 | 0xC1 => fmt_immediate(self.immediate(1)?, Size::Byte),
+                    0xD0 | 0xD1 => "1".into(),
+                    _ => "cl".into(),
+                };
+                format!(
+                    "{} {}, {count}",
+                    SHIFTS[modrm.reg & 7],
+                    self.rm(&modrm.rm, size, size)
+                )
+            }
+            0xC2 => {
+                let immediate = self.immediate(2)?;
+                format!("ret {}", fmt_immediate(immediate, Size::Word))
+            }
+            0xC3 if self.repne => "bnd ret".into(),
+            0xC3 => "ret".into(),
+            0xC6 | 0xC7 => {
+                let size = if opcode == 0xC6 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                let modrm = self.modrm()?;
+                if modrm.reg & 7 != 0 {
+                    return None;
+                }
+                let immediate = self.immediate(size.immediate_len())?;
+                format!(
+                    "mov {}, {}",
+                    self.rm(&modrm.rm, size, size),
+                    fmt_immediate(immediate, size)
+                )
+            }
+            0xC8 => {
+                let size = self.immediate(2)?;
+                let level = self.immediate(1)?;
+                format!(
+                    "enter {}, {}",
+                    fmt_immediate(size, Size::Word),
+                    fmt_immediate(level, Size::Byte)
+                )
+            }
+            0xC9 => "leave".into(),
+            0xCC => "int3".into(),
+            0xCD => format!("int {}", fmt_immediate(self.immediate(1)?, Size::Byte)),
+            0xD8..=0xDF => self.x87(opcode)?,
+            0xE0 => self.relative("loopne", 1)?,
+            0xE1 => self.relative("loope", 1)?,
+            0xE2 => self.relative("loop", 1)?,
+            0xE3 => self.relative("jrcxz", 1)?,
+            0xE8 => self.relative("call", 4)?,
+            0xE9 => self.relative("jmp", 4)?,
+            0xEB => self.relative("jmp", 1)?,
+            0xF4 => "hlt".into(),
+            0xF5 => "cmc".into(),
+            0xF6 | 0xF7 => {
+                let size = if opcode == 0xF6 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                let modrm = self.modrm()?;
+                let rm = self.rm(&modrm.rm, size, size);
+                match modrm.reg & 7 {
+                    0 | 1 => {
+                        let immediate = self.immediate(size.immediate_len())?;
+                        format!("test {rm}, {}", fmt_immediate(immediate, size))
+                    }
+                    reg => {
+                        let mnemonic = ["not", "neg", "mul", "imul", "div", "idiv"][reg - 2];
+                        format!("{mnemonic} {rm}")
+                    }
+                }
+            }
+            0xF8 => "clc".into(),
+            0xF9 => "stc".into(),
+            0xFA => "cli".into(),
+            0xFB => "sti".into(),
+            0xFC => "cld".into(),
+            0xFD => "std".into(),
+            0xFE => {
+                let modrm = self.modrm()?;
+                let rm = self.rm(&modrm.rm, Size::Byte, Size::Byte);
+                match modrm.reg & 7 {
+                    0 => format!("inc {rm}"),
+                    1 => format!("dec {rm}"),
+                    _ => return None,
+                }
+            }
+            0xFF => {
+                let modrm = self.modrm()?;
+                let size = self.operand();
+                match modrm.reg & 7 {
+                    0 => format!("inc {}", self.rm(&modrm.rm, size, size)),
+                    1 => format!("dec {}", self.rm(&modrm.rm, size, size)),
+                    reg @ (2 | 4) => {
+                        let mnemonic = if reg == 2 { "call" } else { "jmp" };
+                        let prefix = match (self.repne, self.segment) {
+                            (true, _) => "bnd ",
+                            (_, Some("ds")) => "notrack ",
+                            _ => "",
+                        };
+                        let rm = self.rm(&modrm.rm, Size::Qword, Size::Qword);
+                        format!("{prefix}{mnemonic} {rm}")
+                    }
+                    6 => {
+                        let size = self.stack_operand();
+                        format!("push {}", self.rm(&modrm.rm, size, size))
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    fn two_byte(&mut self) -> Option<String> {
+        let opcode = self.byte()?;
+        let mandatory = self.mandatory();
+        Some(match opcode {
+            0x01 => match self.byte()? {
+                0xCA => "clac".into(),
+                0xCB => "stac".into(),
+                0xD0 => "xgetbv".into(),
+                0xF9 => "rdtscp".into(),
+                _ => return None,
+            },
+            0x05 => "syscall".into(),
+            0x0B => "ud2".into(),
+            0x0D => {
+                let modrm = self.modrm()?;
+                let Operand::Memory(address) = &modrm.rm else {
+                    return None;
+                };
+                match modrm.reg & 7 {
+                    1 => format!("prefetchw {address}"),
+                    _ => format!("prefetch {address}"),
+                }
+            }
+            0x10 | 0x11 => {
+                let (mnemonic, size) = match mandatory {
+                    Mandatory::None => ("movups", Size::Xmm),
+                    Mandatory::OperandSize => ("movupd", Size::Xmm),
+                    Mandatory::Rep => ("movss", Size::Dword),
+                    Mandatory::Repne => ("movsd", Size::Qword),
+                };
+                self.sse(mnemonic, size, opcode == 0x11)?
+            }
+            0x12 | 0x13 | 0x16 | 0x17 => {
+                let high = opcode >= 0x16;
+                let modrm = self.modrm()?;
+                let reg = self.register(modrm.reg, Size::Xmm);
+                let rm = self.rm(&modrm.rm, Size::Xmm, Size::Qword);
+                let mnemonic = match (mandatory, opcode & 1, modrm.mode == 3, high) {
+                    (Mandatory::Repne, 0, _, false) => "movddup",
+                    (Mandatory::Rep, 0, _, false) => "movsldup",
+                    (Mandatory::Rep, 0, _, true) => "movshdup",
+                    (Mandatory::None, 0, true, false) => "movhlps",
+                    (Mandatory::None, 0, true, true) => "movlhps",
+                    (Mandatory::None, _, false, false) => "movlps",
+                    (Mandatory::None, _, false, true) => "movhps",
+                    (Mandatory::OperandSize, _, false, false) => "movlpd",
+                    (Mandatory::OperandSize, _, false, true) => "movhpd",
+                    _ => return None,
+                };
+                match opcode & 1 {
+                    0 => format!("{mnemonic} {reg}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {reg}"),
+                }
+            }
+            0x14 | 0x15 => {
+                let mnemonic = match (opcode, mandatory) {
+                    (0x14, Mandatory::None) => "unpcklps",
+                    (0x14, Mandatory::OperandSize) => "unpcklpd",
+                    (_, Mandatory::None) => "unpckhps",
+                    (_, Mandatory::OperandSize) => "unpckhpd",
+                    _ => return None,
+                };
+                self.sse(mnemonic, Size::Xmm, false)?
+            }
+            0x18 => {
+                let modrm = self.modrm()?;
+                let Operand::Memory(address) = &modrm.rm else {
+                    return None;
+                };
+                let mnemonic = match modrm.reg & 7 {
+                    0 => "prefetchnta",
+                    1 => "prefetcht0",
+                    2 => "prefetcht1",
+                    3 => "prefetcht2",
+                    _ => "nop",
+                };
+                format!("{mnemonic} byte ptr {address}")
+            }
+            0x1E if self.rep && self.code.get(self.pos) == Some(&0xFA) => {
+                self.pos += 1;
+                "endbr64".into()
+            }
+            0x1E if self.rep && self.code.get(self.pos) == Some(&0xFB) => {
+                self.pos += 1;
+                "endbr32".into()
+            }
+            0x19..=0x1F => {
+                let modrm = self.modrm()?;
+                let size = self.operand();
+                format!("nop {}", self.rm(&modrm.rm, size, size))
+            }
+            0x28 | 0x29 => {
+                let mnemonic = match mandatory {
+                    Mandatory::None => "movaps",
+                    Mandatory::OperandSize => "movapd",
+                    _ => return None,
+                };
+                self.sse(mnemonic, Size::Xmm, opcode == 0x29)?
+            }
+            0x2A => {
+                let mnemonic = match mandatory {
+                    Mandatory::Rep => "cvtsi2ss",
+                    Mandatory::Repne => "cvtsi2sd",
+                    _ => return None,
+                };
+                let modrm = self.modrm()?;
+                let size = self.sse_operand();
+                format!(
+                    "{mnemonic} {}, {}",
+                    self.register(modrm.reg, Size::Xmm),
+                    self.rm(&modrm.rm, size, size)
+                )
+            }
+            0x2C | 0x2D => {
+                let truncate = if opcode == 0x2C { "t" } else { "" };
+                let (suffix, size) = match mandatory {
+                    Mandatory::Rep => ("ss", Size::Dword),
+                    Mandatory::Repne => ("sd", Size::Qword),
+                    _ => return None,
+                };
+                let modrm = self.modrm()?;
+                format!(
+                    "cvt{truncate}{suffix}2si {}, {}",
+                    self.register(modrm.reg, self.sse_operand()),
+                    self.rm(&modrm.rm, Size::Xmm, size)
+                )
+            }
+            0x2E | 0x2F => {
+                let mnemonic = if opcode == 0x2E { "ucomis" } else { "comis" };
+                let (suffix, size) = match mandatory {
+                    Mandatory::None => ("s", Size::Dword),
+                    Mandatory::OperandSize => ("d", Size::Qword),
+                    _ => return None,
+                };
+                self.sse(&format!("{mnemonic}{suffix}"), size, false)?
+            }
+            0x31 => "rdtsc".into(),
+            0x40..=0x4F => {
+                let mnemonic = format!("cmov{}", CONDITIONS[usize::from(opcode & 0xF)]);
+                self.binary(&mnemonic, self.operand(), true)?
+            }
+            0x50 => {
+                let mnemonic = match mandatory {
+                    Mandatory::None => "movmskps",
+                    Mandatory::OperandSize => "movmskpd",
+                    _ => return None,
+                };
+                let modrm = self.modrm()?;
+                let Operand::Register(rm) = modrm.rm else {
+                    return None;
+                };
+                format!(
+                    "{mnemonic} {}, xmm{rm}",
+                    self.register(modrm.reg, Size::Dword)
+                )
+            }
+            0x54..=0x57 => {
+                let mnemonic = ["and", "andn", "or", "xor"][usize::from(opcode - 0x54)];
+                let suffix = match mandatory {
+                    Mandatory::None => "ps",
+                    Mandatory::OperandSize => "pd",
+                    _ => return None,
+                };
+                self.sse(&format!("{mnemonic}{suffix}"), Size::Xmm, false)?
+            }
+            0x5A => {
+                let (mnemonic, size) = match mandatory {
+                    Mandatory::None => ("cvtps2pd", Size::Qword),
+                    Mandatory::OperandSize => ("cvtpd2ps", Size::Xmm),
+                    Mandatory::Rep => ("cvtss2sd", Size::Dword),
+                    Mandatory::Repne => ("cvtsd2ss", Size::Qword),
+                };
+                self.sse(mnemonic, size, false)?
+            }
+            0x5B => {
+                let mnemonic = match mandatory {
+                    Mandatory::None => "cvtdq2ps",
+                    Mandatory::OperandSize => "cvtps2dq",
+                    Mandatory::Rep => "cvttps2dq",
+                    Mandatory::Repne => return None,
+                };
+                self.sse(mnemonic, Size::Xmm, false)?
+            }
+            0x51..=0x5F => {
+                let mnemonic = floating_point(opcode)?;
+                let (suffix, size) = match mandatory {
+                    Mandatory::None => ("ps", Size::Xmm),
+                    Mandatory::OperandSize => ("pd", Size::Xmm),
+                    Mandatory::Rep => ("ss", Size::Dword),
+                    Mandatory::Repne => ("sd", Size::Qword),
+                };
+                self.sse(&format!("{mnemonic}{suffix}"), size, false)?
+            }
+            0x6E | 0x7E if mandatory == Mandatory::OperandSize => {
+                let mnemonic = if self.rex_w() { "movq" } else { "movd" };
+                let modrm = self.modrm()?;
+                let xmm = self.register(modrm.reg, Size::Xmm);
+                let size = self.sse_operand();
+                let rm = self.rm(&modrm.rm, size, size);
+                match opcode {
+                    0x6E => format!("{mnemonic} {xmm}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {xmm}"),
+                }
+            }
+            0x7E if mandatory == Mandatory::Rep => self.sse("movq", Size::Qword, false)?,
+            0x6F | 0x7F => {
+                let mnemonic = match mandatory {
+                    Mandatory::OperandSize => "movdqa",
+                    Mandatory::Rep => "movdqu",
+                    _ => return None,
+                };
+                self.sse(mnemonic, Size::Xmm, opcode == 0x7F)?
+            }
+            0x70 => {
+                let mnemonic = match mandatory {
+                    Mandatory::OperandSize => "pshufd",
+                    Mandatory::Rep => "pshufhw",
+                    Mandatory::Repne => "pshuflw",
+                    Mandatory::None => return None,
+                };
+                self.sse_immediate(mnemonic)?
+            }
+            0x71..=0x73 if mandatory == Mandatory::OperandSize => {
+                let modrm = self.modrm()?;
+                let Operand::Register(rm) = modrm.rm else {
+                    return None;
+                };
+                let mnemonic = match (opcode, modrm.reg & 7) {
+                    (0x71, 2) => "psrlw",
+                    (0x71, 4) => "psraw",
+                    (0x71, 6) => "psllw",
+                    (0x72, 2) => "psrld",
+                    (0x72, 4) => "psrad",
+                    (0x72, 6) => "pslld",
+                    (0x73, 2) => "psrlq",
+                    (0x73, 3) => "psrldq",
+                    (0x73, 6) => "psllq",
+                    (0x73, 7) => "pslldq",
+                    _ => return None,
+                };
+                let immediate = self.immediate(1)?;
+                format!(
+                    "{mnemonic} xmm{rm}, {}",
+                    fmt_immediate(immediate, Size::Byte)
+                )
+            }
+            0x77 => "emms".into(),
+            0x80..=0x8F => {
+                self.relative(&format!("j{}", CONDITIONS[usize::from(opcode & 0xF)]), 4)?
+            }
+            0x90..=0x9F => {
+                let modrm = self.modrm()?;
+                format!(
+                    "set{} {}",
+                    CONDITIONS[usize::from(opcode & 0xF)],
+                    self.rm(&modrm.rm, Size::Byte, Size::Byte)
+                )
+            }
+            0xA0 => "push fs".into(),
+            0xA1 => "pop fs".into(),
+            0xA2 => "cpuid".into(),
+            0xA8 => "push gs".into(),
+            0xA9 => "pop gs".into(),
+            0xA3 => self.binary("bt", self.operand(), false)?,
+            0xAB => self.binary("bts", self.operand(), false)?,
+            0xB3 => self.binary("btr", self.operand(), false)?,
+            0xBB => self.binary("btc", self.operand(), false)?,
+            0xA4 | 0xA5 | 0xAC | 0xAD => {
+                let mnemonic = if opcode < 0xAC { "shld" } else { "shrd" };
+                let text = self.binary(mnemonic, self.operand(), false)?;
+                match opcode & 1 {
+                    0 => format!("{text}, {}", fmt_immediate(self.immediate(1)?, Size::Byte)),
+                    _ => format!("{text}, cl"),
+                }
+            }
+            0xAE => {
+                let modrm = self.modrm()?;
+                match (&modrm.rm, modrm.reg & 7) {
+                    (Operand::Register(_), 5) => "lfence".into(),
+                    (Operand::Register(_), 6) => "mfence".into(),
+                    (Operand::Register(_), 7) => "sfence".into(),
+                    (Operand::Register(_), _) => return None,
+                    (Operand::Memory(address), reg) => {
+                        let mnemonic = match reg {
+                            0 => "fxsave",
+                            1 => "fxrstor",
+                            2 => "ldmxcsr",
+                            3 => "stmxcsr",
+                            4 => "xsave",
+                            5 => "xrstor",
+                            6 => "xsaveopt",
+                            _ if self.operand_size => "clflushopt",
+                            _ => "clflush",
+                        };
+                        format!("{mnemonic} {address}")
+                    }
+                }
+            }
+            0xAF => self.binary("imul", self.operand(), true)?,
+            0xB0 => self.binary("cmpxchg", Size::Byte, false)?,
+            0xB1 => self.binary("cmpxchg", self.operand(), false)?,
+            0xB6 => self.widening("movzx", self.operand(), Size::Byte)?,
+            0xB7 => self.widening("movzx", self.operand(), Size::Word)?,
+            0xBE => self.widening("movsx", self.operand(), Size::Byte)?,
+            0xBF => self.widening("movsx", self.operand(), Size::Word)?,
+            0xB8 if self.rep => self.binary("popcnt", self.operand(), true)?,
+            0xBA => {
+                let size = self.operand();
+                let modrm = self.modrm()?;
+                let mnemonic = match modrm.reg & 7 {
+                    4 => "bt",
+                    5 => "bts",
+                    6 => "btr",
+                    7 => "btc",
+                    _ => return None,
+                };
+                let immediate = self.immediate(1)?;
+                format!(
+                    "{mnemonic} {}, {}",
+                    self.rm(&modrm.rm, size, size),
+                    fmt_immediate(immediate, Size::Byte)
+                )
+            }
+            0xBC if self.rep => self.binary("tzcnt", self.operand(), true)?,
+            0xBC => self.binary("bsf", self.operand(), true)?,
+            0xBD if self.rep => self.binary("lzcnt", self.operand(), true)?,
+            0xBD => self.binary("bsr", self.operand(), true)?,
+            0xC0 => self.binary("xadd", Size::Byte, false)?,
+            0xC1 => self.binary("xadd", self.operand(), false)?,
+            0xC2 | 0xC6 => {
+                let mnemonic = if opcode == 0xC2 { "cmp" } else { "shuf" };
+                let (suffix, size) = match (mandatory, opcode) {
+                    (Mandatory::None, _) => ("ps", Size::Xmm),
+                    (Mandatory::OperandSize, _) => ("pd", Size::Xmm),
+                    (Mandatory::Rep, 0xC2) => ("ss", Size::Dword),
+                    (Mandatory::Repne, 0xC2) => ("sd", Size::Qword),
+                    _ => return None,
+                };
+                let text = self.sse(&format!("{mnemonic}{suffix}"), size, false)?;
+                format!("{text}, {}", fmt_immediate(self.immediate(1)?, Size::Byte))
+            }
+            0xC7 => {
+                let modrm = self.modrm()?;
+                match (&modrm.rm, modrm.reg & 7) {
+                    (Operand::Memory(address), 1) if self.rex_w() => {
+                        format!("cmpxchg16b xmmword ptr {address}")
+                    }
+                    (Operand::Memory(address), 1) => format!("cmpxchg8b qword ptr {address}"),
+                    (Operand::Register(rm), 6) => {
+                        format!("rdrand {}", self.register(*rm, self.operand()))
+                    }
+                    (Operand::Register(rm), 7) => {
+                        format!("rdseed {}", self.register(*rm, self.operand()))
+                    }
+                    _ => return None,
+                }
+            }
+            0xC8..=0xCF => {
+                let register = usize::from(opcode & 7) | usize::from(self.rex & 1) << 3;
+                format!("bswap {}", self.register(register, self.sse_operand()))
+            }
+            0xD6 if mandatory == Mandatory::OperandSize => self.sse("movq", Size::Qword, true)?,
+            0xD7 if mandatory == Mandatory::OperandSize => {
+                let modrm = self.modrm()?;
+                let Operand::Register(rm) = modrm.rm else {
+                    return None;
+                };
+                format!(
+                    "pmovmskb {}, xmm{rm}",
+                    self.register(modrm.reg, Size::Dword)
+                )
+            }
+            0xE7 if mandatory == Mandatory::OperandSize => self.sse("movntdq", Size::Xmm, true)?,
+            0x38 => self.three_byte_38()?,
+            0x3A => self.three_byte_3a()?,
+            _ if mandatory == Mandatory::OperandSize => {
+                self.sse(packed_integer(opcode)?, Size::Xmm, false)?
+            }
+            _ => return None,
+        })
+    }
+
+    fn three_byte_38(&mut self) -> Option<String> {
+        let opcode = self.byte()?;
+        Some(match (self.mandatory(), opcode) {
+            (Mandatory::OperandSize, 0x17) => self.sse("ptest", Size::Xmm, false)?,
+            (Mandatory::OperandSize, _) if packed_integer_38(opcode).is_some() => {
+                self.sse(packed_integer_38(opcode)?, Size::Xmm, false)?
+            }
+            (Mandatory::Repne, 0xF0 | 0xF1) => {
+                let size = if opcode == 0xF0 {
+                    Size::Byte
+                } else {
+                    self.operand()
+                };
+                let modrm = self.modrm()?;
+                format!(
+                    "crc32 {}, {}",
+                    self.register(modrm.reg, self.sse_operand()),
+                    self.rm(&modrm.rm, size, size)
+                )
+            }
+            (_, 0xF0) => self.binary("movbe", self.operand(), true)?,
+            (_, 0xF1) => self.binary("movbe", self.operand(), false)?,
+            // every instruction of this map has a ModRM byte and no immediate
+            _ => {
+                self.modrm()?;
+                "(unknown)".into()
+            }
+        })
+    }
+
+    fn three_byte_3a(&mut self) -> Option<String> {
+        let opcode = self.byte()?;
+        if self.mandatory() != Mandatory::OperandSize {
+            // every instruction of this map has a ModRM byte and an 8 bit immediate
+            self.modrm()?;
+            self.immediate(1)?;
+            return Some("(unknown)".into());
+        }
+        let mnemonic = match opcode {
+            0x08 => "roundps",
+            0x09 => "roundpd",
+            0x0A => "roundss",
+            0x0B => "roundsd",
+            0x0C => "blendps",
+            0x0D => "blendpd",
+            0x0E => "pblendw",
+            0x0F => "palignr",
+            0x14 => "pextrb",
+            0x16 if self.rex_w() => "pextrq",
+            0x16 => "pextrd",
+            0x17 => "extractps",
+            0x20 => "pinsrb",
+            0x21 => "insertps",
+            0x22 if self.rex_w() => "pinsrq",
+            0x22 => "pinsrd",
+            0x40 => "dpps",
+            0x41 => "dppd",
+            0x42 => "mpsadbw",
+            0x44 => "pclmulqdq",
+            0x60 => "pcmpestrm",
+            0x61 => "pcmpestri",
+            0x62 => "pcmpistrm",
+            0x63 => "pcmpistri",
+            _ => {
+                self.modrm()?;
+                self.immediate(1)?;
+                return Some("(unknown)".into());
+            }
+        };
+        match opcode {
+            // moves between a general purpose register or memory and one lane
+            0x14 | 0x16 | 0x17 | 0x20 | 0x22 => {
+                let modrm = self.modrm()?;
+                let xmm = self.register(modrm.reg, Size::Xmm);
+                let size = match opcode {
+                    0x14 | 0x20 => Size::Byte,
+                    _ => self.sse_operand(),
+                };
+                let rm = self.rm(&modrm.rm, self.sse_operand(), size);
+                let immediate = fmt_immediate(self.immediate(1)?, Size::Byte);
+                Some(match opcode {
+                    0x20 | 0x22 => format!("{mnemonic} {xmm}, {rm}, {immediate}"),
+                    _ => format!("{mnemonic} {rm}, {xmm}, {immediate}"),
+                })
+            }
+            _ => self.sse_immediate(mnemonic),
+        }
+    }
+
+    // AVX instructions with a two or three byte VEX prefix
+    fn vex(&mut self, prefix: u8) -> Option<String> {
+        let (rex, map, second) = match prefix {
+            0xC5 => {
+                let byte = self.byte()?;
+                (!byte >> 5 & 4, 1, byte)
+            }
+            _ => {
+                let first = self.byte()?;
+                let second = self.byte()?;
+                (!first >> 5 & 7 | second >> 4 & 8, first & 0x1F, second)
+            }
+        };
+        self.rex = 0x40 | rex;
+        let source = usize::from(!second >> 3 & 0xF);
+        let size = if second & 4 > 0 { Size::Ymm } else { Size::Xmm };
+        match second & 3 {
+            1 => self.operand_size = true,
+            2 => self.rep = true,
+            3 => self.repne = true,
+            _ => {}
+        }
+        let mandatory = self.mandatory();
+        let opcode = self.byte()?;
+        if map == 1 && opcode == 0x77 {
+            return Some(match size {
+                Size::Xmm => "vzeroupper".into(),
+                _ => "vzeroall".into(),
+            });
+        }
+
+        let modrm = self.modrm()?;
+        let reg = self.register(modrm.reg, size);
+        let source_reg = self.register(source, size);
+        let rm = self.rm(&modrm.rm, size, size);
+        let general = self.sse_operand();
+        let text = match (map, mandatory, opcode) {
+            (1, _, 0x10 | 0x11) => {
+                let mnemonic = match mandatory {
+                    Mandatory::None => "vmovups",
+                    Mandatory::OperandSize => "vmovupd",
+                    Mandatory::Rep => "vmovss",
+                    Mandatory::Repne => "vmovsd",
+                };
+                let rm = match mandatory {
+                    Mandatory::Rep => self.rm(&modrm.rm, Size::Xmm, Size::Dword),
+                    Mandatory::Repne => self.rm(&modrm.rm, Size::Xmm, Size::Qword),
+                    _ => rm,
+                };
+                match opcode {
+                    0x10 => format!("{mnemonic} {reg}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {reg}"),
+                }
+            }
+            (1, Mandatory::None | Mandatory::OperandSize, 0x28 | 0x29) => {
+                let mnemonic = match mandatory {
+                    Mandatory::None => "vmovaps",
+                    _ => "vmovapd",
+                };
+                match opcode {
+                    0x28 => format!("{mnemonic} {reg}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {reg}"),
+                }
+            }
+            (1, Mandatory::None | Mandatory::OperandSize, 0x54..=0x57) => {
+                let mnemonic = ["vand", "vandn", "vor", "vxor"][usize::from(opcode - 0x54)];
+                let suffix = match mandatory {
+                    Mandatory::None => "ps",
+                    _ => "pd",
+                };
+                format!("{mnemonic}{suffix} {reg}, {source_reg}, {rm}")
+            }
+            (1, _, 0x51..=0x5F) if floating_point(opcode).is_some() => {
+                let mnemonic = floating_point(opcode)?;
+                let (suffix, reg, source_reg, rm) = match mandatory {
+                    Mandatory::None => ("ps", reg, source_reg, rm),
+                    Mandatory::OperandSize => ("pd", reg, source_reg, rm),
+                    Mandatory::Rep => (
+                        "ss",
+                        self.register(modrm.reg, Size::Xmm),
+                        self.register(source, Size::Xmm),
+                        self.rm(&modrm.rm, Size::Xmm, Size::Dword),
+                    ),
+                    Mandatory::Repne => (
+                        "sd",
+                        self.register(modrm.reg, Size::Xmm),
+                        self.register(source, Size::Xmm),
+                        self.rm(&modrm.rm, Size::Xmm, Size::Qword),
+                    ),
+                };
+                format!("v{mnemonic}{suffix} {reg}, {source_reg}, {rm}")
+            }
+            (1, Mandatory::OperandSize | Mandatory::Rep, 0x6F | 0x7F) => {
+                let mnemonic = match mandatory {
+                    Mandatory::OperandSize => "vmovdqa",
+                    _ => "vmovdqu",
+                };
+                match opcode {
+                    0x6F => format!("{mnemonic} {reg}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {reg}"),
+                }
+            }
+            (1, Mandatory::OperandSize, 0x6E | 0x7E) => {
+                let mnemonic = if self.rex_w() { "vmovq" } else { "vmovd" };
+                let xmm = self.register(modrm.reg, Size::Xmm);
+                let rm = self.rm(&modrm.rm, general, general);
+                match opcode {
+                    0x6E => format!("{mnemonic} {xmm}, {rm}"),
+                    _ => format!("{mnemonic} {rm}, {xmm}"),
+                }
+            }
+            (1, Mandatory::OperandSize, 0x70) => {
+                let immediate = fmt_immediate(self.immediate(1)?, Size::Byte);
+                format!("vpshufd {reg}, {rm}, {immediate}")
+            }
+            (1, Mandatory::OperandSize, 0xD7) if matches!(modrm.rm, Operand::Register(_)) => {
+                format!("vpmovmskb {}, {rm}", self.register(modrm.reg, Size::Dword))
+            }
+            (1, Mandatory::OperandSize, 0xE7) => format!("vmovntdq {rm}, {reg}"),
+            (1, Mandatory::OperandSize, _) if packed_integer(opcode).is_some() => {
+                let mnemonic = packed_integer(opcode)?;
+                format!("v{mnemonic} {reg}, {source_reg}, {rm}")
+            }
+            (2, Mandatory::OperandSize, 0x17) => format!("vptest {reg}, {rm}"),
+            (2, Mandatory::OperandSize, 0x58 | 0x59 | 0x78 | 0x79) => {
+                let (mnemonic, element) = match opcode {
+                    0x58 => ("vpbroadcastd", Size::Dword),
+                    0x59 => ("vpbroadcastq", Size::Qword),
+                    0x78 => ("vpbroadcastb", Size::Byte),
+                    _ => ("vpbroadcastw", Size::Word),
+                };
+                format!(
+                    "{mnemonic} {reg}, {}",
+                    self.rm(&modrm.rm, Size::Xmm, element)
+                )
+            }
+            (2, Mandatory::OperandSize, _) if packed_integer_38(opcode).is_some() => {
+                let mnemonic = packed_integer_38(opcode)?;
+                format!("v{mnemonic} {reg}, {source_reg}, {rm}")
+            }
+            // BMI instructions on general purpose registers
+            (2, _, 0xF2 | 0xF3 | 0xF5 | 0xF6 | 0xF7) => {
+                let reg = self.register(modrm.reg, general);
+                let source_reg = self.register(source, general);
+                let rm = self.rm(&modrm.rm, general, general);
+                match (mandatory, opcode, modrm.reg & 7) {
+                    (Mandatory::None, 0xF2, _) => format!("andn {reg}, {source_reg}, {rm}"),
+                    (Mandatory::None, 0xF3, 1) => format!("blsr {source_reg}, {rm}"),
+                    (Mandatory::None, 0xF3, 2) => format!("blsmsk {source_reg}, {rm}"),
+                    (Mandatory::None, 0xF3, 3) => format!("blsi {source_reg}, {rm}"),
+                    (Mandatory::None, 0xF5, _) => format!("bzhi {reg}, {rm}, {source_reg}"),
+                    (Mandatory::Rep, 0xF5, _) => format!("pext {reg}, {source_reg}, {rm}"),
+                    (Mandatory::Repne, 0xF5, _) => format!("pdep {reg}, {source_reg}, {rm}"),
+                    (Mandatory::Repne, 0xF6, _) => format!("mulx {reg}, {source_reg}, {rm}"),
+                    (Mandatory::None, 0xF7, _) => format!("bextr {reg}, {rm}, {source_reg}"),
+                    (Mandatory::OperandSize, 0xF7, _) => format!("shlx {reg}, {rm}, {source_reg}"),
+                    (Mandatory::Rep, 0xF7, _) => format!("sarx {reg}, {rm}, {source_reg}"),
+                    (Mandatory::Repne, 0xF7, _) => format!("shrx {reg}, {rm}, {source_reg}"),
+                    _ => "(unknown)".into(),
+                }
+            }
+            (3, Mandatory::OperandSize, 0x0F) => {
+                let immediate = fmt_immediate(self.immediate(1)?, Size::Byte);
+                format!("vpalignr {reg}, {source_reg}, {rm}, {immediate}")
+            }
+            (3, Mandatory::OperandSize, 0x60..=0x63) => {
+                let mnemonic = ["vpcmpestrm", "vpcmpestri", "vpcmpistrm", "vpcmpistri"]
+                    [usize::from(opcode - 0x60)];
+                let immediate = fmt_immediate(self.immediate(1)?, Size::Byte);
+                let reg = self.register(modrm.reg, Size::Xmm);
+                let rm = self.rm(&modrm.rm, Size::Xmm, Size::Xmm);
+                format!("{mnemonic} {reg}, {rm}, {immediate}")
+            }
+            _ => {
+                // immediates follow everything in map 3 and a few opcodes of map 1
+                if map == 3 || (map == 1 && matches!(opcode, 0x70..=0x73 | 0xC2 | 0xC4..=0xC6)) {
+                    self.immediate(1)?;
+                }
+                "(unknown)".into()
+            }
+        };
+        Some(text)
+    }
+
+    // AVX-512 instructions are only stepped over
+    fn evex(&mut self) -> Option<String> {
+        let first = self.byte()?;
+        self.byte()?;
+        self.byte()?;
+        let map = first & 7;
+        self.byte()?; // opcode
+        self.modrm()?;
+        if map == 3 {
+            self.immediate(1)?;
+        }
+        Some("(unknown)".into())
+    }
+}
+
+pub(super) fn decode(code: &[u8], address: u64) -> Instruction {
+    let mut decoder = Decoder {
+        code,
+        address,
+        ..Default::default()
+    };
+    match decoder.instruction() {
+        Some(text) => {
+            let len = decoder.pos;
+            let reference = match (decoder.branch, decoder.rip_displacement) {
+                (Some(target), _) => Some(Reference::Operand(target)),
+                (None, Some(displacement)) => Some(Reference::Relative(
+                    address
+                        .wrapping_add(len as u64)
+                        .wrapping_add(displacement as u64),
+                )),
+                (None, None) => None,
+            };
+            Instruction {
+                len,
+                text,
+                reference,
+            }
+        }
+        None => Instruction {
+            len: 1,
+            text: "(bad)".into(),
+            reference: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // encodings from GNU as, checked against objdump -M intel, which spells a few operands
+    // such as negative displacements and string instructions differently
+    fn check(cases: &[(&[u8], &str)]) {
+        for &(code, text) in cases {
+            let instruction = decode(code, 0);
+            assert_eq!(instruction.text, text, "{code:02X?}");
+            assert_eq!(instruction.len, code.len(), "{code:02X?}");
+        }
+    }
+
+    #[test]
+    fn instructions() {
+        check(&[
+            (&[0x55], "push rbp"),
+            (&[0x48, 0x89, 0xE5], "mov rbp, rsp"),
+            (&[0x48, 0x83, 0xEC, 0x20], "sub rsp, 0x20"),
+            (&[0x48, 0x89, 0x7D, 0xF8], "mov qword ptr [rbp-0x8], rdi"),
+            (
+                &[0x64, 0x48, 0x8B, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00],
+                "mov rax, qword ptr fs:[0x28]",
+            ),
+            (
+                &[0x48, 0xB8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11],
+                "movabs rax, 0x1122334455667788",
+            ),
+            (&[0xB9, 0x78, 0x56, 0x34, 0x12], "mov ecx, 0x12345678"),
+            (
+                &[0x0F, 0xB6, 0x44, 0xB7, 0x10],
+                "movzx eax, byte ptr [rdi+rsi*4+0x10]",
+            ),
+            (&[0x48, 0x63, 0x10], "movsxd rdx, dword ptr [rax]"),
+            (&[0x45, 0x01, 0xC8], "add r8d, r9d"),
+            (&[0x31, 0xC0], "xor eax, eax"),
+            (
+                &[0x48, 0x83, 0x7C, 0x24, 0x08, 0x7F],
+                "cmp qword ptr [rsp+0x8], 0x7F",
+            ),
+            (&[0x48, 0x6B, 0xC3, 0x30], "imul rax, rbx, 0x30"),
+            (&[0x48, 0xC1, 0xE2, 0x03], "shl rdx, 0x3"),
+            (&[0xD3, 0xF9], "sar ecx, cl"),
+            (&[0x48, 0x0F, 0x44, 0xC2], "cmove rax, rdx"),
+            (&[0x0F, 0x94, 0xC0], "sete al"),
+            (&[0xC3], "ret"),
+            (
+                &[0x2E, 0x66, 0x0F, 0x1F, 0x04, 0x00],
+                "nop word ptr cs:[rax+rax*1]",
+            ),
+            (&[0xF3, 0x0F, 0x1E, 0xFA], "endbr64"),
+            (&[0x0F, 0x05], "syscall"),
+            (
+                &[0xF0, 0x48, 0x0F, 0xB1, 0x37],
+                "lock cmpxchg qword ptr [rdi], rsi",
+            ),
+            (&[0xF3, 0x48, 0xAB], "rep stosq"),
+            (
+                &[0x0F, 0x28, 0x44, 0x24, 0x10],
+                "movaps xmm0, xmmword ptr [rsp+0x10]",
+            ),
+            (&[0x66, 0x0F, 0xEF, 0xC9], "pxor xmm1, xmm1"),
+            (&[0xF2, 0x48, 0x0F, 0x2A, 0xC0], "cvtsi2sd xmm0, rax"),
+            (&[0xC5, 0xFE, 0x6F, 0x07], "vmovdqu ymm0, ymmword ptr [rdi]"),
+            (&[0xC5, 0xF5, 0xFE, 0xC2], "vpaddd ymm0, ymm1, ymm2"),
+            (&[0xC9], "leave"),
+        ]);
+    }
+
+    #[test]
+    fn x87() {
+        check(&[
+            (&[0xDD, 0x45, 0xF0], "fld qword ptr [rbp-0x10]"),
+            (&[0xD8, 0xC1], "fadd st, st(1)"),
+            (&[0xDC, 0xE9], "fsub st(1), st"),
+            (&[0xDE, 0xC1], "faddp st(1), st"),
+            (&[0xD8, 0x28], "fsubr dword ptr [rax]"),
+            (&[0xDF, 0x3C, 0x24], "fistp qword ptr [rsp]"),
+            (&[0xD9, 0x7C, 0x24, 0x02], "fnstcw word ptr [rsp+0x2]"),
+            (&[0xD9, 0x2C, 0x24], "fldcw word ptr [rsp]"),
+            (&[0xD9, 0xCA], "fxch st(2)"),
+            (&[0xDF, 0xE9], "fucomip st, st(1)"),
+            (&[0xDA, 0xD3], "fcmovbe st, st(3)"),
+            (&[0xD9, 0xE8], "fld1"),
+            (&[0xD9, 0xE0], "fchs"),
+            (&[0xDF, 0xE0], "fnstsw ax"),
+            (&[0xDB, 0x3F], "fstp tbyte ptr [rdi]"),
+            (&[0xD9, 0x20], "fldenv [rax]"),
+            (&[0xD9, 0xD1], "(bad)"),
+            (&[0xD9, 0x08], "(bad)"),
+        ]);
+    }
+
+    #[test]
+    fn references() {
+        let cases = [
+            (&[0xE8, 0x00, 0x00, 0x00, 0x00][..], "call 0x1005", 0x1005),
+            (&[0xEB, 0xFE], "jmp 0x1000", 0x1000),
+            (&[0x75, 0x10], "jne 0x1012", 0x1012),
+            (
+                &[0x8B, 0x05, 0x00, 0x10, 0x00, 0x00],
+                "mov eax, dword ptr [rip+0x1000]",
+                0x2006,
+            ),
+            (
+                &[0x48, 0x8D, 0x05, 0xF9, 0xFF, 0xFF, 0xFF],
+                "lea rax, [rip-0x7]",
+                0x1000,
+            ),
+        ];
+        for (code, text, target) in cases {
+            let instruction = decode(code, 0x1000);
+            assert_eq!(instruction.text, text, "{code:02X?}");
+            let address = match instruction.reference {
+                Some(Reference::Operand(address) | Reference::Relative(address)) => address,
+                None => panic!("no reference for {code:02X?}"),
+            };
+            assert_eq!(address, target, "{code:02X?}");
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let instruction = decode(&[0x48, 0x8B], 0);
+        assert_eq!((instruction.len, instruction.text.as_str()), (1, "(bad)"));
+    }
+}
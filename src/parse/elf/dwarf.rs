@@ -0,0 +1,914 @@
+use super::{Encoding, Parser, WordSize, pull_word, read_sized, strtab_entry};
+use crate::{
+    dwarf_header::*,
+    error::{Error, Res},
+    parse::{Bytes, Endianness, Str, Table},
+    unknown,
+};
+use std::collections::{HashMap, hash_map::Entry};
+
+type Cursor<'a> = std::io::Cursor<&'a [u8]>;
+
+#[derive(Default)]
+struct DebugSections {
+    info: Vec<u8>,
+    abbrev: Vec<u8>,
+    str: Vec<u8>,
+    str_offsets: Vec<u8>,
+    line: Vec<u8>,
+    line_str: Vec<u8>,
+    addr: Vec<u8>,
+}
+
+#[derive(Copy, Clone)]
+struct UnitEncoding {
+    version: u16,
+    offset_size: WordSize,
+    address_size: u8,
+    endianness: Endianness,
+}
+
+impl UnitEncoding {
+    fn offset(&self) -> Encoding {
+        Encoding {
+            word_size: self.offset_size,
+            endianness: self.endianness,
+        }
+    }
+
+    fn offset_bytes(&self) -> u64 {
+        match self.offset_size {
+            WordSize::Four => 4,
+            WordSize::Eight => 8,
+        }
+    }
+}
+
+struct AttributeSpec {
+    name: u64,
+    form: u64,
+    implicit_const: i64,
+}
+
+struct Abbreviation {
+    tag: u64,
+    attributes: Vec<AttributeSpec>,
+}
+
+enum Value {
+    Address(u64),
+    AddressIndex(u64),
+    Constant(u64),
+    String(String),
+    StringIndex(u64),
+    Other,
+}
+
+// the .debug_str_offsets and .debug_addr contributions of a unit
+struct UnitContext<'a> {
+    sections: &'a DebugSections,
+    encoding: UnitEncoding,
+    str_offsets_base: u64,
+    addr_base: u64,
+}
+
+impl UnitContext<'_> {
+    fn string(&self, value: Value) -> Res<Option<String>> {
+        Ok(match value {
+            Value::String(string) => Some(string),
+            Value::StringIndex(index) => {
+                let mut str_offsets = Cursor::new(&self.sections.str_offsets);
+                let Some(offset) = index
+                    .checked_mul(self.encoding.offset_bytes())
+                    .and_then(|offset| offset.checked_add(self.str_offsets_base))
+                else {
+                    unknown!()
+                };
+                str_offsets.jump(offset)?;
+                let offset = pull_word(&mut str_offsets, self.encoding.offset())?;
+                Some(strtab_entry(&self.sections.str, offset)?)
+            }
+            _ => None,
+        })
+    }
+
+    fn address(&self, value: &Value) -> Res<Option<u64>> {
+        Ok(match *value {
+            Value::Address(address) => Some(address),
+            Value::AddressIndex(index) => {
+                let mut addr = Cursor::new(&self.sections.addr);
+                let Some(offset) = index
+                    .checked_mul(self.encoding.address_size.into())
+                    .and_then(|offset| offset.checked_add(self.addr_base))
+                else {
+                    unknown!()
+                };
+                addr.jump(offset)?;
+                Some(pull_sized(
+                    &mut addr,
+                    self.encoding.address_size,
+                    self.encoding.endianness,
+                )?)
+            }
+            _ => None,
+        })
+    }
+}
+
+#[derive(Default)]
+struct CompilationUnit {
+    offset: u64,
+    version: u16,
+    offset_size: Option<WordSize>,
+    r#type: u8,
+    address_size: u8,
+    dwo_id: Option<u64>,
+    producer: Option<String>,
+    language: Option<u64>,
+    name: Option<String>,
+    comp_dir: Option<String>,
+    dwo_name: Option<String>,
+    pc_range: Option<(u64, u64)>,
+    has_ranges: bool,
+    line_program: Option<LineProgram>,
+}
+
+struct LineProgram {
+    files: Vec<(u64, String)>,
+    minimum_instruction_length: u8,
+    line_base: i8,
+    line_range: u8,
+    opcode_base: u8,
+    standard_opcode_lengths: Vec<u8>,
+    opcodes: std::ops::Range<u64>,
+}
+
+#[derive(Copy, Clone)]
+struct LineRow {
+    address: u64,
+    file: u64,
+    line: u64,
+    column: u64,
+    end_sequence: bool,
+}
+
+// the rows of one line number program, with the files they refer to
+pub(super) struct LineTable {
+    files: Vec<(u64, String)>,
+    rows: Vec<LineRow>,
+}
+
+impl LineTable {
+    // file, line and column of the row covering an address
+    pub(super) fn find(&self, address: u64) -> Option<(&str, u64, u64)> {
+        let row = self
+            .rows
+            .windows(2)
+            .find(|pair| {
+                !pair[0].end_sequence && (pair[0].address..pair[1].address).contains(&address)
+            })
+            .map(|pair| pair[0])?;
+        let file = self
+            .files
+            .iter()
+            .find(|(index, _)| *index == row.file)
+            .map_or("??", |(_, path)| path.as_str());
+        Some((file, row.line, row.column))
+    }
+}
+
+pub fn pull_uleb128(bytes: &mut impl Bytes) -> Res<u64> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte: u8 = bytes.pull()?;
+        if shift < u64::BITS {
+            value |= u64::from(byte & 0x7f) << shift;
+        }
+        shift += 7;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+pub fn pull_sleb128(bytes: &mut impl Bytes) -> Res<i64> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte: u8 = bytes.pull()?;
+        if shift < u64::BITS {
+            value |= u64::from(byte & 0x7f) << shift;
+        }
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < u64::BITS && byte & 0x40 > 0 {
+                value |= u64::MAX << shift;
+            }
+            return Ok(value as i64);
+        }
+    }
+}
+
+fn pull_sized(bytes: &mut impl Bytes, size: u8, endianness: Endianness) -> Res<u64> {
+    if size > 8 {
+        unknown!();
+    }
+    let mut value = [0; 8];
+    bytes.read_exact(&mut value[..size.into()])?;
+    Ok(read_sized(&value[..size.into()], endianness))
+}
+
+// the 32-bit or 64-bit DWARF format is picked by the first word of a unit
+pub fn pull_initial_length(bytes: &mut impl Bytes, endianness: Endianness) -> Res<(u64, WordSize)> {
+    Ok(match bytes.pull_via::<u32>(endianness)? {
+        0xffff_ffff => (bytes.pull_via(endianness)?, WordSize::Eight),
+        0xffff_fff0.. => unknown!(),
+        length => (length.into(), WordSize::Four),
+    })
+}
+
+fn pull_value(
+    bytes: &mut impl Bytes,
+    form: u64,
+    implicit_const: i64,
+    encoding: UnitEncoding,
+    sections: &DebugSections,
+) -> Res<Value> {
+    let endianness = encoding.endianness;
+    let skip = |bytes: &mut _, count: u64| -> Res<Value> {
+        let Ok(count) = count.try_into() else {
+            unknown!()
+        };
+        Bytes::forward(bytes, count)?;
+        Ok(Value::Other)
+    };
+    Ok(match form {
+        DW_FORM_ADDR => Value::Address(pull_sized(bytes, encoding.address_size, endianness)?),
+        DW_FORM_ADDRX | DW_FORM_GNU_ADDR_INDEX => Value::AddressIndex(pull_uleb128(bytes)?),
+        DW_FORM_ADDRX1 => Value::AddressIndex(pull_sized(bytes, 1, endianness)?),
+        DW_FORM_ADDRX2 => Value::AddressIndex(pull_sized(bytes, 2, endianness)?),
+        DW_FORM_ADDRX3 => Value::AddressIndex(pull_sized(bytes, 3, endianness)?),
+        DW_FORM_ADDRX4 => Value::AddressIndex(pull_sized(bytes, 4, endianness)?),
+        DW_FORM_DATA1 => Value::Constant(bytes.pull_via::<u8>(endianness)?.into()),
+        DW_FORM_DATA2 => Value::Constant(bytes.pull_via::<u16>(endianness)?.into()),
+        DW_FORM_DATA4 => Value::Constant(bytes.pull_via::<u32>(endianness)?.into()),
+        DW_FORM_DATA8 => Value::Constant(bytes.pull_via(endianness)?),
+        DW_FORM_UDATA => Value::Constant(pull_uleb128(bytes)?),
+        DW_FORM_SDATA => Value::Constant(pull_sleb128(bytes)? as u64),
+        DW_FORM_IMPLICIT_CONST => Value::Constant(implicit_const as u64),
+        DW_FORM_SEC_OFFSET => Value::Constant(pull_word(bytes, encoding.offset())?),
+        DW_FORM_STRING => {
+            let string: std::ffi::CString = bytes.pull()?;
+            Value::String(string.to_string_lossy().into_owned())
+        }
+        DW_FORM_STRP => Value::String(strtab_entry(
+            &sections.str,
+            pull_word(bytes, encoding.offset())?,
+        )?),
+        DW_FORM_LINE_STRP => Value::String(strtab_entry(
+            &sections.line_str,
+            pull_word(bytes, encoding.offset())?,
+        )?),
+        DW_FORM_STRX | DW_FORM_GNU_STR_INDEX => Value::StringIndex(pull_uleb128(bytes)?),
+        DW_FORM_STRX1 => Value::StringIndex(pull_sized(bytes, 1, endianness)?),
+        DW_FORM_STRX2 => Value::StringIndex(pull_sized(bytes, 2, endianness)?),
+        DW_FORM_STRX3 => Value::StringIndex(pull_sized(bytes, 3, endianness)?),
+        DW_FORM_STRX4 => Value::StringIndex(pull_sized(bytes, 4, endianness)?),
+        DW_FORM_FLAG_PRESENT => Value::Other,
+        DW_FORM_FLAG | DW_FORM_REF1 => skip(bytes, 1)?,
+        DW_FORM_REF2 => skip(bytes, 2)?,
+        DW_FORM_REF4 | DW_FORM_REF_SUP4 => skip(bytes, 4)?,
+        DW_FORM_REF8 | DW_FORM_REF_SIG8 | DW_FORM_REF_SUP8 => skip(bytes, 8)?,
+        DW_FORM_DATA16 => skip(bytes, 16)?,
+        DW_FORM_REF_ADDR if encoding.version == 2 => skip(bytes, encoding.address_size.into())?,
+        DW_FORM_REF_ADDR | DW_FORM_STRP_SUP | DW_FORM_GNU_REF_ALT | DW_FORM_GNU_STRP_ALT => {
+            skip(bytes, encoding.offset_bytes())?
+        }
+        DW_FORM_REF_UDATA | DW_FORM_LOCLISTX | DW_FORM_RNGLISTX => {
+            pull_uleb128(bytes)?;
+            Value::Other
+        }
+        DW_FORM_BLOCK1 => {
+            let len = bytes.pull_via::<u8>(endianness)?;
+            skip(bytes, len.into())?
+        }
+        DW_FORM_BLOCK2 => {
+            let len = bytes.pull_via::<u16>(endianness)?;
+            skip(bytes, len.into())?
+        }
+        DW_FORM_BLOCK4 => {
+            let len = bytes.pull_via::<u32>(endianness)?;
+            skip(bytes, len.into())?
+        }
+        DW_FORM_BLOCK | DW_FORM_EXPRLOC => {
+            let len = pull_uleb128(bytes)?;
+            skip(bytes, len)?
+        }
+        DW_FORM_INDIRECT => {
+            let form = pull_uleb128(bytes)?;
+            pull_value(bytes, form, 0, encoding, sections)?
+        }
+        _ => unknown!(),
+    })
+}
+
+fn abbreviations(abbrev: &[u8], offset: u64) -> Res<HashMap<u64, Abbreviation>> {
+    let mut abbrev = Cursor::new(abbrev);
+    abbrev.jump(offset)?;
+    let mut abbreviations = HashMap::new();
+    loop {
+        let code = pull_uleb128(&mut abbrev)?;
+        if code == 0 {
+            return Ok(abbreviations);
+        }
+        let tag = pull_uleb128(&mut abbrev)?;
+        abbrev.forward_sizeof::<u8>()?; // children
+        let mut attributes = vec![];
+        loop {
+            let name = pull_uleb128(&mut abbrev)?;
+            let form = pull_uleb128(&mut abbrev)?;
+            if name == 0 && form == 0 {
+                break;
+            }
+            let implicit_const = if form == DW_FORM_IMPLICIT_CONST {
+                pull_sleb128(&mut abbrev)?
+            } else {
+                0
+            };
+            attributes.push(AttributeSpec {
+                name,
+                form,
+                implicit_const,
+            });
+        }
+        abbreviations.insert(code, Abbreviation { tag, attributes });
+    }
+}
+
+fn language_name(language: u64) -> Option<&'static str> {
+    Some(match language {
+        DW_LANG_C89 => "C89",
+        DW_LANG_C => "C",
+        DW_LANG_ADA83 => "Ada 83",
+        DW_LANG_C_PLUS_PLUS => "C++",
+        DW_LANG_COBOL74 => "COBOL 74",
+        DW_LANG_COBOL85 => "COBOL 85",
+        DW_LANG_FORTRAN77 => "Fortran 77",
+        DW_LANG_FORTRAN90 => "Fortran 90",
+        DW_LANG_PASCAL83 => "Pascal 83",
+        DW_LANG_MODULA2 => "Modula-2",
+        DW_LANG_JAVA => "Java",
+        DW_LANG_C99 => "C99",
+        DW_LANG_ADA95 => "Ada 95",
+        DW_LANG_FORTRAN95 => "Fortran 95",
+        DW_LANG_PLI => "PL/I",
+        DW_LANG_OBJC => "Objective-C",
+        DW_LANG_OBJC_PLUS_PLUS => "Objective-C++",
+        DW_LANG_UPC => "UPC",
+        DW_LANG_D => "D",
+        DW_LANG_PYTHON => "Python",
+        DW_LANG_OPENCL => "OpenCL",
+        DW_LANG_GO => "Go",
+        DW_LANG_MODULA3 => "Modula-3",
+        DW_LANG_HASKELL => "Haskell",
+        DW_LANG_C_PLUS_PLUS_03 => "C++03",
+        DW_LANG_C_PLUS_PLUS_11 => "C++11",
+        DW_LANG_OCAML => "OCaml",
+        DW_LANG_RUST => "Rust",
+        DW_LANG_C11 => "C11",
+        DW_LANG_SWIFT => "Swift",
+        DW_LANG_JULIA => "Julia",
+        DW_LANG_DYLAN => "Dylan",
+        DW_LANG_C_PLUS_PLUS_14 => "C++14",
+        DW_LANG_FORTRAN03 => "Fortran 2003",
+        DW_LANG_FORTRAN08 => "Fortran 2008",
+        DW_LANG_RENDERSCRIPT => "RenderScript",
+        DW_LANG_BLISS => "BLISS",
+        DW_LANG_KOTLIN => "Kotlin",
+        DW_LANG_ZIG => "Zig",
+        DW_LANG_CRYSTAL => "Crystal",
+        DW_LANG_C_PLUS_PLUS_17 => "C++17",
+        DW_LANG_C_PLUS_PLUS_20 => "C++20",
+        DW_LANG_C17 => "C17",
+        DW_LANG_FORTRAN18 => "Fortran 2018",
+        DW_LANG_ADA2005 => "Ada 2005",
+        DW_LANG_ADA2012 => "Ada 2012",
+        DW_LANG_MIPS_ASSEMBLER => "MIPS Assembler",
+        _ => return None,
+    })
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() || name.starts_with('/') {
+        name.to_owned()
+    } else {
+        format!("{}/{name}", dir.trim_end_matches('/'))
+    }
+}
+
+// reads the header of a line number program, up to where its opcodes start
+fn line_program(context: &UnitContext, offset: u64, comp_dir: &str) -> Res<LineProgram> {
+    let endianness = context.encoding.endianness;
+    let mut line = Cursor::new(&context.sections.line);
+    line.jump(offset)?;
+    let (length, offset_size) = pull_initial_length(&mut line, endianness)?;
+    let Some(end) = line.position().checked_add(length) else {
+        unknown!()
+    };
+    let version = line.pull_via::<u16>(endianness)?;
+    let encoding = UnitEncoding {
+        version,
+        offset_size,
+        ..context.encoding
+    };
+    if version >= 5 {
+        line.forward(2 * size_of::<u8>())?; // address and segment selector size
+    }
+    let header_length = pull_word(&mut line, encoding.offset())?;
+    let Some(start) = line.position().checked_add(header_length) else {
+        unknown!()
+    };
+    let minimum_instruction_length = line.pull()?;
+    if version >= 4 {
+        line.forward_sizeof::<u8>()?; // maximum operations per instruction
+    }
+    line.forward_sizeof::<u8>()?; // default is_stmt
+    let line_base = line.pull::<u8>()? as i8;
+    let line_range = line.pull()?;
+    let opcode_base = line.pull::<u8>()?;
+    let mut standard_opcode_lengths = vec![0; opcode_base.saturating_sub(1).into()];
+    std::io::Read::read_exact(&mut line, &mut standard_opcode_lengths)?;
+    let mut program = LineProgram {
+        files: vec![],
+        minimum_instruction_length,
+        line_base,
+        line_range,
+        opcode_base,
+        standard_opcode_lengths,
+        opcodes: start..end,
+    };
+
+    if version < 5 {
+        let mut dirs = vec![comp_dir.to_owned()];
+        loop {
+            let dir: std::ffi::CString = line.pull()?;
+            if dir.is_empty() {
+                break;
+            }
+            dirs.push(join_path(comp_dir, &dir.to_string_lossy()));
+        }
+        loop {
+            let name: std::ffi::CString = line.pull()?;
+            if name.is_empty() {
+                return Ok(program);
+            }
+            let dir_index = pull_uleb128(&mut line)?;
+            pull_uleb128(&mut line)?; // modification time
+            pull_uleb128(&mut line)?; // file length
+            let dir = usize::try_from(dir_index)
+                .ok()
+                .and_then(|dir_index| dirs.get(dir_index))
+                .map_or("", String::as_str);
+            program.files.push((
+                program.files.len() as u64 + 1,
+                join_path(dir, &name.to_string_lossy()),
+            ));
+        }
+    }
+
+    let entries = |line: &mut Cursor| -> Res<Vec<(String, u64)>> {
+        let format_count = line.pull_via::<u8>(endianness)?;
+        let format = (0..format_count)
+            .map(|_| Ok((pull_uleb128(line)?, pull_uleb128(line)?)))
+            .collect::<Res<Vec<_>>>()?;
+        let count = pull_uleb128(line)?;
+        (0..count)
+            .map(|_| {
+                let mut path = String::new();
+                let mut dir_index = 0;
+                for &(content_type, form) in &format {
+                    let value = pull_value(line, form, 0, encoding, context.sections)?;
+                    match (content_type, value) {
+                        (DW_LNCT_PATH, value) => {
+                            path = context.string(value)?.unwrap_or_default();
+                        }
+                        (DW_LNCT_DIRECTORY_INDEX, Value::Constant(index)) => dir_index = index,
+                        _ => {}
+                    }
+                }
+                Ok((path, dir_index))
+            })
+            .collect()
+    };
+    let dirs: Vec<_> = entries(&mut line)?
+        .into_iter()
+        .map(|(dir, _)| join_path(comp_dir, &dir))
+        .collect();
+    program.files = entries(&mut line)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, dir_index))| {
+            let dir = usize::try_from(dir_index)
+                .ok()
+                .and_then(|dir_index| dirs.get(dir_index))
+                .map_or("", String::as_str);
+            (i as u64, join_path(dir, &name))
+        })
+        .collect();
+    Ok(program)
+}
+
+// runs the opcodes of a line number program, keeping the rows of its matrix
+fn line_rows(line: &[u8], program: &LineProgram, endianness: Endianness) -> Res<Vec<LineRow>> {
+    let Some(opcodes) = line.get(program.opcodes.start as usize..program.opcodes.end as usize)
+    else {
+        unknown!()
+    };
+    if program.line_range == 0 {
+        unknown!();
+    }
+    let initial = LineRow {
+        address: 0,
+        file: 1,
+        line: 1,
+        column: 0,
+        end_sequence: false,
+    };
+    let minimum_instruction_length = u64::from(program.minimum_instruction_length);
+    let mut opcodes = Cursor::new(opcodes);
+    let mut row = initial;
+    let mut rows = vec![];
+    while opcodes.position() < opcodes.get_ref().len() as u64 {
+        match opcodes.pull::<u8>()? {
+            0 => {
+                let length = pull_uleb128(&mut opcodes)?;
+                let Some(end) = opcodes.position().checked_add(length) else {
+                    unknown!()
+                };
+                match opcodes.pull::<u8>()? {
+                    DW_LNE_END_SEQUENCE => {
+                        rows.push(LineRow {
+                            end_sequence: true,
+                            ..row
+                        });
+                        row = initial;
+                    }
+                    DW_LNE_SET_ADDRESS => {
+                        let Some(Ok(size)) = length.checked_sub(1).map(u8::try_from) else {
+                            unknown!()
+                        };
+                        row.address = pull_sized(&mut opcodes, size, endianness)?;
+                    }
+                    _ => {}
+                }
+                opcodes.jump(end)?;
+            }
+            DW_LNS_COPY => rows.push(row),
+            DW_LNS_ADVANCE_PC => {
+                let advance = pull_uleb128(&mut opcodes)?;
+                row.address = row
+                    .address
+                    .wrapping_add(advance.wrapping_mul(minimum_instruction_length));
+            }
+            DW_LNS_ADVANCE_LINE => {
+                row.line = row.line.wrapping_add_signed(pull_sleb128(&mut opcodes)?);
+            }
+            DW_LNS_SET_FILE => row.file = pull_uleb128(&mut opcodes)?,
+            DW_LNS_SET_COLUMN => row.column = pull_uleb128(&mut opcodes)?,
+            DW_LNS_CONST_ADD_PC => {
+                let advance = u64::from((255 - program.opcode_base) / program.line_range);
+                row.address = row
+                    .address
+                    .wrapping_add(advance * minimum_instruction_length);
+            }
+            DW_LNS_FIXED_ADVANCE_PC => {
+                let advance = opcodes.pull_via::<u16>(endianness)?;
+                row.address = row.address.wrapping_add(advance.into());
+            }
+            opcode if opcode < program.opcode_base => {
+                // statement, basic block, prologue, epilogue and ISA markers, or newer opcodes
+                for _ in 0..program.standard_opcode_lengths[usize::from(opcode) - 1] {
+                    pull_uleb128(&mut opcodes)?;
+                }
+            }
+            opcode => {
+                let adjusted = opcode - program.opcode_base;
+                let advance = u64::from(adjusted / program.line_range);
+                row.address = row
+                    .address
+                    .wrapping_add(advance * minimum_instruction_length);
+                row.line = row.line.wrapping_add_signed(
+                    i64::from(program.line_base) + i64::from(adjusted % program.line_range),
+                );
+                rows.push(row);
+            }
+        }
+    }
+    Ok(rows)
+}
+
+impl Parser {
+    // split DWARF objects carry the same sections with a .dwo suffix
+    fn debug_section(&self, bytes: &mut impl Bytes, name: &str) -> Res<Vec<u8>> {
+        let zdebug_name = name.replacen(".debug_", ".zdebug_", 1);
+        match self
+            .section_named(name)
+            .or_else(|| self.section_named(&format!("{name}.dwo")))
+            .or_else(|| self.section_named(&zdebug_name))
+        {
+            Some(idx) => self.relocated_section_data(bytes, idx),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn compilation_units(&self, sections: &DebugSections) -> Res<Vec<CompilationUnit>> {
+        let endianness = self.endianness;
+        let mut info = Cursor::new(&sections.info);
+        let mut abbreviation_tables = HashMap::new();
+        let mut units = vec![];
+        while info.position() < sections.info.len() as u64 {
+            let mut unit = CompilationUnit {
+                offset: info.position(),
+                ..Default::default()
+            };
+            let (length, offset_size) = pull_initial_length(&mut info, endianness)?;
+            let Some(end) = info.position().checked_add(length) else {
+                unknown!()
+            };
+            unit.offset_size = Some(offset_size);
+            unit.version = info.pull_via(endianness)?;
+            let mut encoding = UnitEncoding {
+                version: unit.version,
+                offset_size,
+                address_size: 0,
+                endianness,
+            };
+            let abbrev_offset = match unit.version {
+                2..=4 => {
+                    unit.r#type = DW_UT_COMPILE;
+                    let abbrev_offset = pull_word(&mut info, encoding.offset())?;
+                    unit.address_size = info.pull_via(endianness)?;
+                    abbrev_offset
+                }
+                5 => {
+                    unit.r#type = info.pull_via(endianness)?;
+                    unit.address_size = info.pull_via(endianness)?;
+                    let abbrev_offset = pull_word(&mut info, encoding.offset())?;
+                    match unit.r#type {
+                        DW_UT_SKELETON | DW_UT_SPLIT_COMPILE => {
+                            unit.dwo_id = Some(info.pull_via(endianness)?);
+                        }
+                        DW_UT_TYPE | DW_UT_SPLIT_TYPE => {
+                            info.forward_sizeof::<u64>()?; // type signature
+                            pull_word(&mut info, encoding.offset())?; // type offset
+                        }
+                        _ => {}
+                    }
+                    abbrev_offset
+                }
+                _ => unknown!(),
+            };
+            encoding.address_size = unit.address_size;
+
+            let abbreviation_table = match abbreviation_tables.entry(abbrev_offset) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    entry.insert(abbreviations(&sections.abbrev, abbrev_offset)?)
+                }
+            };
+            let code = pull_uleb128(&mut info)?;
+            let abbreviation = match abbreviation_table.get(&code) {
+                _ if code == 0 => None,
+                Some(abbreviation) => Some(abbreviation),
+                None => unknown!(),
+            };
+            if let Some(abbreviation) = abbreviation
+                && matches!(
+                    abbreviation.tag,
+                    DW_TAG_COMPILE_UNIT
+                        | DW_TAG_PARTIAL_UNIT
+                        | DW_TAG_TYPE_UNIT
+                        | DW_TAG_SKELETON_UNIT
+                )
+            {
+                let mut values = vec![];
+                for spec in &abbreviation.attributes {
+                    let value = pull_value(
+                        &mut info,
+                        spec.form,
+                        spec.implicit_const,
+                        encoding,
+                        sections,
+                    )?;
+                    values.push((spec.name, value));
+                }
+                self.unit_attributes(&mut unit, values, encoding, sections)?;
+            }
+
+            units.push(unit);
+            info.jump(end)?;
+        }
+        Ok(units)
+    }
+
+    fn unit_attributes(
+        &self,
+        unit: &mut CompilationUnit,
+        values: Vec<(u64, Value)>,
+        encoding: UnitEncoding,
+        sections: &DebugSections,
+    ) -> Res<()> {
+        // the bases of indexed strings and addresses may follow the attributes using them
+        let base = |name: u64, name_gnu: u64, header_size: u64| {
+            values
+                .iter()
+                .find_map(|(attribute, value)| match value {
+                    Value::Constant(base) if *attribute == name || *attribute == name_gnu => {
+                        Some(*base)
+                    }
+                    _ => None,
+                })
+                .unwrap_or(if encoding.version >= 5 {
+                    header_size
+                } else {
+                    0
+                })
+        };
+        let context = UnitContext {
+            sections,
+            encoding,
+            str_offsets_base: base(DW_AT_STR_OFFSETS_BASE, 0, 2 * encoding.offset_bytes()),
+            addr_base: base(DW_AT_ADDR_BASE, DW_AT_GNU_ADDR_BASE, 8),
+        };
+
+        let mut low_pc = None;
+        let mut high_pc = None;
+        let mut stmt_list = None;
+        for (attribute, value) in values {
+            match attribute {
+                DW_AT_PRODUCER => unit.producer = context.string(value)?,
+                DW_AT_NAME => unit.name = context.string(value)?,
+                DW_AT_COMP_DIR => unit.comp_dir = context.string(value)?,
+                DW_AT_DWO_NAME | DW_AT_GNU_DWO_NAME => unit.dwo_name = context.string(value)?,
+                DW_AT_LANGUAGE => {
+                    if let Value::Constant(language) = value {
+                        unit.language = Some(language);
+                    }
+                }
+                DW_AT_STMT_LIST => {
+                    if let Value::Constant(offset) = value {
+                        stmt_list = Some(offset);
+                    }
+                }
+                DW_AT_LOW_PC => low_pc = context.address(&value)?,
+                DW_AT_HIGH_PC => high_pc = Some(value),
+                DW_AT_RANGES => unit.has_ranges = true,
+                _ => {}
+            }
+        }
+        if let Some(low_pc) = low_pc {
+            let high_pc = match high_pc {
+                Some(Value::Constant(size)) => Some(low_pc.wrapping_add(size)),
+                Some(value) => context.address(&value)?,
+                None => None,
+            };
+            unit.pc_range = high_pc.map(|high_pc| (low_pc, high_pc));
+        }
+        if let Some(offset) = stmt_list
+            && !sections.line.is_empty()
+        {
+            let comp_dir = unit.comp_dir.as_deref().unwrap_or_default();
+            unit.line_program = Some(line_program(&context, offset, comp_dir)?);
+        }
+        Ok(())
+    }
+
+    fn debug_sections(&self, bytes: &mut impl Bytes) -> Res<DebugSections> {
+        Ok(DebugSections {
+            info: self.debug_section(bytes, ".debug_info")?,
+            abbrev: self.debug_section(bytes, ".debug_abbrev")?,
+            str: self.debug_section(bytes, ".debug_str")?,
+            str_offsets: self.debug_section(bytes, ".debug_str_offsets")?,
+            line: self.debug_section(bytes, ".debug_line")?,
+            line_str: self.debug_section(bytes, ".debug_line_str")?,
+            addr: self.debug_section(bytes, ".debug_addr")?,
+        })
+    }
+
+    pub(super) fn line_tables(&self, bytes: &mut impl Bytes) -> Res<Vec<LineTable>> {
+        let sections = self.debug_sections(bytes)?;
+        self.compilation_units(&sections)?
+            .into_iter()
+            .filter_map(|unit| unit.line_program)
+            .map(|program| {
+                Ok(LineTable {
+                    rows: line_rows(&sections.line, &program, self.endianness)?,
+                    files: program.files,
+                })
+            })
+            .collect()
+    }
+
+    pub(super) fn dwarf(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        let sections = self.debug_sections(bytes)?;
+        let units = self.compilation_units(&sections)?;
+
+        table.new_named_section("Debug Information");
+        let debug_sections: Vec<_> = self
+            .sh_names
+            .iter()
+            .filter(|name| name.starts_with(".debug_") || name.starts_with(".zdebug_"))
+            .map(String::as_str)
+            .collect();
+        table.add_entry(
+            "Debug Sections",
+            if debug_sections.is_empty() {
+                "None".into()
+            } else {
+                debug_sections.join(", ")
+            },
+        );
+        table.add_entry("Compilation Units", units.len().to_string());
+        table.add_entry(
+            "Units Without Line Info",
+            units
+                .iter()
+                .filter(|unit| unit.line_program.is_none())
+                .count()
+                .to_string(),
+        );
+        if let Some(idx) = self.section_named(".gnu_debuglink") {
+            let debuglink = self.section_data(bytes, idx)?;
+            table.add_entry("Debug Link", strtab_entry(&debuglink, 0)?);
+        }
+
+        for (i, unit) in units.iter().enumerate() {
+            table.new_named_section(format!("Compilation Unit {}/{}", i + 1, units.len()));
+            table.add_entry("Offset", format!("0x{:X}", unit.offset));
+            table.add_entry(
+                "DWARF Version",
+                match unit.offset_size {
+                    Some(WordSize::Eight) => format!("{} (64-bit format)", unit.version),
+                    _ => unit.version.to_string(),
+                },
+            );
+            let unit_type: Str = match unit.r#type {
+                DW_UT_COMPILE => "Compile".into(),
+                DW_UT_TYPE => "Type".into(),
+                DW_UT_PARTIAL => "Partial".into(),
+                DW_UT_SKELETON => "Skeleton".into(),
+                DW_UT_SPLIT_COMPILE => "Split Compile".into(),
+                DW_UT_SPLIT_TYPE => "Split Type".into(),
+                other => format!("Unknown (0x{other:X})").into(),
+            };
+            table.add_entry("Unit Type", unit_type);
+            table.add_entry("Address Size", format!("{} bytes", unit.address_size));
+            if let Some(dwo_id) = unit.dwo_id {
+                table.add_entry("DWO ID", format!("0x{dwo_id:016X}"));
+            }
+            let mut add_string = |key: &'static str, value: &Option<String>| {
+                if let Some(value) = value {
+                    table.add_entry(key, value.clone());
+                }
+            };
+            add_string("Producer", &unit.producer);
+            add_string("Name", &unit.name);
+            add_string("Comp Dir", &unit.comp_dir);
+            add_string("DWO Name", &unit.dwo_name);
+            if let Some(language) = unit.language {
+                table.add_entry(
+                    "Language",
+                    match language_name(language) {
+                        Some(name) => name.to_owned(),
+                        None => format!("Unknown (0x{language:X})"),
+                    },
+                );
+            }
+            if let Some((low_pc, high_pc)) = unit.pc_range {
+                table.add_entry(
+                    "PC Range",
+                    format!("{} - {}", self.fmt_word(low_pc), self.fmt_word(high_pc)),
+                );
+            } else if unit.has_ranges {
+                table.add_entry("PC Range", "Non-contiguous");
+            }
+
+            if let Some(LineProgram { files, .. }) = &unit.line_program {
+                table.new_named_section(format!(
+                    "Source Files of '{}' ({} entries)",
+                    unit.name
+                        .as_ref()
+                        .or(unit.dwo_name.as_ref())
+                        .map_or("", String::as_str),
+                    files.len()
+                ));
+                for (index, path) in files {
+                    table.add_entry(index.to_string(), path.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use super::WordSize;
+use crate::elf_header::*;
+
+pub fn describe(machine: u16, word_size: WordSize, flags: u32) -> Vec<&'static str> {
+    match machine {
+        EM_ARM => arm(flags),
+        EM_MIPS | EM_MIPS_RS3_LE => mips(word_size, flags),
+        EM_PARISC => parisc(flags),
+        EM_RISCV => riscv(flags),
+        _ => vec![],
+    }
+}
+
+fn set_bits(flags: u32, names: &[(u32, &'static str)]) -> impl Iterator<Item = &'static str> {
+    names
+        .iter()
+        .filter(move |(bit, _)| flags & bit > 0)
+        .map(|(_, name)| *name)
+}
+
+fn arm(flags: u32) -> Vec<&'static str> {
+    let mut descriptions = vec![];
+    match flags & EF_ARM_EABIMASK {
+        EF_ARM_EABI_VER5 => {
+            descriptions.push("EABI5");
+            descriptions.extend(set_bits(
+                flags,
+                &[
+                    (EF_ARM_BE8, "BE8"),
+                    (EF_ARM_LE8, "LE8"),
+                    (EF_ARM_ABI_FLOAT_SOFT, "soft-float ABI"),
+                    (EF_ARM_ABI_FLOAT_HARD, "hard-float ABI"),
+                ],
+            ));
+        }
+        EF_ARM_EABI_VER4 => {
+            descriptions.push("EABI4");
+            descriptions.extend(set_bits(flags, &[(EF_ARM_BE8, "BE8"), (EF_ARM_LE8, "LE8")]));
+        }
+        EF_ARM_EABI_VER3 => descriptions.push("EABI3"),
+        EF_ARM_EABI_VER2 => descriptions.push("EABI2"),
+        EF_ARM_EABI_VER1 => descriptions.push("EABI1"),
+        EF_ARM_EABI_UNKNOWN => {
+            // pre-EABI GNU toolchains
+            descriptions.push("GNU EABI");
+            descriptions.push(if flags & EF_ARM_APCS_26 > 0 {
+                "APCS-26"
+            } else {
+                "APCS-32"
+            });
+            descriptions.extend(set_bits(
+                flags,
+                &[
+                    (EF_ARM_INTERWORK, "interworking"),
+                    (EF_ARM_APCS_FLOAT, "floats in FP registers"),
+                    (EF_ARM_PIC, "position independent"),
+                    (EF_ARM_ALIGN8, "8-bit structure alignment"),
+                    (EF_ARM_NEW_ABI, "new ABI"),
+                    (EF_ARM_OLD_ABI, "old ABI"),
+                    (EF_ARM_SOFT_FLOAT, "software FP"),
+                    (EF_ARM_VFP_FLOAT, "VFP"),
+                    (EF_ARM_MAVERICK_FLOAT, "Maverick FP"),
+                ],
+            ));
+        }
+        _ => descriptions.push("unknown EABI"),
+    }
+    descriptions
+}
+
+fn mips(word_size: WordSize, flags: u32) -> Vec<&'static str> {
+    let mut descriptions = vec![match flags & EF_MIPS_ARCH {
+        EF_MIPS_ARCH_1 => "mips1",
+        EF_MIPS_ARCH_2 => "mips2",
+        EF_MIPS_ARCH_3 => "mips3",
+        EF_MIPS_ARCH_4 => "mips4",
+        EF_MIPS_ARCH_5 => "mips5",
+        EF_MIPS_ARCH_32 => "mips32",
+        EF_MIPS_ARCH_64 => "mips64",
+        EF_MIPS_ARCH_32R2 => "mips32r2",
+        EF_MIPS_ARCH_64R2 => "mips64r2",
+        EF_MIPS_ARCH_32R6 => "mips32r6",
+        EF_MIPS_ARCH_64R6 => "mips64r6",
+        _ => "unknown ISA",
+    }];
+    let abi = match (flags & EF_MIPS_ABI, word_size) {
+        (E_MIPS_ABI_O32, _) => Some("o32"),
+        (E_MIPS_ABI_O64, _) => Some("o64"),
+        (E_MIPS_ABI_EABI32, _) => Some("eabi32"),
+        (E_MIPS_ABI_EABI64, _) => Some("eabi64"),
+        (0, WordSize::Eight) => Some("n64"),
+        (0, WordSize::Four) if flags & EF_MIPS_ABI2 > 0 => Some("n32"),
+        (0, WordSize::Four) => None,
+        _ => Some("unknown ABI"),
+    };
+    descriptions.extend(abi);
+    descriptions.extend(set_bits(
+        flags,
+        &[
+            (EF_MIPS_NOREORDER, "noreorder"),
+            (EF_MIPS_PIC, "pic"),
+            (EF_MIPS_CPIC, "cpic"),
+            (EF_MIPS_XGOT, "xgot"),
+            (EF_MIPS_FP64, "fp64"),
+            (EF_MIPS_NAN2008, "nan2008"),
+            (EF_MIPS_MICROMIPS, "micromips"),
+            (EF_MIPS_ARCH_ASE_M16, "mips16"),
+            (EF_MIPS_ARCH_ASE_MDMX, "mdmx"),
+        ],
+    ));
+    descriptions
+}
+
+fn parisc(flags: u32) -> Vec<&'static str> {
+    let mut descriptions = vec![match flags & EF_PARISC_ARCH {
+        EFA_PARISC_1_0 => "PA-RISC 1.0",
+        EFA_PARISC_1_1 => "PA-RISC 1.1",
+        EFA_PARISC_2_0 => "PA-RISC 2.0",
+        _ => "unknown architecture",
+    }];
+    descriptions.extend(set_bits(
+        flags,
+        &[
+            (EF_PARISC_TRAPNIL, "trap nil pointer"),
+            (EF_PARISC_EXT, "extensions"),
+            (EF_PARISC_LSB, "little-endian"),
+            (EF_PARISC_WIDE, "wide"),
+            (EF_PARISC_NO_KABP, "no kernel-assisted branch prediction"),
+            (EF_PARISC_LAZYSWAP, "lazy swap"),
+        ],
+    ));
+    descriptions
+}
+
+fn riscv(flags: u32) -> Vec<&'static str> {
+    let mut descriptions = vec![match flags & EF_RISCV_FLOAT_ABI {
+        EF_RISCV_FLOAT_ABI_SOFT => "soft-float ABI",
+        EF_RISCV_FLOAT_ABI_SINGLE => "single-float ABI",
+        EF_RISCV_FLOAT_ABI_DOUBLE => "double-float ABI",
+        _ => "quad-float ABI",
+    }];
+    descriptions.extend(set_bits(
+        flags,
+        &[
+            (EF_RISCV_RVC, "RVC"),
+            (EF_RISCV_RVE, "RVE"),
+            (EF_RISCV_TSO, "TSO"),
+        ],
+    ));
+    descriptions
+}
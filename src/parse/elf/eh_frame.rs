@@ -0,0 +1,571 @@
+use super::{
+    Encoding, Parser, SegmentType, WordSize,
+    dwarf::{pull_initial_length, pull_sleb128, pull_uleb128},
+    pull_word, read_range, segment_data,
+};
+use crate::{
+    dwarf_header::*,
+    error::{Error, Res},
+    parse::{Bytes, Table},
+    unknown,
+};
+use std::collections::{HashMap, HashSet};
+
+type Cursor<'a> = std::io::Cursor<&'a [u8]>;
+
+// contents of .eh_frame or .eh_frame_hdr along with the address they are loaded at,
+// which pc-relative and data-relative pointers are based on
+struct Location {
+    data: Vec<u8>,
+    address: u64,
+}
+
+struct Cie {
+    offset: u64,
+    version: u8,
+    augmentation: String,
+    code_alignment: u64,
+    data_alignment: i64,
+    return_address_register: u64,
+    fde_encoding: u8,
+    lsda_encoding: u8,
+    personality: Option<(u8, u64)>,
+    signal_frame: bool,
+}
+
+struct Fde {
+    offset: u64,
+    cie: usize,
+    pc_begin: u64,
+    pc_range: u64,
+    lsda: Option<u64>,
+}
+
+enum Entry {
+    Cie(Cie),
+    Fde(Fde),
+    Orphan(u64),
+}
+
+#[derive(Default)]
+struct EhFrame {
+    cies: Vec<Cie>,
+    fdes: Vec<Fde>,
+    problems: Vec<String>,
+}
+
+struct EhFrameHdr {
+    version: u8,
+    eh_frame_ptr: u64,
+    table_encoding: u8,
+    table: Option<Vec<(u64, u64)>>,
+}
+
+fn fmt_encoding(encoding: u8) -> String {
+    if encoding == DW_EH_PE_OMIT {
+        return "omit".into();
+    }
+    let mut parts = vec![];
+    if encoding & DW_EH_PE_INDIRECT > 0 {
+        parts.push("indirect");
+    }
+    parts.push(match encoding & 0x70 {
+        DW_EH_PE_ABSPTR => "",
+        DW_EH_PE_PCREL => "pcrel",
+        DW_EH_PE_TEXTREL => "textrel",
+        DW_EH_PE_DATAREL => "datarel",
+        DW_EH_PE_FUNCREL => "funcrel",
+        DW_EH_PE_ALIGNED => "aligned",
+        _ => "unknown",
+    });
+    parts.push(match encoding & 0x0f {
+        DW_EH_PE_ABSPTR => "absptr",
+        DW_EH_PE_ULEB128 => "uleb128",
+        DW_EH_PE_UDATA2 => "udata2",
+        DW_EH_PE_UDATA4 => "udata4",
+        DW_EH_PE_UDATA8 => "udata8",
+        DW_EH_PE_SLEB128 => "sleb128",
+        DW_EH_PE_SDATA2 => "sdata2",
+        DW_EH_PE_SDATA4 => "sdata4",
+        DW_EH_PE_SDATA8 => "sdata8",
+        _ => "unknown",
+    });
+    parts.retain(|part| !part.is_empty());
+    format!("{} (0x{encoding:02X})", parts.join(" "))
+}
+
+impl Parser {
+    // pointers are relative to the address of the field itself (pcrel) or to the start of
+    // .eh_frame_hdr (datarel); indirect pointers are left as the address holding the value
+    fn pull_pointer(&self, bytes: &mut Cursor, encoding: u8, location: &Location) -> Res<u64> {
+        let word_size = self.word_size.expect("word size assigned");
+        let endianness = self.endianness;
+        if encoding & 0x70 == DW_EH_PE_ALIGNED {
+            let align = match word_size {
+                WordSize::Four => 4,
+                WordSize::Eight => 8,
+            };
+            bytes.set_position(bytes.position().next_multiple_of(align));
+        }
+        let field_address = location.address.wrapping_add(bytes.position());
+        let value = match encoding & 0x0f {
+            DW_EH_PE_ABSPTR => pull_word(bytes, self.encoding())?,
+            DW_EH_PE_ULEB128 => pull_uleb128(bytes)?,
+            DW_EH_PE_UDATA2 => bytes.pull_via::<u16>(endianness)?.into(),
+            DW_EH_PE_UDATA4 => bytes.pull_via::<u32>(endianness)?.into(),
+            DW_EH_PE_UDATA8 => bytes.pull_via(endianness)?,
+            DW_EH_PE_SLEB128 => pull_sleb128(bytes)? as u64,
+            DW_EH_PE_SDATA2 => bytes.pull_via::<u16>(endianness)? as i16 as u64,
+            DW_EH_PE_SDATA4 => bytes.pull_via::<u32>(endianness)? as i32 as u64,
+            DW_EH_PE_SDATA8 => bytes.pull_via(endianness)?,
+            _ => unknown!(),
+        };
+        let value = match encoding & 0x70 {
+            DW_EH_PE_ABSPTR | DW_EH_PE_ALIGNED => value,
+            DW_EH_PE_PCREL => field_address.wrapping_add(value),
+            DW_EH_PE_DATAREL => location.address.wrapping_add(value),
+            _ => unknown!(),
+        };
+        Ok(match word_size {
+            WordSize::Four => value & 0xffff_ffff,
+            WordSize::Eight => value,
+        })
+    }
+
+    fn cie(&self, bytes: &mut Cursor, location: &Location, offset: u64) -> Res<Cie> {
+        let version = bytes.pull()?;
+        if version != 1 && version != 3 {
+            unknown!();
+        }
+        let augmentation = bytes
+            .pull::<std::ffi::CString>()?
+            .to_string_lossy()
+            .into_owned();
+        if augmentation.contains("eh") {
+            pull_word(bytes, self.encoding())?;
+        }
+        let mut cie = Cie {
+            offset,
+            version,
+            code_alignment: pull_uleb128(bytes)?,
+            data_alignment: pull_sleb128(bytes)?,
+            return_address_register: match version {
+                1 => bytes.pull::<u8>()?.into(),
+                _ => pull_uleb128(bytes)?,
+            },
+            augmentation,
+            fde_encoding: DW_EH_PE_ABSPTR,
+            lsda_encoding: DW_EH_PE_OMIT,
+            personality: None,
+            signal_frame: false,
+        };
+        if let Some(letters) = cie.augmentation.strip_prefix('z') {
+            let length = pull_uleb128(bytes)?;
+            let Some(end) = bytes.position().checked_add(length) else {
+                unknown!()
+            };
+            for letter in letters.chars() {
+                match letter {
+                    'L' => cie.lsda_encoding = bytes.pull()?,
+                    'P' => {
+                        let encoding = bytes.pull()?;
+                        cie.personality =
+                            Some((encoding, self.pull_pointer(bytes, encoding, location)?));
+                    }
+                    'R' => cie.fde_encoding = bytes.pull()?,
+                    'S' => cie.signal_frame = true,
+                    // AArch64 branch target identification and memory tagging carry no data
+                    'B' | 'G' => {}
+                    _ => break,
+                }
+            }
+            bytes.jump(end)?;
+        }
+        Ok(cie)
+    }
+
+    fn fde(
+        &self,
+        bytes: &mut Cursor,
+        location: &Location,
+        offset: u64,
+        cie: usize,
+        cies: &[Cie],
+    ) -> Res<Fde> {
+        let encoding = cies[cie].fde_encoding;
+        let mut fde = Fde {
+            offset,
+            cie,
+            pc_begin: self.pull_pointer(bytes, encoding, location)?,
+            // the range is a plain length in the format of the start address
+            pc_range: self.pull_pointer(bytes, encoding & 0x0f, location)?,
+            lsda: None,
+        };
+        if cies[cie].augmentation.starts_with('z') {
+            let length = pull_uleb128(bytes)?;
+            let Some(end) = bytes.position().checked_add(length) else {
+                unknown!()
+            };
+            if cies[cie].lsda_encoding != DW_EH_PE_OMIT && length > 0 {
+                fde.lsda = Some(self.pull_pointer(bytes, cies[cie].lsda_encoding, location)?);
+            }
+            bytes.jump(end)?;
+        }
+        Ok(fde)
+    }
+
+    fn eh_frame_entry(
+        &self,
+        bytes: &mut Cursor,
+        location: &Location,
+        cies: &[Cie],
+    ) -> Res<Option<Entry>> {
+        let offset = bytes.position();
+        let (length, offset_size) = pull_initial_length(bytes, self.endianness)?;
+        if length == 0 {
+            return Ok(None);
+        }
+        let start = bytes.position();
+        let Some(end) = start
+            .checked_add(length)
+            .filter(|&end| end <= location.data.len() as u64)
+        else {
+            unknown!()
+        };
+        let id = pull_word(
+            bytes,
+            Encoding {
+                word_size: offset_size,
+                endianness: self.endianness,
+            },
+        )?;
+        // unlike .debug_frame, FDEs point back to their CIE relative to the pointer itself
+        let entry = if id == 0 {
+            Entry::Cie(self.cie(bytes, location, offset)?)
+        } else {
+            let cie_offset = start.wrapping_sub(id);
+            match cies.iter().position(|cie| cie.offset == cie_offset) {
+                Some(cie) => Entry::Fde(self.fde(bytes, location, offset, cie, cies)?),
+                None => Entry::Orphan(cie_offset),
+            }
+        };
+        if bytes.position() > end {
+            unknown!();
+        }
+        bytes.jump(end)?;
+        Ok(Some(entry))
+    }
+
+    // a malformed entry ends the walk, as the length of everything after it is unknown
+    fn eh_frame(&self, location: &Location) -> EhFrame {
+        let mut eh_frame = EhFrame::default();
+        let mut bytes = Cursor::new(&location.data);
+        while bytes.position() < location.data.len() as u64 {
+            let offset = bytes.position();
+            match self.eh_frame_entry(&mut bytes, location, &eh_frame.cies) {
+                Ok(Some(Entry::Cie(cie))) => eh_frame.cies.push(cie),
+                Ok(Some(Entry::Fde(fde))) => eh_frame.fdes.push(fde),
+                Ok(Some(Entry::Orphan(cie_offset))) => eh_frame.problems.push(format!(
+                    "FDE at offset 0x{offset:X} refers to missing CIE at offset 0x{cie_offset:X}"
+                )),
+                Ok(None) => break,
+                Err(_) => {
+                    eh_frame
+                        .problems
+                        .push(format!("Malformed entry at offset 0x{offset:X}"));
+                    break;
+                }
+            }
+        }
+        eh_frame
+    }
+
+    fn eh_frame_hdr(&self, location: &Location) -> Res<EhFrameHdr> {
+        let mut bytes = Cursor::new(&location.data);
+        let [
+            version,
+            eh_frame_ptr_encoding,
+            fde_count_encoding,
+            table_encoding,
+        ] = bytes.pull()?;
+        if version != 1 {
+            unknown!();
+        }
+        let eh_frame_ptr = self.pull_pointer(&mut bytes, eh_frame_ptr_encoding, location)?;
+        let table = if fde_count_encoding != DW_EH_PE_OMIT && table_encoding != DW_EH_PE_OMIT {
+            let fde_count = self.pull_pointer(&mut bytes, fde_count_encoding, location)?;
+            let mut table = vec![];
+            for _ in 0..fde_count {
+                table.push((
+                    self.pull_pointer(&mut bytes, table_encoding, location)?,
+                    self.pull_pointer(&mut bytes, table_encoding, location)?,
+                ));
+            }
+            Some(table)
+        } else {
+            None
+        };
+        Ok(EhFrameHdr {
+            version,
+            eh_frame_ptr,
+            table_encoding,
+            table,
+        })
+    }
+
+    // without section headers the tables are found through PT_GNU_EH_FRAME, and .eh_frame
+    // runs from where the header points to the end of its segment
+    fn unwind_locations(
+        &self,
+        bytes: &mut impl Bytes,
+    ) -> Res<(Option<Location>, Option<Location>)> {
+        let hdr = if let Some(idx) = self.section_named(".eh_frame_hdr") {
+            Some(Location {
+                data: self.section_data(bytes, idx)?,
+                address: self.sheaders[idx].addr,
+            })
+        } else if let Some(pheader) = self
+            .pheaders
+            .iter()
+            .find(|pheader| pheader.r#type == SegmentType::GnuEhFrame)
+        {
+            Some(Location {
+                data: segment_data(bytes, pheader)?,
+                address: pheader.vaddr,
+            })
+        } else {
+            None
+        };
+
+        let eh_frame = if let Some(idx) = self.section_named(".eh_frame") {
+            Some(Location {
+                data: self.relocated_section_data(bytes, idx)?,
+                address: self.sheaders[idx].addr,
+            })
+        } else if let Some(hdr) = &hdr
+            && let Ok(EhFrameHdr { eh_frame_ptr, .. }) = self.eh_frame_hdr(hdr)
+            && let Some(load) = self.pheaders.iter().find(|pheader| {
+                pheader.r#type == SegmentType::Load
+                    && (pheader.vaddr..pheader.vaddr.saturating_add(pheader.filesz))
+                        .contains(&eh_frame_ptr)
+            })
+        {
+            let skipped = eh_frame_ptr - load.vaddr;
+            Some(Location {
+                data: read_range(
+                    bytes,
+                    load.offset.saturating_add(skipped),
+                    load.filesz - skipped,
+                )?,
+                address: eh_frame_ptr,
+            })
+        } else {
+            None
+        };
+
+        Ok((eh_frame, hdr))
+    }
+
+    pub(super) fn unwind(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        let (eh_frame_location, hdr_location) = self.unwind_locations(bytes)?;
+        let eh_frame = eh_frame_location
+            .as_ref()
+            .map(|location| self.eh_frame(location))
+            .unwrap_or_default();
+        let eh_frame_address = eh_frame_location.as_ref().map(|location| location.address);
+
+        table.new_named_section("Unwind Information");
+        table.add_entry(
+            ".eh_frame",
+            match &eh_frame_location {
+                Some(location) => format!(
+                    "{} ({} bytes)",
+                    self.fmt_word(location.address),
+                    location.data.len()
+                ),
+                None => "None".into(),
+            },
+        );
+        table.add_entry("CIEs", eh_frame.cies.len().to_string());
+        table.add_entry("FDEs", eh_frame.fdes.len().to_string());
+
+        let mut problems = eh_frame.problems;
+        match &hdr_location {
+            Some(location) => {
+                table.add_entry(".eh_frame_hdr", self.fmt_word(location.address));
+                match self.eh_frame_hdr(location) {
+                    Ok(hdr) => {
+                        self.eh_frame_hdr_entries(table, &hdr, &eh_frame.fdes, eh_frame_address);
+                        problems.extend(eh_frame_hdr_problems(
+                            &hdr,
+                            &eh_frame.fdes,
+                            eh_frame_address,
+                        ));
+                    }
+                    Err(_) => problems.push("Malformed .eh_frame_hdr".into()),
+                }
+            }
+            None => table.add_entry(".eh_frame_hdr", "None"),
+        }
+        table.add_entry("Problems", problems.len().to_string());
+
+        for (i, cie) in eh_frame.cies.iter().enumerate() {
+            table.new_named_section(format!("CIE {}/{}", i + 1, eh_frame.cies.len()));
+            table.add_entry("Offset", format!("0x{:X}", cie.offset));
+            table.add_entry("Version", cie.version.to_string());
+            table.add_entry(
+                "Augmentation",
+                if cie.augmentation.is_empty() {
+                    "None".into()
+                } else {
+                    format!("\"{}\"", cie.augmentation)
+                },
+            );
+            table.add_entry("Code Alignment", cie.code_alignment.to_string());
+            table.add_entry("Data Alignment", cie.data_alignment.to_string());
+            table.add_entry(
+                "Return Address Register",
+                cie.return_address_register.to_string(),
+            );
+            table.add_entry("FDE Encoding", fmt_encoding(cie.fde_encoding));
+            if cie.lsda_encoding != DW_EH_PE_OMIT {
+                table.add_entry("LSDA Encoding", fmt_encoding(cie.lsda_encoding));
+            }
+            if let Some((encoding, personality)) = cie.personality {
+                table.add_entry(
+                    "Personality",
+                    format!("{} {}", self.fmt_word(personality), fmt_encoding(encoding)),
+                );
+            }
+            if cie.signal_frame {
+                table.add_entry("Signal Frame", "Yes");
+            }
+
+            let fdes: Vec<_> = eh_frame.fdes.iter().filter(|fde| fde.cie == i).collect();
+            table.new_named_section(format!(
+                "FDEs of CIE {}/{} ({} entries)",
+                i + 1,
+                eh_frame.cies.len(),
+                fdes.len()
+            ));
+            for fde in fdes {
+                let mut range = format!(
+                    "{} - {}",
+                    self.fmt_word(fde.pc_begin),
+                    self.fmt_word(fde.pc_begin.wrapping_add(fde.pc_range))
+                );
+                if let Some(lsda) = fde.lsda {
+                    range += &format!(" (LSDA {})", self.fmt_word(lsda));
+                }
+                table.add_entry(format!("0x{:X}", fde.offset), range);
+            }
+        }
+
+        if !problems.is_empty() {
+            table.new_named_section("Unwind Problems");
+            for (i, problem) in problems.into_iter().enumerate() {
+                table.add_entry((i + 1).to_string(), problem);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn eh_frame_hdr_entries(
+        &self,
+        table: &mut Table,
+        hdr: &EhFrameHdr,
+        fdes: &[Fde],
+        eh_frame_address: Option<u64>,
+    ) {
+        table.add_entry("Header Version", hdr.version.to_string());
+        table.add_entry("Header Frame Pointer", self.fmt_word(hdr.eh_frame_ptr));
+        let Some(entries) = &hdr.table else {
+            table.add_entry("Header Table", "None");
+            return;
+        };
+        table.add_entry("Header Table Encoding", fmt_encoding(hdr.table_encoding));
+        table.add_entry("Header Table Entries", entries.len().to_string());
+        table.add_entry(
+            "Header Table Sorted",
+            if entries.is_sorted_by_key(|&(pc, _)| pc) {
+                "Yes"
+            } else {
+                "No"
+            },
+        );
+        let indexed: HashSet<_> = entries.iter().map(|&(_, fde)| fde).collect();
+        let missing = match eh_frame_address {
+            Some(address) => fdes
+                .iter()
+                .filter(|fde| !indexed.contains(&address.wrapping_add(fde.offset)))
+                .count(),
+            None => fdes.len(),
+        };
+        table.add_entry(
+            "Header Table Complete",
+            match missing {
+                0 => "Yes".into(),
+                _ => format!("No ({missing} of {} FDEs missing)", fdes.len()),
+            },
+        );
+    }
+}
+
+fn eh_frame_hdr_problems(
+    hdr: &EhFrameHdr,
+    fdes: &[Fde],
+    eh_frame_address: Option<u64>,
+) -> Vec<String> {
+    let mut problems = vec![];
+    let Some(eh_frame_address) = eh_frame_address else {
+        return problems;
+    };
+    if hdr.eh_frame_ptr != eh_frame_address {
+        problems.push(format!(
+            "Header frame pointer 0x{:X} does not point at .eh_frame",
+            hdr.eh_frame_ptr
+        ));
+    }
+    let Some(entries) = &hdr.table else {
+        return problems;
+    };
+
+    for (i, pair) in entries.windows(2).enumerate() {
+        if pair[0].0 > pair[1].0 {
+            problems.push(format!(
+                "Header table entry {} (0x{:X}) is out of order after 0x{:X}",
+                i + 1,
+                pair[1].0,
+                pair[0].0
+            ));
+        }
+    }
+
+    let by_address: HashMap<_, _> = fdes
+        .iter()
+        .map(|fde| (eh_frame_address.wrapping_add(fde.offset), fde))
+        .collect();
+    for (i, &(pc, address)) in entries.iter().enumerate() {
+        match by_address.get(&address) {
+            Some(fde) if fde.pc_begin == pc => {}
+            Some(fde) => problems.push(format!(
+                "Header table entry {i} starts at 0x{pc:X} but its FDE at offset 0x{:X} starts at 0x{:X}",
+                fde.offset, fde.pc_begin
+            )),
+            None => problems.push(format!(
+                "Header table entry {i} for 0x{pc:X} points at 0x{address:X}, which is not an FDE"
+            )),
+        }
+    }
+    let indexed: HashSet<_> = entries.iter().map(|&(_, address)| address).collect();
+    for fde in fdes {
+        if !indexed.contains(&eh_frame_address.wrapping_add(fde.offset)) {
+            problems.push(format!(
+                "FDE at offset 0x{:X} for 0x{:X} is missing from the header table",
+                fde.offset, fde.pc_begin
+            ));
+        }
+    }
+    problems
+}
@@ -0,0 +1,443 @@
+use super::{Parser, SectionType, Symbol, WordSize, pull_word};
+use crate::{
+    elf_header::*,
+    error::{Error, Res},
+    parse::{Bytes, Table},
+    unknown,
+};
+
+// the hash function of SHT_HASH from the System V ABI
+fn sysv_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0, |hash: u32, &byte| {
+        let hash = (hash << 4).wrapping_add(byte.into());
+        let high = hash & 0xF000_0000;
+        (hash ^ (high >> 24)) & !high
+    })
+}
+
+// the hash function of SHT_GNU_HASH, which is djb2
+fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381, |hash: u32, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte.into())
+    })
+}
+
+enum HashTable {
+    Sysv {
+        buckets: Vec<u32>,
+        chains: Vec<u32>,
+    },
+    Gnu {
+        // symbols below this index are not hashed, and the chains start at it
+        symbol_offset: u32,
+        bloom_shift: u32,
+        bloom_bits: u32,
+        bloom: Vec<u64>,
+        buckets: Vec<u32>,
+        // the hash of each symbol with the lowest bit marking the end of a chain
+        chains: Vec<u32>,
+    },
+}
+
+impl HashTable {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Sysv { .. } => "SysV",
+            Self::Gnu { .. } => "GNU",
+        }
+    }
+
+    fn hash(&self, name: &str) -> u32 {
+        match self {
+            Self::Sysv { .. } => sysv_hash(name.as_bytes()),
+            Self::Gnu { .. } => gnu_hash(name.as_bytes()),
+        }
+    }
+
+    fn buckets(&self) -> &[u32] {
+        match self {
+            Self::Sysv { buckets, .. } | Self::Gnu { buckets, .. } => buckets,
+        }
+    }
+
+    fn bucket(&self, hash: u32) -> Option<usize> {
+        let count = self.buckets().len();
+        (count > 0).then(|| hash as usize % count)
+    }
+
+    // the GNU bloom filter turns away most names that are not in the table
+    // before any bucket is read
+    fn bloom_passes(&self, hash: u32) -> bool {
+        let Self::Gnu {
+            bloom_shift,
+            bloom_bits,
+            bloom,
+            ..
+        } = self
+        else {
+            return true;
+        };
+        if bloom.is_empty() {
+            return true;
+        }
+        let word = bloom[(hash / bloom_bits) as usize % bloom.len()];
+        let mask = 1 << (hash % bloom_bits)
+            | 1 << (hash.checked_shr(*bloom_shift).unwrap_or(0) % bloom_bits);
+        word & mask == mask
+    }
+
+    // the symbols in a bucket, in the order the loader walks them
+    fn chain(&self, bucket: usize) -> Vec<usize> {
+        let mut chain = vec![];
+        match self {
+            Self::Sysv { buckets, chains } => {
+                let mut idx = buckets[bucket] as usize;
+                // index 0 ends the chain, and the length limit stops loops in a corrupt table
+                while idx != 0 && chain.len() < chains.len() {
+                    chain.push(idx);
+                    idx = chains.get(idx).copied().unwrap_or_default() as usize;
+                }
+            }
+            Self::Gnu {
+                symbol_offset,
+                buckets,
+                chains,
+                ..
+            } => {
+                let first = buckets[bucket];
+                if first == 0 || first < *symbol_offset {
+                    return chain;
+                }
+                for (i, hash) in chains
+                    .iter()
+                    .enumerate()
+                    .skip((first - symbol_offset) as usize)
+                {
+                    chain.push(i + *symbol_offset as usize);
+                    if hash & 1 > 0 {
+                        break;
+                    }
+                }
+            }
+        }
+        chain
+    }
+
+    // the symbols the loader compares a name against, as the GNU table skips those
+    // whose stored hash differs
+    fn candidates(&self, hash: u32) -> Vec<usize> {
+        let Some(bucket) = self.bucket(hash) else {
+            return vec![];
+        };
+        let chain = self.chain(bucket);
+        match self {
+            Self::Sysv { .. } => chain,
+            Self::Gnu {
+                symbol_offset,
+                chains,
+                ..
+            } => chain
+                .into_iter()
+                .filter(|idx| chains[idx - *symbol_offset as usize] | 1 == hash | 1)
+                .collect(),
+        }
+    }
+
+    // why the loader cannot find a symbol by its name, if it cannot
+    fn unreachable(&self, symbols: &[(String, Symbol)], idx: usize) -> Option<String> {
+        let hash = self.hash(&symbols[idx].0);
+        if let Self::Gnu { symbol_offset, .. } = self
+            && idx < *symbol_offset as usize
+        {
+            return Some(format!("below the first hashed symbol {symbol_offset}"));
+        }
+        if !self.bloom_passes(hash) {
+            return Some("rejected by the bloom filter".into());
+        }
+        // versioned symbols can share a name, and the loader tells them apart by version
+        let bucket = self.bucket(hash)?;
+        if self.candidates(hash).contains(&idx) {
+            None
+        } else if self.chain(bucket).contains(&idx) {
+            Some(format!(
+                "in bucket {bucket} with a stored hash not matching its name"
+            ))
+        } else {
+            Some(format!("missing from the chain of bucket {bucket}"))
+        }
+    }
+}
+
+impl Parser {
+    fn read_hash_table(&self, bytes: &mut impl Bytes, idx: usize) -> Res<HashTable> {
+        let sheader = &self.sheaders[idx];
+        let data = self.section_data(bytes, idx)?;
+        let len = data.len() as u64;
+        let mut data = std::io::Cursor::new(data);
+        let pull_u32s = |data: &mut std::io::Cursor<Vec<u8>>, count: u64| -> Res<Vec<u32>> {
+            (0..count).map(|_| data.pull_via(self.endianness)).collect()
+        };
+        Ok(match sheader.r#type {
+            SectionType::Hash => {
+                // 64-bit s390 and Alpha use 8 byte entries where everyone else uses 4
+                let entry_size = if sheader.entsize == 8 { 8 } else { 4 };
+                let pull = |data: &mut std::io::Cursor<Vec<u8>>| -> Res<u32> {
+                    Ok(match entry_size {
+                        8 => data.pull_via::<u64>(self.endianness)? as u32,
+                        _ => data.pull_via(self.endianness)?,
+                    })
+                };
+                let bucket_count = pull(&mut data)?;
+                let chain_count = pull(&mut data)?;
+                if (2 + u64::from(bucket_count) + u64::from(chain_count)) * entry_size > len {
+                    unknown!();
+                }
+                HashTable::Sysv {
+                    buckets: (0..bucket_count)
+                        .map(|_| pull(&mut data))
+                        .collect::<Res<_>>()?,
+                    chains: (0..chain_count)
+                        .map(|_| pull(&mut data))
+                        .collect::<Res<_>>()?,
+                }
+            }
+            SectionType::GnuHash => {
+                let bucket_count: u32 = data.pull_via(self.endianness)?;
+                let symbol_offset = data.pull_via(self.endianness)?;
+                let bloom_size: u32 = data.pull_via(self.endianness)?;
+                let bloom_shift = data.pull_via(self.endianness)?;
+                let (bloom_bits, word_size) = match self.word_size.expect("word size assigned") {
+                    WordSize::Four => (32, 4),
+                    WordSize::Eight => (64, 8),
+                };
+                let header_size = 16 + u64::from(bloom_size) * word_size;
+                if header_size + u64::from(bucket_count) * 4 > len {
+                    unknown!();
+                }
+                let bloom = (0..bloom_size)
+                    .map(|_| pull_word(&mut data, self.encoding()))
+                    .collect::<Res<_>>()?;
+                let buckets = pull_u32s(&mut data, bucket_count.into())?;
+                let chain_count = (len - header_size - u64::from(bucket_count) * 4) / 4;
+                HashTable::Gnu {
+                    symbol_offset,
+                    bloom_shift,
+                    bloom_bits,
+                    bloom,
+                    buckets,
+                    chains: pull_u32s(&mut data, chain_count)?,
+                }
+            }
+            _ => unknown!(),
+        })
+    }
+
+    pub(super) fn hash_table(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        idx: usize,
+    ) -> Res<()> {
+        let hash_table = self.read_hash_table(bytes, idx)?;
+        let symbols = self.symbols(bytes, self.sheaders[idx].link as usize)?;
+        let buckets = hash_table.buckets().len();
+        let chain_lengths: Vec<_> = (0..buckets)
+            .map(|bucket| hash_table.chain(bucket).len())
+            .collect();
+        let hashed: usize = chain_lengths.iter().sum();
+        let empty = chain_lengths.iter().filter(|&&len| len == 0).count();
+        let percentage = |count: usize, total: usize| match total {
+            0 => 0.0,
+            _ => 100.0 * count as f64 / total as f64,
+        };
+
+        table.new_named_section(format!(
+            "Hash Table '{}' ({})",
+            self.sh_names[idx],
+            hash_table.kind()
+        ));
+        table.add_entry("Buckets", buckets.to_string());
+        table.add_entry("Hashed Symbols", hashed.to_string());
+        table.add_entry(
+            "Empty Buckets",
+            format!("{empty} ({:.1}%)", percentage(empty, buckets)),
+        );
+        table.add_entry(
+            "Load Factor",
+            format!(
+                "{:.2} symbols per bucket",
+                hashed as f64 / buckets.max(1) as f64
+            ),
+        );
+        table.add_entry(
+            "Longest Chain",
+            chain_lengths
+                .iter()
+                .max()
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+        );
+        table.add_entry(
+            "Average Chain",
+            format!(
+                "{:.2} symbols per used bucket",
+                hashed as f64 / (buckets - empty).max(1) as f64
+            ),
+        );
+        if let HashTable::Gnu {
+            symbol_offset,
+            bloom_shift,
+            bloom_bits,
+            bloom,
+            ..
+        } = &hash_table
+        {
+            table.add_entry("Symbol Offset", symbol_offset.to_string());
+            let total = bloom.len() * *bloom_bits as usize;
+            let set: usize = bloom.iter().map(|word| word.count_ones() as usize).sum();
+            table.add_entry(
+                "Bloom Filter",
+                format!(
+                    "{} words of {bloom_bits} bits, shift {bloom_shift}, {set}/{total} bits set ({:.1}%)",
+                    bloom.len(),
+                    percentage(set, total)
+                ),
+            );
+        }
+
+        // every symbol another object can bind to should be found by name
+        let exported: Vec<_> = symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, symbol))| {
+                !name.is_empty() && symbol.shndx != SHN_UNDEF && symbol.binding() != STB_LOCAL
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let problems: Vec<_> = exported
+            .iter()
+            .filter_map(|&i| {
+                hash_table
+                    .unreachable(&symbols, i)
+                    .map(|reason| format!("Symbol {i} '{}' is {reason}", symbols[i].0))
+            })
+            .collect();
+        table.add_entry(
+            "Reachable Exports",
+            format!("{}/{}", exported.len() - problems.len(), exported.len()),
+        );
+        table.add_entry("Problems", problems.len().to_string());
+
+        if !problems.is_empty() {
+            table.new_named_section(format!("Problems of Hash Table '{}'", self.sh_names[idx]));
+            for (i, problem) in problems.into_iter().enumerate() {
+                table.add_entry((i + 1).to_string(), problem);
+            }
+        }
+
+        Ok(())
+    }
+
+    // the dynamic loader's lookup of each name, through the GNU table first as it would
+    pub(super) fn symbol_lookups(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        names: &[String],
+    ) -> Res<()> {
+        let mut tables = vec![];
+        for r#type in [SectionType::GnuHash, SectionType::Hash] {
+            if let Some(idx) = self
+                .sheaders
+                .iter()
+                .position(|sheader| sheader.r#type == r#type)
+            {
+                let symtab_idx = self.sheaders[idx].link as usize;
+                let symbols = self.symbols(bytes, symtab_idx)?;
+                let versions = self.symbol_versions(bytes, &symbols, symtab_idx)?;
+                tables.push((self.read_hash_table(bytes, idx)?, symbols, versions));
+            }
+        }
+        if tables.is_empty() {
+            return Err(Error::Cli(
+                "No .gnu.hash or .hash section to look symbols up in".into(),
+            ));
+        }
+        let fmt_comparisons = |count: usize| match count {
+            1 => "1 string comparison".to_string(),
+            count => format!("{count} string comparisons"),
+        };
+
+        for name in names {
+            table.new_named_section(format!("Symbol Lookup of '{name}'"));
+            // symbols can share a name in different versions, so every match is listed
+            let mut found = std::collections::BTreeMap::new();
+            for (hash_table, symbols, versions) in &tables {
+                let hash = hash_table.hash(name);
+                let mut value = format!("hash 0x{hash:08X}");
+                if let Some(bucket) = hash_table.bucket(hash) {
+                    value += &format!(", bucket {bucket}");
+                }
+                if hash_table.bloom_passes(hash) {
+                    let candidates = hash_table.candidates(hash);
+                    let matches: Vec<_> = candidates
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &i)| {
+                            symbols
+                                .get(i)
+                                .is_some_and(|(symbol_name, _)| symbol_name == name)
+                        })
+                        .collect();
+                    match matches.first() {
+                        Some(&(position, idx)) => {
+                            value += &format!(
+                                ", found symbol {idx} after {}",
+                                fmt_comparisons(position + 1)
+                            )
+                        }
+                        None => {
+                            value +=
+                                &format!(", not found after {}", fmt_comparisons(candidates.len()))
+                        }
+                    }
+                    for (_, &idx) in matches {
+                        found.entry(idx).or_insert_with(|| {
+                            (
+                                &symbols[idx].1,
+                                versions.get(idx).map_or("", String::as_str),
+                            )
+                        });
+                    }
+                } else {
+                    value += ", rejected by the bloom filter";
+                }
+                table.add_entry(format!("{} Hash", hash_table.kind()), value);
+            }
+            if found.is_empty() {
+                table.add_entry("Symbol", "Not found");
+            }
+            for (idx, (symbol, version)) in found {
+                let section = match symbol.shndx {
+                    SHN_UNDEF => "undefined".into(),
+                    SHN_ABS => "absolute".into(),
+                    shndx => match self.sh_names.get(shndx as usize) {
+                        Some(name) => format!("in {name}"),
+                        None => format!("in section {shndx}"),
+                    },
+                };
+                table.add_entry(
+                    format!("Symbol {idx}"),
+                    format!(
+                        "{name}{version}, value {}, {} bytes, {section}",
+                        self.fmt_word(symbol.value),
+                        symbol.size
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use super::{
+    Parser, SectionType, SegmentType, Symbol, WordSize, lookup::nearest_symbol, pull_word, reloc,
+};
+use crate::{
+    elf_header::*,
+    error::Res,
+    parse::{Bytes, Table},
+};
+
+// a relocation patching a function pointer, with the symbol it refers to if any
+struct PointerRelocation {
+    offset: u64,
+    r#type: u32,
+    symbol: Option<(String, Symbol)>,
+    addend: Option<i64>,
+}
+
+impl Parser {
+    fn describe_function(
+        &self,
+        symbols: &[(String, Symbol)],
+        address: u64,
+        source: &str,
+    ) -> String {
+        match self.describe_address(symbols, address) {
+            Some(description) => format!("{description} at 0x{address:X} from {source}"),
+            None => format!("0x{address:X} from {source}"),
+        }
+    }
+
+    // relocations of a relocatable object that target the section, or else the dynamic
+    // relocations the loader applies
+    fn pointer_relocations(
+        &self,
+        bytes: &mut impl Bytes,
+        idx: usize,
+    ) -> Res<Vec<PointerRelocation>> {
+        let mut relocations = vec![];
+        for (rel_idx, rel_header) in self.sheaders.iter().enumerate() {
+            let applies = match self.file_type {
+                ET_REL => rel_header.info as usize == idx,
+                _ => rel_header.flags & SHF_ALLOC > 0,
+            };
+            if !matches!(rel_header.r#type, SectionType::Rel | SectionType::Rela) || !applies {
+                continue;
+            }
+            let symbols = self.linked_symbols(bytes, rel_header)?;
+            for relocation in self.read_relocations(bytes, rel_idx)? {
+                let (symbol, r#type) = self.relocation_info(relocation.info);
+                relocations.push(PointerRelocation {
+                    offset: relocation.offset,
+                    r#type,
+                    symbol: symbols.get(symbol as usize).filter(|_| symbol > 0).cloned(),
+                    addend: relocation.addend,
+                });
+            }
+        }
+        Ok(relocations)
+    }
+
+    // what each pointer of an init, fini or preinit array calls, relative to its section
+    // in a relocatable object and as loaded at address 0 otherwise
+    fn function_array(
+        &self,
+        bytes: &mut impl Bytes,
+        idx: usize,
+        symbols: &[(String, Symbol)],
+    ) -> Res<Vec<String>> {
+        let encoding = self.encoding();
+        let word_size = match self.word_size.expect("word size assigned") {
+            WordSize::Four => 4,
+            WordSize::Eight => 8,
+        };
+        let sheader = &self.sheaders[idx];
+        let name = &self.sh_names[idx];
+        let relocations = self.pointer_relocations(bytes, idx)?;
+        let data = self.section_data(bytes, idx)?;
+        let count = data.len() as u64 / word_size;
+        let mut data = std::io::Cursor::new(data);
+
+        let mut functions = vec![];
+        for i in 0..count {
+            let stored = pull_word(&mut data, encoding)?;
+            let place = match self.file_type {
+                ET_REL => i * word_size,
+                _ => sheader.addr.wrapping_add(i * word_size),
+            };
+            let source = format!("{name}[{i}]");
+            let relocation = relocations
+                .iter()
+                .find(|relocation| relocation.offset == place);
+            let addend =
+                |relocation: &PointerRelocation| relocation.addend.unwrap_or(stored as i64) as u64;
+            let address = match relocation {
+                Some(
+                    relocation @ PointerRelocation {
+                        symbol: Some((symbol_name, symbol)),
+                        ..
+                    },
+                ) => {
+                    let offset = addend(relocation);
+                    // objects refer to local functions through their section symbol
+                    let description = if self.file_type == ET_REL && symbol.r#type() == STT_SECTION
+                    {
+                        let section = symbol.shndx as usize;
+                        let target = symbol.value.wrapping_add(offset);
+                        match nearest_symbol(symbols, target, Some(section)) {
+                            Some((name, symbol)) if target == symbol.value => name.clone(),
+                            Some((name, symbol)) => format!("{name}+0x{:X}", target - symbol.value),
+                            None => format!(
+                                "{}+0x{target:X}",
+                                self.sh_names.get(section).map_or("?", |name| name)
+                            ),
+                        }
+                    } else if offset == 0 {
+                        symbol_name.clone()
+                    } else {
+                        format!("{symbol_name}+0x{offset:X}")
+                    };
+                    functions.push(format!("{description} from {source}"));
+                    continue;
+                }
+                Some(relocation) if reloc::is_relative(self.machine, relocation.r#type) => {
+                    addend(relocation)
+                }
+                // RELR packed relocations and fixed addresses keep the pointer in place
+                _ => stored,
+            };
+            functions.push(self.describe_function(symbols, address, &source));
+        }
+        Ok(functions)
+    }
+
+    // DT_INIT and DT_FINI, or the _init and _fini sections static executables call
+    fn init_fini(&self, bytes: &mut impl Bytes) -> Res<[Option<(u64, &'static str)>; 2]> {
+        if let Some(dynamic) = self
+            .pheaders
+            .iter()
+            .find(|pheader| pheader.r#type == SegmentType::Dynamic)
+        {
+            let entries = self.dynamic_entries(bytes, dynamic)?;
+            let find = |tag: u32, source| {
+                entries
+                    .iter()
+                    .find(|entry| entry.tag == tag.into())
+                    .map(|entry| (entry.value, source))
+            };
+            return Ok([find(DT_INIT, "DT_INIT"), find(DT_FINI, "DT_FINI")]);
+        }
+        if self.file_type == ET_REL {
+            return Ok([None, None]);
+        }
+        let find = |name| {
+            self.section_named(name)
+                .map(|idx| (self.sheaders[idx].addr, name))
+        };
+        Ok([find(".init"), find(".fini")])
+    }
+
+    // constructors in the order the loader runs them: the preinit array of an executable,
+    // DT_INIT and then the init array, and destructors from the end of the fini array
+    // before DT_FINI
+    pub(super) fn init_functions(&self, bytes: &mut impl Bytes, table: &mut Table) -> Res<()> {
+        let arrays = |r#type| {
+            self.sheaders
+                .iter()
+                .enumerate()
+                .filter(move |(_, sheader)| sheader.r#type == r#type)
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>()
+        };
+        let (preinit_arrays, init_arrays, fini_arrays) = (
+            arrays(SectionType::PreinitArray),
+            arrays(SectionType::InitArray),
+            arrays(SectionType::FiniArray),
+        );
+        let [init, fini] = self.init_fini(bytes)?;
+        if preinit_arrays.is_empty()
+            && init_arrays.is_empty()
+            && fini_arrays.is_empty()
+            && init.is_none()
+            && fini.is_none()
+        {
+            return Ok(());
+        }
+
+        let symbols = self.lookup_symbols(bytes)?;
+        let describe =
+            |(address, source): (u64, &str)| self.describe_function(&symbols, address, source);
+
+        let mut constructors = vec![];
+        for idx in preinit_arrays {
+            constructors.extend(self.function_array(bytes, idx, &symbols)?);
+        }
+        constructors.extend(init.map(describe));
+        for idx in init_arrays {
+            constructors.extend(self.function_array(bytes, idx, &symbols)?);
+        }
+
+        let mut destructors = vec![];
+        for idx in fini_arrays {
+            destructors.extend(self.function_array(bytes, idx, &symbols)?);
+        }
+        destructors.reverse();
+        destructors.extend(fini.map(describe));
+
+        for (name, functions) in [("Constructors", constructors), ("Destructors", destructors)] {
+            if functions.is_empty() {
+                continue;
+            }
+            let count = match functions.len() {
+                1 => "1 function".into(),
+                count => format!("{count} functions"),
+            };
+            table.new_named_section(format!("{name} ({count})"));
+            for (i, function) in functions.into_iter().enumerate() {
+                table.add_entry(format!("Function {}", i + 1), function);
+            }
+        }
+
+        Ok(())
+    }
+}
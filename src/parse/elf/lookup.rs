@@ -0,0 +1,178 @@
+use super::{Parser, ProgramHeader, SectionType, SegmentType, Symbol, fmt_flags};
+use crate::{
+    elf_header::*,
+    error::Res,
+    parse::{Bytes, Table},
+};
+
+// the closest symbol at or below the address, preferring one that covers it,
+// and staying within the section of the address when there is one
+pub(super) fn nearest_symbol(
+    symbols: &[(String, Symbol)],
+    address: u64,
+    section: Option<usize>,
+) -> Option<&(String, Symbol)> {
+    symbols
+        .iter()
+        .filter(|(_, symbol)| {
+            symbol.value <= address
+                && section
+                    .is_none_or(|idx| symbol.shndx as usize == idx || symbol.shndx >= SHN_LORESERVE)
+        })
+        .max_by_key(|(_, symbol)| {
+            (
+                address - symbol.value < symbol.size,
+                symbol.value,
+                symbol.r#type() == STT_FUNC,
+            )
+        })
+}
+
+impl Parser {
+    // defined symbols naming code or data, from .symtab or else from .dynsym
+    pub(super) fn lookup_symbols(&self, bytes: &mut impl Bytes) -> Res<Vec<(String, Symbol)>> {
+        let Some(idx) = [SectionType::SymTab, SectionType::DynSym]
+            .iter()
+            .find_map(|r#type| {
+                self.sheaders
+                    .iter()
+                    .position(|sheader| sheader.r#type == *r#type)
+            })
+        else {
+            return Ok(vec![]);
+        };
+        Ok(self
+            .symbols(bytes, idx)?
+            .into_iter()
+            .filter(|(name, symbol)| {
+                !name.is_empty()
+                    && symbol.shndx != SHN_UNDEF
+                    && matches!(
+                        symbol.r#type(),
+                        STT_NOTYPE | STT_OBJECT | STT_FUNC | STT_GNU_IFUNC
+                    )
+            })
+            .collect())
+    }
+
+    // the symbol covering an address, or else the section it is in
+    pub(super) fn describe_address(
+        &self,
+        symbols: &[(String, Symbol)],
+        address: u64,
+    ) -> Option<String> {
+        let section = self.containing_section(address);
+        if let Some((name, symbol)) = nearest_symbol(symbols, address, section)
+            && (address - symbol.value < symbol.size || address == symbol.value)
+        {
+            return Some(match address - symbol.value {
+                0 => name.clone(),
+                offset => format!("{name}+0x{offset:X}"),
+            });
+        }
+        section.map(|idx| match address - self.sheaders[idx].addr {
+            0 => self.sh_names[idx].clone(),
+            offset => format!("{}+0x{offset:X}", self.sh_names[idx]),
+        })
+    }
+
+    pub(super) fn containing_segment(&self, address: u64) -> Option<(usize, &ProgramHeader)> {
+        self.pheaders.iter().enumerate().find(|(_, pheader)| {
+            pheader.r#type == SegmentType::Load
+                && (pheader.vaddr..pheader.vaddr.saturating_add(pheader.memsz)).contains(&address)
+        })
+    }
+
+    // TLS sections are skipped, as .tbss takes no address space and overlaps what follows
+    pub(super) fn containing_section(&self, address: u64) -> Option<usize> {
+        self.sheaders.iter().position(|sheader| {
+            sheader.flags & SHF_ALLOC > 0
+                && sheader.flags & SHF_TLS == 0
+                && (sheader.addr..sheader.addr.saturating_add(sheader.size)).contains(&address)
+        })
+    }
+
+    pub(super) fn lookup(
+        &self,
+        bytes: &mut impl Bytes,
+        table: &mut Table,
+        addresses: &[u64],
+    ) -> Res<()> {
+        let symbols = self.lookup_symbols(bytes)?;
+        let line_tables = self.line_tables(bytes)?;
+
+        for &address in addresses {
+            table.new_named_section(format!("Address {}", self.fmt_word(address)));
+
+            let segment = self.containing_segment(address);
+            match segment {
+                Some((i, pheader)) => {
+                    table.add_entry(
+                        "Segment",
+                        format!(
+                            "{} ({})",
+                            i + 1,
+                            fmt_flags(&[
+                                (pheader.flags & PF_R > 0, "Read"),
+                                (pheader.flags & PF_W > 0, "Write"),
+                                (pheader.flags & PF_X > 0, "Execute"),
+                            ])
+                        ),
+                    );
+                    let offset = address - pheader.vaddr;
+                    table.add_entry(
+                        "File Offset",
+                        if offset < pheader.filesz {
+                            format!("0x{:X}", pheader.offset + offset)
+                        } else {
+                            "None (zero-filled)".into()
+                        },
+                    );
+                }
+                None => table.add_entry("Segment", "None"),
+            }
+
+            let section = self.containing_section(address);
+            match section {
+                Some(idx) => {
+                    table.add_entry("Section", format!("{idx} ({})", self.sh_names[idx]));
+                    table.add_entry(
+                        "Section Offset",
+                        format!("0x{:X}", address - self.sheaders[idx].addr),
+                    );
+                }
+                None => table.add_entry("Section", "None"),
+            }
+
+            // an address outside the file is in no symbol, and one inside is only in a
+            // symbol that covers it
+            let symbol = nearest_symbol(&symbols, address, section).filter(|(_, symbol)| {
+                (section.is_some() || segment.is_some())
+                    && (address - symbol.value < symbol.size || address == symbol.value)
+            });
+            table.add_entry(
+                "Symbol",
+                match symbol {
+                    Some((name, symbol)) => match address - symbol.value {
+                        0 => name.clone(),
+                        offset => format!("{name}+0x{offset:X}"),
+                    },
+                    None => "None".into(),
+                },
+            );
+
+            table.add_entry(
+                "Source Line",
+                match line_tables.iter().find_map(|lines| lines.find(address)) {
+                    // line 0 marks code without a source line, such as compiler generated code
+                    Some((file, 0, _)) => format!("{file} (no line)"),
+                    Some((file, line, 0)) => format!("{file}:{line}"),
+                    Some((file, line, column)) => format!("{file}:{line}:{column}"),
+                    None => "None".into(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1116 @@
+use crate::elf_header::*;
+
+pub fn type_name(machine: u16, r#type: u32) -> Option<&'static str> {
+    let small_type = u8::try_from(r#type).ok();
+    match machine {
+        EM_X86_64 => x86_64(small_type?),
+        EM_386 => i386(small_type?),
+        EM_ARM => arm(small_type?),
+        EM_PPC => ppc(small_type?),
+        EM_PPC64 => ppc64(small_type?),
+        EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => sparc(small_type?),
+        EM_S390 => s390(small_type?),
+        EM_68K => m68k(small_type?),
+        EM_ALPHA | EM_FAKE_ALPHA => alpha(small_type?),
+        EM_CRIS => cris(small_type?),
+        EM_IA_64 => ia64(r#type),
+        EM_M32R => m32r(small_type?),
+        EM_MIPS | EM_MIPS_RS3_LE => mips(small_type?),
+        EM_MN10300 => mn10300(small_type?),
+        EM_PARISC => parisc(small_type?),
+        EM_SH => sh(small_type?),
+        _ => None,
+    }
+}
+
+// absolute data relocations (S + A) and the number of bytes they patch
+pub fn absolute_size(machine: u16, r#type: u32) -> Option<usize> {
+    if machine == EM_AARCH64 {
+        return match r#type {
+            R_AARCH64_ABS64 => Some(8),
+            R_AARCH64_ABS32 => Some(4),
+            _ => None,
+        };
+    }
+    let r#type = u8::try_from(r#type).ok()?;
+    Some(match (machine, r#type) {
+        (EM_X86_64, R_X86_64_64) => 8,
+        (EM_X86_64, R_X86_64_32 | R_X86_64_32S) => 4,
+        (EM_386, R_386_32) => 4,
+        (EM_ARM, R_ARM_ABS32) => 4,
+        (EM_PPC, R_PPC_ADDR32) => 4,
+        (EM_PPC64, R_PPC64_ADDR64) => 8,
+        (EM_PPC64, R_PPC64_ADDR32) => 4,
+        (EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9, R_SPARC_64 | R_SPARC_UA64) => 8,
+        (EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9, R_SPARC_32 | R_SPARC_UA32) => 4,
+        (EM_S390, R_390_64) => 8,
+        (EM_S390, R_390_32) => 4,
+        (EM_MIPS | EM_MIPS_RS3_LE, R_MIPS_64) => 8,
+        (EM_MIPS | EM_MIPS_RS3_LE, R_MIPS_32) => 4,
+        (EM_RISCV, R_RISCV_64) => 8,
+        (EM_RISCV, R_RISCV_32) => 4,
+        _ => return None,
+    })
+}
+
+// pc-relative data relocations (S + A - P) and the number of bytes they patch
+pub fn relative_size(machine: u16, r#type: u32) -> Option<usize> {
+    if machine == EM_AARCH64 {
+        return match r#type {
+            R_AARCH64_PREL64 => Some(8),
+            R_AARCH64_PREL32 => Some(4),
+            _ => None,
+        };
+    }
+    let r#type = u8::try_from(r#type).ok()?;
+    Some(match (machine, r#type) {
+        (EM_X86_64, R_X86_64_PC64) => 8,
+        (EM_X86_64, R_X86_64_PC32) => 4,
+        (EM_386, R_386_PC32) => 4,
+        (EM_ARM, R_ARM_REL32) => 4,
+        (EM_PPC, R_PPC_REL32) => 4,
+        (EM_PPC64, R_PPC64_REL64) => 8,
+        (EM_PPC64, R_PPC64_REL32) => 4,
+        (EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9, R_SPARC_DISP32) => 4,
+        (EM_S390, R_390_PC64) => 8,
+        (EM_S390, R_390_PC32) => 4,
+        (EM_RISCV, R_RISCV_32_PCREL) => 4,
+        _ => return None,
+    })
+}
+
+// relative relocations (B + A) the loader applies to position independent files
+pub fn is_relative(machine: u16, r#type: u32) -> bool {
+    if machine == EM_AARCH64 {
+        return r#type == R_AARCH64_RELATIVE;
+    }
+    let Ok(r#type) = u8::try_from(r#type) else {
+        return false;
+    };
+    matches!(
+        (machine, r#type),
+        (EM_X86_64, R_X86_64_RELATIVE | R_X86_64_RELATIVE64)
+            | (EM_386, R_386_RELATIVE)
+            | (EM_ARM, R_ARM_RELATIVE)
+            | (EM_PPC, R_PPC_RELATIVE)
+            | (EM_PPC64, R_PPC64_RELATIVE)
+            | (EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9, R_SPARC_RELATIVE)
+            | (EM_S390, R_390_RELATIVE)
+            | (EM_RISCV, R_RISCV_RELATIVE)
+    )
+}
+
+fn x86_64(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_X86_64_NONE => "R_X86_64_NONE",
+        R_X86_64_64 => "R_X86_64_64",
+        R_X86_64_PC32 => "R_X86_64_PC32",
+        R_X86_64_GOT32 => "R_X86_64_GOT32",
+        R_X86_64_PLT32 => "R_X86_64_PLT32",
+        R_X86_64_COPY => "R_X86_64_COPY",
+        R_X86_64_GLOB_DAT => "R_X86_64_GLOB_DAT",
+        R_X86_64_JUMP_SLOT => "R_X86_64_JUMP_SLOT",
+        R_X86_64_RELATIVE => "R_X86_64_RELATIVE",
+        R_X86_64_GOTPCREL => "R_X86_64_GOTPCREL",
+        R_X86_64_32 => "R_X86_64_32",
+        R_X86_64_32S => "R_X86_64_32S",
+        R_X86_64_16 => "R_X86_64_16",
+        R_X86_64_PC16 => "R_X86_64_PC16",
+        R_X86_64_8 => "R_X86_64_8",
+        R_X86_64_PC8 => "R_X86_64_PC8",
+        R_X86_64_DTPMOD64 => "R_X86_64_DTPMOD64",
+        R_X86_64_DTPOFF64 => "R_X86_64_DTPOFF64",
+        R_X86_64_TPOFF64 => "R_X86_64_TPOFF64",
+        R_X86_64_TLSGD => "R_X86_64_TLSGD",
+        R_X86_64_TLSLD => "R_X86_64_TLSLD",
+        R_X86_64_DTPOFF32 => "R_X86_64_DTPOFF32",
+        R_X86_64_GOTTPOFF => "R_X86_64_GOTTPOFF",
+        R_X86_64_TPOFF32 => "R_X86_64_TPOFF32",
+        R_X86_64_PC64 => "R_X86_64_PC64",
+        R_X86_64_GOTOFF64 => "R_X86_64_GOTOFF64",
+        R_X86_64_GOTPC32 => "R_X86_64_GOTPC32",
+        R_X86_64_GOT64 => "R_X86_64_GOT64",
+        R_X86_64_GOTPCREL64 => "R_X86_64_GOTPCREL64",
+        R_X86_64_GOTPC64 => "R_X86_64_GOTPC64",
+        R_X86_64_GOTPLT64 => "R_X86_64_GOTPLT64",
+        R_X86_64_PLTOFF64 => "R_X86_64_PLTOFF64",
+        R_X86_64_SIZE32 => "R_X86_64_SIZE32",
+        R_X86_64_SIZE64 => "R_X86_64_SIZE64",
+        R_X86_64_GOTPC32_TLSDESC => "R_X86_64_GOTPC32_TLSDESC",
+        R_X86_64_TLSDESC_CALL => "R_X86_64_TLSDESC_CALL",
+        R_X86_64_TLSDESC => "R_X86_64_TLSDESC",
+        R_X86_64_IRELATIVE => "R_X86_64_IRELATIVE",
+        R_X86_64_RELATIVE64 => "R_X86_64_RELATIVE64",
+        R_X86_64_GOTPCRELX => "R_X86_64_GOTPCRELX",
+        R_X86_64_REX_GOTPCRELX => "R_X86_64_REX_GOTPCRELX",
+        _ => return None,
+    })
+}
+
+fn i386(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_386_NONE => "R_386_NONE",
+        R_386_32 => "R_386_32",
+        R_386_PC32 => "R_386_PC32",
+        R_386_GOT32 => "R_386_GOT32",
+        R_386_PLT32 => "R_386_PLT32",
+        R_386_COPY => "R_386_COPY",
+        R_386_GLOB_DAT => "R_386_GLOB_DAT",
+        R_386_JMP_SLOT => "R_386_JMP_SLOT",
+        R_386_RELATIVE => "R_386_RELATIVE",
+        R_386_GOTOFF => "R_386_GOTOFF",
+        R_386_GOTPC => "R_386_GOTPC",
+        R_386_32PLT => "R_386_32PLT",
+        R_386_TLS_TPOFF => "R_386_TLS_TPOFF",
+        R_386_TLS_IE => "R_386_TLS_IE",
+        R_386_TLS_GOTIE => "R_386_TLS_GOTIE",
+        R_386_TLS_LE => "R_386_TLS_LE",
+        R_386_TLS_GD => "R_386_TLS_GD",
+        R_386_TLS_LDM => "R_386_TLS_LDM",
+        R_386_16 => "R_386_16",
+        R_386_PC16 => "R_386_PC16",
+        R_386_8 => "R_386_8",
+        R_386_PC8 => "R_386_PC8",
+        R_386_TLS_GD_32 => "R_386_TLS_GD_32",
+        R_386_TLS_GD_PUSH => "R_386_TLS_GD_PUSH",
+        R_386_TLS_GD_CALL => "R_386_TLS_GD_CALL",
+        R_386_TLS_GD_POP => "R_386_TLS_GD_POP",
+        R_386_TLS_LDM_32 => "R_386_TLS_LDM_32",
+        R_386_TLS_LDM_PUSH => "R_386_TLS_LDM_PUSH",
+        R_386_TLS_LDM_CALL => "R_386_TLS_LDM_CALL",
+        R_386_TLS_LDM_POP => "R_386_TLS_LDM_POP",
+        R_386_TLS_LDO_32 => "R_386_TLS_LDO_32",
+        R_386_TLS_IE_32 => "R_386_TLS_IE_32",
+        R_386_TLS_LE_32 => "R_386_TLS_LE_32",
+        R_386_TLS_DTPMOD32 => "R_386_TLS_DTPMOD32",
+        R_386_TLS_DTPOFF32 => "R_386_TLS_DTPOFF32",
+        R_386_TLS_TPOFF32 => "R_386_TLS_TPOFF32",
+        R_386_TLS_GOTDESC => "R_386_TLS_GOTDESC",
+        R_386_TLS_DESC_CALL => "R_386_TLS_DESC_CALL",
+        R_386_TLS_DESC => "R_386_TLS_DESC",
+        R_386_IRELATIVE => "R_386_IRELATIVE",
+        R_386_GOT32X => "R_386_GOT32X",
+        _ => return None,
+    })
+}
+
+fn arm(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_ARM_NONE => "R_ARM_NONE",
+        R_ARM_PC24 => "R_ARM_PC24",
+        R_ARM_ABS32 => "R_ARM_ABS32",
+        R_ARM_REL32 => "R_ARM_REL32",
+        R_ARM_PC13 => "R_ARM_PC13",
+        R_ARM_ABS16 => "R_ARM_ABS16",
+        R_ARM_ABS12 => "R_ARM_ABS12",
+        R_ARM_THM_ABS5 => "R_ARM_THM_ABS5",
+        R_ARM_ABS8 => "R_ARM_ABS8",
+        R_ARM_SBREL32 => "R_ARM_SBREL32",
+        R_ARM_THM_PC22 => "R_ARM_THM_PC22",
+        R_ARM_THM_PC8 => "R_ARM_THM_PC8",
+        R_ARM_AMP_VCALL9 => "R_ARM_AMP_VCALL9",
+        R_ARM_SWI24 => "R_ARM_SWI24",
+        R_ARM_THM_SWI8 => "R_ARM_THM_SWI8",
+        R_ARM_XPC25 => "R_ARM_XPC25",
+        R_ARM_THM_XPC22 => "R_ARM_THM_XPC22",
+        R_ARM_TLS_DTPMOD32 => "R_ARM_TLS_DTPMOD32",
+        R_ARM_TLS_DTPOFF32 => "R_ARM_TLS_DTPOFF32",
+        R_ARM_TLS_TPOFF32 => "R_ARM_TLS_TPOFF32",
+        R_ARM_COPY => "R_ARM_COPY",
+        R_ARM_GLOB_DAT => "R_ARM_GLOB_DAT",
+        R_ARM_JUMP_SLOT => "R_ARM_JUMP_SLOT",
+        R_ARM_RELATIVE => "R_ARM_RELATIVE",
+        R_ARM_GOTOFF => "R_ARM_GOTOFF",
+        R_ARM_GOTPC => "R_ARM_GOTPC",
+        R_ARM_GOT32 => "R_ARM_GOT32",
+        R_ARM_PLT32 => "R_ARM_PLT32",
+        R_ARM_ALU_PCREL_7_0 => "R_ARM_ALU_PCREL_7_0",
+        R_ARM_ALU_PCREL_15_8 => "R_ARM_ALU_PCREL_15_8",
+        R_ARM_ALU_PCREL_23_15 => "R_ARM_ALU_PCREL_23_15",
+        R_ARM_LDR_SBREL_11_0 => "R_ARM_LDR_SBREL_11_0",
+        R_ARM_ALU_SBREL_19_12 => "R_ARM_ALU_SBREL_19_12",
+        R_ARM_ALU_SBREL_27_20 => "R_ARM_ALU_SBREL_27_20",
+        R_ARM_GNU_VTENTRY => "R_ARM_GNU_VTENTRY",
+        R_ARM_GNU_VTINHERIT => "R_ARM_GNU_VTINHERIT",
+        R_ARM_THM_PC11 => "R_ARM_THM_PC11",
+        R_ARM_THM_PC9 => "R_ARM_THM_PC9",
+        R_ARM_TLS_GD32 => "R_ARM_TLS_GD32",
+        R_ARM_TLS_LDM32 => "R_ARM_TLS_LDM32",
+        R_ARM_TLS_LDO32 => "R_ARM_TLS_LDO32",
+        R_ARM_TLS_IE32 => "R_ARM_TLS_IE32",
+        R_ARM_TLS_LE32 => "R_ARM_TLS_LE32",
+        R_ARM_RXPC25 => "R_ARM_RXPC25",
+        R_ARM_RSBREL32 => "R_ARM_RSBREL32",
+        R_ARM_THM_RPC22 => "R_ARM_THM_RPC22",
+        R_ARM_RREL32 => "R_ARM_RREL32",
+        R_ARM_RABS22 => "R_ARM_RABS22",
+        R_ARM_RPC24 => "R_ARM_RPC24",
+        R_ARM_RBASE => "R_ARM_RBASE",
+        _ => return None,
+    })
+}
+
+fn ppc(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_PPC_NONE => "R_PPC_NONE",
+        R_PPC_ADDR32 => "R_PPC_ADDR32",
+        R_PPC_ADDR24 => "R_PPC_ADDR24",
+        R_PPC_ADDR16 => "R_PPC_ADDR16",
+        R_PPC_ADDR16_LO => "R_PPC_ADDR16_LO",
+        R_PPC_ADDR16_HI => "R_PPC_ADDR16_HI",
+        R_PPC_ADDR16_HA => "R_PPC_ADDR16_HA",
+        R_PPC_ADDR14 => "R_PPC_ADDR14",
+        R_PPC_ADDR14_BRTAKEN => "R_PPC_ADDR14_BRTAKEN",
+        R_PPC_ADDR14_BRNTAKEN => "R_PPC_ADDR14_BRNTAKEN",
+        R_PPC_REL24 => "R_PPC_REL24",
+        R_PPC_REL14 => "R_PPC_REL14",
+        R_PPC_REL14_BRTAKEN => "R_PPC_REL14_BRTAKEN",
+        R_PPC_REL14_BRNTAKEN => "R_PPC_REL14_BRNTAKEN",
+        R_PPC_GOT16 => "R_PPC_GOT16",
+        R_PPC_GOT16_LO => "R_PPC_GOT16_LO",
+        R_PPC_GOT16_HI => "R_PPC_GOT16_HI",
+        R_PPC_GOT16_HA => "R_PPC_GOT16_HA",
+        R_PPC_PLTREL24 => "R_PPC_PLTREL24",
+        R_PPC_COPY => "R_PPC_COPY",
+        R_PPC_GLOB_DAT => "R_PPC_GLOB_DAT",
+        R_PPC_JMP_SLOT => "R_PPC_JMP_SLOT",
+        R_PPC_RELATIVE => "R_PPC_RELATIVE",
+        R_PPC_LOCAL24PC => "R_PPC_LOCAL24PC",
+        R_PPC_UADDR32 => "R_PPC_UADDR32",
+        R_PPC_UADDR16 => "R_PPC_UADDR16",
+        R_PPC_REL32 => "R_PPC_REL32",
+        R_PPC_PLT32 => "R_PPC_PLT32",
+        R_PPC_PLTREL32 => "R_PPC_PLTREL32",
+        R_PPC_PLT16_LO => "R_PPC_PLT16_LO",
+        R_PPC_PLT16_HI => "R_PPC_PLT16_HI",
+        R_PPC_PLT16_HA => "R_PPC_PLT16_HA",
+        R_PPC_SDAREL16 => "R_PPC_SDAREL16",
+        R_PPC_SECTOFF => "R_PPC_SECTOFF",
+        R_PPC_SECTOFF_LO => "R_PPC_SECTOFF_LO",
+        R_PPC_SECTOFF_HI => "R_PPC_SECTOFF_HI",
+        R_PPC_SECTOFF_HA => "R_PPC_SECTOFF_HA",
+        R_PPC_TLS => "R_PPC_TLS",
+        R_PPC_DTPMOD32 => "R_PPC_DTPMOD32",
+        R_PPC_TPREL16 => "R_PPC_TPREL16",
+        R_PPC_TPREL16_LO => "R_PPC_TPREL16_LO",
+        R_PPC_TPREL16_HI => "R_PPC_TPREL16_HI",
+        R_PPC_TPREL16_HA => "R_PPC_TPREL16_HA",
+        R_PPC_TPREL32 => "R_PPC_TPREL32",
+        R_PPC_DTPREL16 => "R_PPC_DTPREL16",
+        R_PPC_DTPREL16_LO => "R_PPC_DTPREL16_LO",
+        R_PPC_DTPREL16_HI => "R_PPC_DTPREL16_HI",
+        R_PPC_DTPREL16_HA => "R_PPC_DTPREL16_HA",
+        R_PPC_DTPREL32 => "R_PPC_DTPREL32",
+        R_PPC_GOT_TLSGD16 => "R_PPC_GOT_TLSGD16",
+        R_PPC_GOT_TLSGD16_LO => "R_PPC_GOT_TLSGD16_LO",
+        R_PPC_GOT_TLSGD16_HI => "R_PPC_GOT_TLSGD16_HI",
+        R_PPC_GOT_TLSGD16_HA => "R_PPC_GOT_TLSGD16_HA",
+        R_PPC_GOT_TLSLD16 => "R_PPC_GOT_TLSLD16",
+        R_PPC_GOT_TLSLD16_LO => "R_PPC_GOT_TLSLD16_LO",
+        R_PPC_GOT_TLSLD16_HI => "R_PPC_GOT_TLSLD16_HI",
+        R_PPC_GOT_TLSLD16_HA => "R_PPC_GOT_TLSLD16_HA",
+        R_PPC_GOT_TPREL16 => "R_PPC_GOT_TPREL16",
+        R_PPC_GOT_TPREL16_LO => "R_PPC_GOT_TPREL16_LO",
+        R_PPC_GOT_TPREL16_HI => "R_PPC_GOT_TPREL16_HI",
+        R_PPC_GOT_TPREL16_HA => "R_PPC_GOT_TPREL16_HA",
+        R_PPC_GOT_DTPREL16 => "R_PPC_GOT_DTPREL16",
+        R_PPC_GOT_DTPREL16_LO => "R_PPC_GOT_DTPREL16_LO",
+        R_PPC_GOT_DTPREL16_HI => "R_PPC_GOT_DTPREL16_HI",
+        R_PPC_GOT_DTPREL16_HA => "R_PPC_GOT_DTPREL16_HA",
+        R_PPC_EMB_NADDR32 => "R_PPC_EMB_NADDR32",
+        R_PPC_EMB_NADDR16 => "R_PPC_EMB_NADDR16",
+        R_PPC_EMB_NADDR16_LO => "R_PPC_EMB_NADDR16_LO",
+        R_PPC_EMB_NADDR16_HI => "R_PPC_EMB_NADDR16_HI",
+        R_PPC_EMB_NADDR16_HA => "R_PPC_EMB_NADDR16_HA",
+        R_PPC_EMB_SDAI16 => "R_PPC_EMB_SDAI16",
+        R_PPC_EMB_SDA2I16 => "R_PPC_EMB_SDA2I16",
+        R_PPC_EMB_SDA2REL => "R_PPC_EMB_SDA2REL",
+        R_PPC_EMB_SDA21 => "R_PPC_EMB_SDA21",
+        R_PPC_EMB_MRKREF => "R_PPC_EMB_MRKREF",
+        R_PPC_EMB_RELSEC16 => "R_PPC_EMB_RELSEC16",
+        R_PPC_EMB_RELST_LO => "R_PPC_EMB_RELST_LO",
+        R_PPC_EMB_RELST_HI => "R_PPC_EMB_RELST_HI",
+        R_PPC_EMB_RELST_HA => "R_PPC_EMB_RELST_HA",
+        R_PPC_EMB_BIT_FLD => "R_PPC_EMB_BIT_FLD",
+        R_PPC_EMB_RELSDA => "R_PPC_EMB_RELSDA",
+        R_PPC_DIAB_SDA21_LO => "R_PPC_DIAB_SDA21_LO",
+        R_PPC_DIAB_SDA21_HI => "R_PPC_DIAB_SDA21_HI",
+        R_PPC_DIAB_SDA21_HA => "R_PPC_DIAB_SDA21_HA",
+        R_PPC_DIAB_RELSDA_LO => "R_PPC_DIAB_RELSDA_LO",
+        R_PPC_DIAB_RELSDA_HI => "R_PPC_DIAB_RELSDA_HI",
+        R_PPC_DIAB_RELSDA_HA => "R_PPC_DIAB_RELSDA_HA",
+        R_PPC_IRELATIVE => "R_PPC_IRELATIVE",
+        R_PPC_REL16 => "R_PPC_REL16",
+        R_PPC_REL16_LO => "R_PPC_REL16_LO",
+        R_PPC_REL16_HI => "R_PPC_REL16_HI",
+        R_PPC_REL16_HA => "R_PPC_REL16_HA",
+        R_PPC_TOC16 => "R_PPC_TOC16",
+        _ => return None,
+    })
+}
+
+fn ppc64(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_PPC64_NONE => "R_PPC64_NONE",
+        R_PPC64_ADDR32 => "R_PPC64_ADDR32",
+        R_PPC64_ADDR24 => "R_PPC64_ADDR24",
+        R_PPC64_ADDR16 => "R_PPC64_ADDR16",
+        R_PPC64_ADDR16_LO => "R_PPC64_ADDR16_LO",
+        R_PPC64_ADDR16_HI => "R_PPC64_ADDR16_HI",
+        R_PPC64_ADDR16_HA => "R_PPC64_ADDR16_HA",
+        R_PPC64_ADDR14 => "R_PPC64_ADDR14",
+        R_PPC64_ADDR14_BRTAKEN => "R_PPC64_ADDR14_BRTAKEN",
+        R_PPC64_ADDR14_BRNTAKEN => "R_PPC64_ADDR14_BRNTAKEN",
+        R_PPC64_REL24 => "R_PPC64_REL24",
+        R_PPC64_REL14 => "R_PPC64_REL14",
+        R_PPC64_REL14_BRTAKEN => "R_PPC64_REL14_BRTAKEN",
+        R_PPC64_REL14_BRNTAKEN => "R_PPC64_REL14_BRNTAKEN",
+        R_PPC64_GOT16 => "R_PPC64_GOT16",
+        R_PPC64_GOT16_LO => "R_PPC64_GOT16_LO",
+        R_PPC64_GOT16_HI => "R_PPC64_GOT16_HI",
+        R_PPC64_GOT16_HA => "R_PPC64_GOT16_HA",
+        R_PPC64_COPY => "R_PPC64_COPY",
+        R_PPC64_GLOB_DAT => "R_PPC64_GLOB_DAT",
+        R_PPC64_JMP_SLOT => "R_PPC64_JMP_SLOT",
+        R_PPC64_RELATIVE => "R_PPC64_RELATIVE",
+        R_PPC64_UADDR32 => "R_PPC64_UADDR32",
+        R_PPC64_UADDR16 => "R_PPC64_UADDR16",
+        R_PPC64_REL32 => "R_PPC64_REL32",
+        R_PPC64_PLT32 => "R_PPC64_PLT32",
+        R_PPC64_PLTREL32 => "R_PPC64_PLTREL32",
+        R_PPC64_PLT16_LO => "R_PPC64_PLT16_LO",
+        R_PPC64_PLT16_HI => "R_PPC64_PLT16_HI",
+        R_PPC64_PLT16_HA => "R_PPC64_PLT16_HA",
+        R_PPC64_SECTOFF => "R_PPC64_SECTOFF",
+        R_PPC64_SECTOFF_LO => "R_PPC64_SECTOFF_LO",
+        R_PPC64_SECTOFF_HI => "R_PPC64_SECTOFF_HI",
+        R_PPC64_SECTOFF_HA => "R_PPC64_SECTOFF_HA",
+        R_PPC64_ADDR30 => "R_PPC64_ADDR30",
+        R_PPC64_ADDR64 => "R_PPC64_ADDR64",
+        R_PPC64_ADDR16_HIGHER => "R_PPC64_ADDR16_HIGHER",
+        R_PPC64_ADDR16_HIGHERA => "R_PPC64_ADDR16_HIGHERA",
+        R_PPC64_ADDR16_HIGHEST => "R_PPC64_ADDR16_HIGHEST",
+        R_PPC64_ADDR16_HIGHESTA => "R_PPC64_ADDR16_HIGHESTA",
+        R_PPC64_UADDR64 => "R_PPC64_UADDR64",
+        R_PPC64_REL64 => "R_PPC64_REL64",
+        R_PPC64_PLT64 => "R_PPC64_PLT64",
+        R_PPC64_PLTREL64 => "R_PPC64_PLTREL64",
+        R_PPC64_TOC16 => "R_PPC64_TOC16",
+        R_PPC64_TOC16_LO => "R_PPC64_TOC16_LO",
+        R_PPC64_TOC16_HI => "R_PPC64_TOC16_HI",
+        R_PPC64_TOC16_HA => "R_PPC64_TOC16_HA",
+        R_PPC64_TOC => "R_PPC64_TOC",
+        R_PPC64_PLTGOT16 => "R_PPC64_PLTGOT16",
+        R_PPC64_PLTGOT16_LO => "R_PPC64_PLTGOT16_LO",
+        R_PPC64_PLTGOT16_HI => "R_PPC64_PLTGOT16_HI",
+        R_PPC64_PLTGOT16_HA => "R_PPC64_PLTGOT16_HA",
+        R_PPC64_ADDR16_DS => "R_PPC64_ADDR16_DS",
+        R_PPC64_ADDR16_LO_DS => "R_PPC64_ADDR16_LO_DS",
+        R_PPC64_GOT16_DS => "R_PPC64_GOT16_DS",
+        R_PPC64_GOT16_LO_DS => "R_PPC64_GOT16_LO_DS",
+        R_PPC64_PLT16_LO_DS => "R_PPC64_PLT16_LO_DS",
+        R_PPC64_SECTOFF_DS => "R_PPC64_SECTOFF_DS",
+        R_PPC64_SECTOFF_LO_DS => "R_PPC64_SECTOFF_LO_DS",
+        R_PPC64_TOC16_DS => "R_PPC64_TOC16_DS",
+        R_PPC64_TOC16_LO_DS => "R_PPC64_TOC16_LO_DS",
+        R_PPC64_PLTGOT16_DS => "R_PPC64_PLTGOT16_DS",
+        R_PPC64_PLTGOT16_LO_DS => "R_PPC64_PLTGOT16_LO_DS",
+        R_PPC64_TLS => "R_PPC64_TLS",
+        R_PPC64_DTPMOD64 => "R_PPC64_DTPMOD64",
+        R_PPC64_TPREL16 => "R_PPC64_TPREL16",
+        R_PPC64_TPREL16_LO => "R_PPC64_TPREL16_LO",
+        R_PPC64_TPREL16_HI => "R_PPC64_TPREL16_HI",
+        R_PPC64_TPREL16_HA => "R_PPC64_TPREL16_HA",
+        R_PPC64_TPREL64 => "R_PPC64_TPREL64",
+        R_PPC64_DTPREL16 => "R_PPC64_DTPREL16",
+        R_PPC64_DTPREL16_LO => "R_PPC64_DTPREL16_LO",
+        R_PPC64_DTPREL16_HI => "R_PPC64_DTPREL16_HI",
+        R_PPC64_DTPREL16_HA => "R_PPC64_DTPREL16_HA",
+        R_PPC64_DTPREL64 => "R_PPC64_DTPREL64",
+        R_PPC64_GOT_TLSGD16 => "R_PPC64_GOT_TLSGD16",
+        R_PPC64_GOT_TLSGD16_LO => "R_PPC64_GOT_TLSGD16_LO",
+        R_PPC64_GOT_TLSGD16_HI => "R_PPC64_GOT_TLSGD16_HI",
+        R_PPC64_GOT_TLSGD16_HA => "R_PPC64_GOT_TLSGD16_HA",
+        R_PPC64_GOT_TLSLD16 => "R_PPC64_GOT_TLSLD16",
+        R_PPC64_GOT_TLSLD16_LO => "R_PPC64_GOT_TLSLD16_LO",
+        R_PPC64_GOT_TLSLD16_HI => "R_PPC64_GOT_TLSLD16_HI",
+        R_PPC64_GOT_TLSLD16_HA => "R_PPC64_GOT_TLSLD16_HA",
+        R_PPC64_GOT_TPREL16_DS => "R_PPC64_GOT_TPREL16_DS",
+        R_PPC64_GOT_TPREL16_LO_DS => "R_PPC64_GOT_TPREL16_LO_DS",
+        R_PPC64_GOT_TPREL16_HI => "R_PPC64_GOT_TPREL16_HI",
+        R_PPC64_GOT_TPREL16_HA => "R_PPC64_GOT_TPREL16_HA",
+        R_PPC64_GOT_DTPREL16_DS => "R_PPC64_GOT_DTPREL16_DS",
+        R_PPC64_GOT_DTPREL16_LO_DS => "R_PPC64_GOT_DTPREL16_LO_DS",
+        R_PPC64_GOT_DTPREL16_HI => "R_PPC64_GOT_DTPREL16_HI",
+        R_PPC64_GOT_DTPREL16_HA => "R_PPC64_GOT_DTPREL16_HA",
+        R_PPC64_TPREL16_DS => "R_PPC64_TPREL16_DS",
+        R_PPC64_TPREL16_LO_DS => "R_PPC64_TPREL16_LO_DS",
+        R_PPC64_TPREL16_HIGHER => "R_PPC64_TPREL16_HIGHER",
+        R_PPC64_TPREL16_HIGHERA => "R_PPC64_TPREL16_HIGHERA",
+        R_PPC64_TPREL16_HIGHEST => "R_PPC64_TPREL16_HIGHEST",
+        R_PPC64_TPREL16_HIGHESTA => "R_PPC64_TPREL16_HIGHESTA",
+        R_PPC64_DTPREL16_DS => "R_PPC64_DTPREL16_DS",
+        R_PPC64_DTPREL16_LO_DS => "R_PPC64_DTPREL16_LO_DS",
+        R_PPC64_DTPREL16_HIGHER => "R_PPC64_DTPREL16_HIGHER",
+        R_PPC64_DTPREL16_HIGHERA => "R_PPC64_DTPREL16_HIGHERA",
+        R_PPC64_DTPREL16_HIGHEST => "R_PPC64_DTPREL16_HIGHEST",
+        R_PPC64_DTPREL16_HIGHESTA => "R_PPC64_DTPREL16_HIGHESTA",
+        R_PPC64_JMP_IREL => "R_PPC64_JMP_IREL",
+        R_PPC64_IRELATIVE => "R_PPC64_IRELATIVE",
+        R_PPC64_REL16 => "R_PPC64_REL16",
+        R_PPC64_REL16_LO => "R_PPC64_REL16_LO",
+        R_PPC64_REL16_HI => "R_PPC64_REL16_HI",
+        R_PPC64_REL16_HA => "R_PPC64_REL16_HA",
+        _ => return None,
+    })
+}
+
+fn sparc(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_SPARC_NONE => "R_SPARC_NONE",
+        R_SPARC_8 => "R_SPARC_8",
+        R_SPARC_16 => "R_SPARC_16",
+        R_SPARC_32 => "R_SPARC_32",
+        R_SPARC_DISP8 => "R_SPARC_DISP8",
+        R_SPARC_DISP16 => "R_SPARC_DISP16",
+        R_SPARC_DISP32 => "R_SPARC_DISP32",
+        R_SPARC_WDISP30 => "R_SPARC_WDISP30",
+        R_SPARC_WDISP22 => "R_SPARC_WDISP22",
+        R_SPARC_HI22 => "R_SPARC_HI22",
+        R_SPARC_22 => "R_SPARC_22",
+        R_SPARC_13 => "R_SPARC_13",
+        R_SPARC_LO10 => "R_SPARC_LO10",
+        R_SPARC_GOT10 => "R_SPARC_GOT10",
+        R_SPARC_GOT13 => "R_SPARC_GOT13",
+        R_SPARC_GOT22 => "R_SPARC_GOT22",
+        R_SPARC_PC10 => "R_SPARC_PC10",
+        R_SPARC_PC22 => "R_SPARC_PC22",
+        R_SPARC_WPLT30 => "R_SPARC_WPLT30",
+        R_SPARC_COPY => "R_SPARC_COPY",
+        R_SPARC_GLOB_DAT => "R_SPARC_GLOB_DAT",
+        R_SPARC_JMP_SLOT => "R_SPARC_JMP_SLOT",
+        R_SPARC_RELATIVE => "R_SPARC_RELATIVE",
+        R_SPARC_UA32 => "R_SPARC_UA32",
+        R_SPARC_PLT32 => "R_SPARC_PLT32",
+        R_SPARC_HIPLT22 => "R_SPARC_HIPLT22",
+        R_SPARC_LOPLT10 => "R_SPARC_LOPLT10",
+        R_SPARC_PCPLT32 => "R_SPARC_PCPLT32",
+        R_SPARC_PCPLT22 => "R_SPARC_PCPLT22",
+        R_SPARC_PCPLT10 => "R_SPARC_PCPLT10",
+        R_SPARC_10 => "R_SPARC_10",
+        R_SPARC_11 => "R_SPARC_11",
+        R_SPARC_64 => "R_SPARC_64",
+        R_SPARC_OLO10 => "R_SPARC_OLO10",
+        R_SPARC_HH22 => "R_SPARC_HH22",
+        R_SPARC_HM10 => "R_SPARC_HM10",
+        R_SPARC_LM22 => "R_SPARC_LM22",
+        R_SPARC_PC_HH22 => "R_SPARC_PC_HH22",
+        R_SPARC_PC_HM10 => "R_SPARC_PC_HM10",
+        R_SPARC_PC_LM22 => "R_SPARC_PC_LM22",
+        R_SPARC_WDISP16 => "R_SPARC_WDISP16",
+        R_SPARC_WDISP19 => "R_SPARC_WDISP19",
+        R_SPARC_GLOB_JMP => "R_SPARC_GLOB_JMP",
+        R_SPARC_7 => "R_SPARC_7",
+        R_SPARC_5 => "R_SPARC_5",
+        R_SPARC_6 => "R_SPARC_6",
+        R_SPARC_DISP64 => "R_SPARC_DISP64",
+        R_SPARC_PLT64 => "R_SPARC_PLT64",
+        R_SPARC_HIX22 => "R_SPARC_HIX22",
+        R_SPARC_LOX10 => "R_SPARC_LOX10",
+        R_SPARC_H44 => "R_SPARC_H44",
+        R_SPARC_M44 => "R_SPARC_M44",
+        R_SPARC_L44 => "R_SPARC_L44",
+        R_SPARC_REGISTER => "R_SPARC_REGISTER",
+        R_SPARC_UA64 => "R_SPARC_UA64",
+        R_SPARC_UA16 => "R_SPARC_UA16",
+        R_SPARC_TLS_GD_HI22 => "R_SPARC_TLS_GD_HI22",
+        R_SPARC_TLS_GD_LO10 => "R_SPARC_TLS_GD_LO10",
+        R_SPARC_TLS_GD_ADD => "R_SPARC_TLS_GD_ADD",
+        R_SPARC_TLS_GD_CALL => "R_SPARC_TLS_GD_CALL",
+        R_SPARC_TLS_LDM_HI22 => "R_SPARC_TLS_LDM_HI22",
+        R_SPARC_TLS_LDM_LO10 => "R_SPARC_TLS_LDM_LO10",
+        R_SPARC_TLS_LDM_ADD => "R_SPARC_TLS_LDM_ADD",
+        R_SPARC_TLS_LDM_CALL => "R_SPARC_TLS_LDM_CALL",
+        R_SPARC_TLS_LDO_HIX22 => "R_SPARC_TLS_LDO_HIX22",
+        R_SPARC_TLS_LDO_LOX10 => "R_SPARC_TLS_LDO_LOX10",
+        R_SPARC_TLS_LDO_ADD => "R_SPARC_TLS_LDO_ADD",
+        R_SPARC_TLS_IE_HI22 => "R_SPARC_TLS_IE_HI22",
+        R_SPARC_TLS_IE_LO10 => "R_SPARC_TLS_IE_LO10",
+        R_SPARC_TLS_IE_LD => "R_SPARC_TLS_IE_LD",
+        R_SPARC_TLS_IE_LDX => "R_SPARC_TLS_IE_LDX",
+        R_SPARC_TLS_IE_ADD => "R_SPARC_TLS_IE_ADD",
+        R_SPARC_TLS_LE_HIX22 => "R_SPARC_TLS_LE_HIX22",
+        R_SPARC_TLS_LE_LOX10 => "R_SPARC_TLS_LE_LOX10",
+        R_SPARC_TLS_DTPMOD32 => "R_SPARC_TLS_DTPMOD32",
+        R_SPARC_TLS_DTPMOD64 => "R_SPARC_TLS_DTPMOD64",
+        R_SPARC_TLS_DTPOFF32 => "R_SPARC_TLS_DTPOFF32",
+        R_SPARC_TLS_DTPOFF64 => "R_SPARC_TLS_DTPOFF64",
+        R_SPARC_TLS_TPOFF32 => "R_SPARC_TLS_TPOFF32",
+        R_SPARC_TLS_TPOFF64 => "R_SPARC_TLS_TPOFF64",
+        R_SPARC_GOTDATA_HIX22 => "R_SPARC_GOTDATA_HIX22",
+        R_SPARC_GOTDATA_LOX10 => "R_SPARC_GOTDATA_LOX10",
+        R_SPARC_GOTDATA_OP_HIX22 => "R_SPARC_GOTDATA_OP_HIX22",
+        R_SPARC_GOTDATA_OP_LOX10 => "R_SPARC_GOTDATA_OP_LOX10",
+        R_SPARC_GOTDATA_OP => "R_SPARC_GOTDATA_OP",
+        R_SPARC_H34 => "R_SPARC_H34",
+        R_SPARC_SIZE32 => "R_SPARC_SIZE32",
+        R_SPARC_SIZE64 => "R_SPARC_SIZE64",
+        R_SPARC_GNU_VTINHERIT => "R_SPARC_GNU_VTINHERIT",
+        R_SPARC_GNU_VTENTRY => "R_SPARC_GNU_VTENTRY",
+        R_SPARC_REV32 => "R_SPARC_REV32",
+        _ => return None,
+    })
+}
+
+fn s390(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_390_NONE => "R_390_NONE",
+        R_390_8 => "R_390_8",
+        R_390_12 => "R_390_12",
+        R_390_16 => "R_390_16",
+        R_390_32 => "R_390_32",
+        R_390_PC32 => "R_390_PC32",
+        R_390_GOT12 => "R_390_GOT12",
+        R_390_GOT32 => "R_390_GOT32",
+        R_390_PLT32 => "R_390_PLT32",
+        R_390_COPY => "R_390_COPY",
+        R_390_GLOB_DAT => "R_390_GLOB_DAT",
+        R_390_JMP_SLOT => "R_390_JMP_SLOT",
+        R_390_RELATIVE => "R_390_RELATIVE",
+        R_390_GOTOFF32 => "R_390_GOTOFF32",
+        R_390_GOTPC => "R_390_GOTPC",
+        R_390_GOT16 => "R_390_GOT16",
+        R_390_PC16 => "R_390_PC16",
+        R_390_PC16DBL => "R_390_PC16DBL",
+        R_390_PLT16DBL => "R_390_PLT16DBL",
+        R_390_PC32DBL => "R_390_PC32DBL",
+        R_390_PLT32DBL => "R_390_PLT32DBL",
+        R_390_GOTPCDBL => "R_390_GOTPCDBL",
+        R_390_64 => "R_390_64",
+        R_390_PC64 => "R_390_PC64",
+        R_390_GOT64 => "R_390_GOT64",
+        R_390_PLT64 => "R_390_PLT64",
+        R_390_GOTENT => "R_390_GOTENT",
+        R_390_GOTOFF16 => "R_390_GOTOFF16",
+        R_390_GOTOFF64 => "R_390_GOTOFF64",
+        R_390_GOTPLT12 => "R_390_GOTPLT12",
+        R_390_GOTPLT16 => "R_390_GOTPLT16",
+        R_390_GOTPLT32 => "R_390_GOTPLT32",
+        R_390_GOTPLT64 => "R_390_GOTPLT64",
+        R_390_GOTPLTENT => "R_390_GOTPLTENT",
+        R_390_PLTOFF16 => "R_390_PLTOFF16",
+        R_390_PLTOFF32 => "R_390_PLTOFF32",
+        R_390_PLTOFF64 => "R_390_PLTOFF64",
+        R_390_TLS_LOAD => "R_390_TLS_LOAD",
+        R_390_TLS_GDCALL => "R_390_TLS_GDCALL",
+        R_390_TLS_LDCALL => "R_390_TLS_LDCALL",
+        R_390_TLS_GD32 => "R_390_TLS_GD32",
+        R_390_TLS_GD64 => "R_390_TLS_GD64",
+        R_390_TLS_GOTIE12 => "R_390_TLS_GOTIE12",
+        R_390_TLS_GOTIE32 => "R_390_TLS_GOTIE32",
+        R_390_TLS_GOTIE64 => "R_390_TLS_GOTIE64",
+        R_390_TLS_LDM32 => "R_390_TLS_LDM32",
+        R_390_TLS_LDM64 => "R_390_TLS_LDM64",
+        R_390_TLS_IE32 => "R_390_TLS_IE32",
+        R_390_TLS_IE64 => "R_390_TLS_IE64",
+        R_390_TLS_IEENT => "R_390_TLS_IEENT",
+        R_390_TLS_LE32 => "R_390_TLS_LE32",
+        R_390_TLS_LE64 => "R_390_TLS_LE64",
+        R_390_TLS_LDO32 => "R_390_TLS_LDO32",
+        R_390_TLS_LDO64 => "R_390_TLS_LDO64",
+        R_390_TLS_DTPMOD => "R_390_TLS_DTPMOD",
+        R_390_TLS_DTPOFF => "R_390_TLS_DTPOFF",
+        R_390_TLS_TPOFF => "R_390_TLS_TPOFF",
+        R_390_20 => "R_390_20",
+        R_390_GOT20 => "R_390_GOT20",
+        R_390_GOTPLT20 => "R_390_GOTPLT20",
+        R_390_TLS_GOTIE20 => "R_390_TLS_GOTIE20",
+        _ => return None,
+    })
+}
+
+fn m68k(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_68K_NONE => "R_68K_NONE",
+        R_68K_32 => "R_68K_32",
+        R_68K_16 => "R_68K_16",
+        R_68K_8 => "R_68K_8",
+        R_68K_PC32 => "R_68K_PC32",
+        R_68K_PC16 => "R_68K_PC16",
+        R_68K_PC8 => "R_68K_PC8",
+        R_68K_GOT32 => "R_68K_GOT32",
+        R_68K_GOT16 => "R_68K_GOT16",
+        R_68K_GOT8 => "R_68K_GOT8",
+        R_68K_GOT32O => "R_68K_GOT32O",
+        R_68K_GOT16O => "R_68K_GOT16O",
+        R_68K_GOT8O => "R_68K_GOT8O",
+        R_68K_PLT32 => "R_68K_PLT32",
+        R_68K_PLT16 => "R_68K_PLT16",
+        R_68K_PLT8 => "R_68K_PLT8",
+        R_68K_PLT32O => "R_68K_PLT32O",
+        R_68K_PLT16O => "R_68K_PLT16O",
+        R_68K_PLT8O => "R_68K_PLT8O",
+        R_68K_COPY => "R_68K_COPY",
+        R_68K_GLOB_DAT => "R_68K_GLOB_DAT",
+        R_68K_JMP_SLOT => "R_68K_JMP_SLOT",
+        R_68K_RELATIVE => "R_68K_RELATIVE",
+        R_68K_TLS_GD32 => "R_68K_TLS_GD32",
+        R_68K_TLS_GD16 => "R_68K_TLS_GD16",
+        R_68K_TLS_GD8 => "R_68K_TLS_GD8",
+        R_68K_TLS_LDM32 => "R_68K_TLS_LDM32",
+        R_68K_TLS_LDM16 => "R_68K_TLS_LDM16",
+        R_68K_TLS_LDM8 => "R_68K_TLS_LDM8",
+        R_68K_TLS_LDO32 => "R_68K_TLS_LDO32",
+        R_68K_TLS_LDO16 => "R_68K_TLS_LDO16",
+        R_68K_TLS_LDO8 => "R_68K_TLS_LDO8",
+        R_68K_TLS_IE32 => "R_68K_TLS_IE32",
+        R_68K_TLS_IE16 => "R_68K_TLS_IE16",
+        R_68K_TLS_IE8 => "R_68K_TLS_IE8",
+        R_68K_TLS_LE32 => "R_68K_TLS_LE32",
+        R_68K_TLS_LE16 => "R_68K_TLS_LE16",
+        R_68K_TLS_LE8 => "R_68K_TLS_LE8",
+        R_68K_TLS_DTPMOD32 => "R_68K_TLS_DTPMOD32",
+        R_68K_TLS_DTPREL32 => "R_68K_TLS_DTPREL32",
+        R_68K_TLS_TPREL32 => "R_68K_TLS_TPREL32",
+        _ => return None,
+    })
+}
+
+fn alpha(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_ALPHA_NONE => "R_ALPHA_NONE",
+        R_ALPHA_REFLONG => "R_ALPHA_REFLONG",
+        R_ALPHA_REFQUAD => "R_ALPHA_REFQUAD",
+        R_ALPHA_GPREL32 => "R_ALPHA_GPREL32",
+        R_ALPHA_LITERAL => "R_ALPHA_LITERAL",
+        R_ALPHA_LITUSE => "R_ALPHA_LITUSE",
+        R_ALPHA_GPDISP => "R_ALPHA_GPDISP",
+        R_ALPHA_BRADDR => "R_ALPHA_BRADDR",
+        R_ALPHA_HINT => "R_ALPHA_HINT",
+        R_ALPHA_SREL16 => "R_ALPHA_SREL16",
+        R_ALPHA_SREL32 => "R_ALPHA_SREL32",
+        R_ALPHA_SREL64 => "R_ALPHA_SREL64",
+        R_ALPHA_GPRELHIGH => "R_ALPHA_GPRELHIGH",
+        R_ALPHA_GPRELLOW => "R_ALPHA_GPRELLOW",
+        R_ALPHA_GPREL16 => "R_ALPHA_GPREL16",
+        R_ALPHA_COPY => "R_ALPHA_COPY",
+        R_ALPHA_GLOB_DAT => "R_ALPHA_GLOB_DAT",
+        R_ALPHA_JMP_SLOT => "R_ALPHA_JMP_SLOT",
+        R_ALPHA_RELATIVE => "R_ALPHA_RELATIVE",
+        R_ALPHA_TLS_GD_HI => "R_ALPHA_TLS_GD_HI",
+        R_ALPHA_TLSGD => "R_ALPHA_TLSGD",
+        R_ALPHA_TLS_LDM => "R_ALPHA_TLS_LDM",
+        R_ALPHA_DTPMOD64 => "R_ALPHA_DTPMOD64",
+        R_ALPHA_GOTDTPREL => "R_ALPHA_GOTDTPREL",
+        R_ALPHA_DTPREL64 => "R_ALPHA_DTPREL64",
+        R_ALPHA_DTPRELHI => "R_ALPHA_DTPRELHI",
+        R_ALPHA_DTPRELLO => "R_ALPHA_DTPRELLO",
+        R_ALPHA_DTPREL16 => "R_ALPHA_DTPREL16",
+        R_ALPHA_GOTTPREL => "R_ALPHA_GOTTPREL",
+        R_ALPHA_TPREL64 => "R_ALPHA_TPREL64",
+        R_ALPHA_TPRELHI => "R_ALPHA_TPRELHI",
+        R_ALPHA_TPRELLO => "R_ALPHA_TPRELLO",
+        R_ALPHA_TPREL16 => "R_ALPHA_TPREL16",
+        _ => return None,
+    })
+}
+
+fn cris(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_CRIS_NONE => "R_CRIS_NONE",
+        R_CRIS_8 => "R_CRIS_8",
+        R_CRIS_16 => "R_CRIS_16",
+        R_CRIS_32 => "R_CRIS_32",
+        R_CRIS_8_PCREL => "R_CRIS_8_PCREL",
+        R_CRIS_16_PCREL => "R_CRIS_16_PCREL",
+        R_CRIS_32_PCREL => "R_CRIS_32_PCREL",
+        R_CRIS_GNU_VTINHERIT => "R_CRIS_GNU_VTINHERIT",
+        R_CRIS_GNU_VTENTRY => "R_CRIS_GNU_VTENTRY",
+        R_CRIS_COPY => "R_CRIS_COPY",
+        R_CRIS_GLOB_DAT => "R_CRIS_GLOB_DAT",
+        R_CRIS_JUMP_SLOT => "R_CRIS_JUMP_SLOT",
+        R_CRIS_RELATIVE => "R_CRIS_RELATIVE",
+        R_CRIS_16_GOT => "R_CRIS_16_GOT",
+        R_CRIS_32_GOT => "R_CRIS_32_GOT",
+        R_CRIS_16_GOTPLT => "R_CRIS_16_GOTPLT",
+        R_CRIS_32_GOTPLT => "R_CRIS_32_GOTPLT",
+        R_CRIS_32_GOTREL => "R_CRIS_32_GOTREL",
+        R_CRIS_32_PLT_GOTREL => "R_CRIS_32_PLT_GOTREL",
+        R_CRIS_32_PLT_PCREL => "R_CRIS_32_PLT_PCREL",
+        _ => return None,
+    })
+}
+
+fn ia64(r#type: u32) -> Option<&'static str> {
+    Some(match r#type {
+        R_IA64_NONE => "R_IA64_NONE",
+        R_IA64_IMM14 => "R_IA64_IMM14",
+        R_IA64_IMM22 => "R_IA64_IMM22",
+        R_IA64_IMM64 => "R_IA64_IMM64",
+        R_IA64_DIR32MSB => "R_IA64_DIR32MSB",
+        R_IA64_DIR32LSB => "R_IA64_DIR32LSB",
+        R_IA64_DIR64MSB => "R_IA64_DIR64MSB",
+        R_IA64_DIR64LSB => "R_IA64_DIR64LSB",
+        R_IA64_GPREL22 => "R_IA64_GPREL22",
+        R_IA64_GPREL64I => "R_IA64_GPREL64I",
+        R_IA64_GPREL32MSB => "R_IA64_GPREL32MSB",
+        R_IA64_GPREL32LSB => "R_IA64_GPREL32LSB",
+        R_IA64_GPREL64MSB => "R_IA64_GPREL64MSB",
+        R_IA64_GPREL64LSB => "R_IA64_GPREL64LSB",
+        R_IA64_LTOFF22 => "R_IA64_LTOFF22",
+        R_IA64_LTOFF64I => "R_IA64_LTOFF64I",
+        R_IA64_PLTOFF22 => "R_IA64_PLTOFF22",
+        R_IA64_PLTOFF64I => "R_IA64_PLTOFF64I",
+        R_IA64_PLTOFF64MSB => "R_IA64_PLTOFF64MSB",
+        R_IA64_PLTOFF64LSB => "R_IA64_PLTOFF64LSB",
+        R_IA64_FPTR64I => "R_IA64_FPTR64I",
+        R_IA64_FPTR32MSB => "R_IA64_FPTR32MSB",
+        R_IA64_FPTR32LSB => "R_IA64_FPTR32LSB",
+        R_IA64_FPTR64MSB => "R_IA64_FPTR64MSB",
+        R_IA64_FPTR64LSB => "R_IA64_FPTR64LSB",
+        R_IA64_PCREL60B => "R_IA64_PCREL60B",
+        R_IA64_PCREL21B => "R_IA64_PCREL21B",
+        R_IA64_PCREL21M => "R_IA64_PCREL21M",
+        R_IA64_PCREL21F => "R_IA64_PCREL21F",
+        R_IA64_PCREL32MSB => "R_IA64_PCREL32MSB",
+        R_IA64_PCREL32LSB => "R_IA64_PCREL32LSB",
+        R_IA64_PCREL64MSB => "R_IA64_PCREL64MSB",
+        R_IA64_PCREL64LSB => "R_IA64_PCREL64LSB",
+        R_IA64_LTOFF_FPTR22 => "R_IA64_LTOFF_FPTR22",
+        R_IA64_LTOFF_FPTR64I => "R_IA64_LTOFF_FPTR64I",
+        R_IA64_LTOFF_FPTR32MSB => "R_IA64_LTOFF_FPTR32MSB",
+        R_IA64_LTOFF_FPTR32LSB => "R_IA64_LTOFF_FPTR32LSB",
+        R_IA64_LTOFF_FPTR64MSB => "R_IA64_LTOFF_FPTR64MSB",
+        R_IA64_LTOFF_FPTR64LSB => "R_IA64_LTOFF_FPTR64LSB",
+        R_IA64_SEGREL32MSB => "R_IA64_SEGREL32MSB",
+        R_IA64_SEGREL32LSB => "R_IA64_SEGREL32LSB",
+        R_IA64_SEGREL64MSB => "R_IA64_SEGREL64MSB",
+        R_IA64_SEGREL64LSB => "R_IA64_SEGREL64LSB",
+        R_IA64_SECREL32MSB => "R_IA64_SECREL32MSB",
+        R_IA64_SECREL32LSB => "R_IA64_SECREL32LSB",
+        R_IA64_SECREL64MSB => "R_IA64_SECREL64MSB",
+        R_IA64_SECREL64LSB => "R_IA64_SECREL64LSB",
+        R_IA64_REL32MSB => "R_IA64_REL32MSB",
+        R_IA64_REL32LSB => "R_IA64_REL32LSB",
+        R_IA64_REL64MSB => "R_IA64_REL64MSB",
+        R_IA64_REL64LSB => "R_IA64_REL64LSB",
+        R_IA64_LTV32MSB => "R_IA64_LTV32MSB",
+        R_IA64_LTV32LSB => "R_IA64_LTV32LSB",
+        R_IA64_LTV64MSB => "R_IA64_LTV64MSB",
+        R_IA64_LTV64LSB => "R_IA64_LTV64LSB",
+        R_IA64_PCREL21BI => "R_IA64_PCREL21BI",
+        R_IA64_PCREL22 => "R_IA64_PCREL22",
+        R_IA64_PCREL64I => "R_IA64_PCREL64I",
+        R_IA64_IPLTMSB => "R_IA64_IPLTMSB",
+        R_IA64_IPLTLSB => "R_IA64_IPLTLSB",
+        R_IA64_COPY => "R_IA64_COPY",
+        R_IA64_SUB => "R_IA64_SUB",
+        R_IA64_LTOFF22X => "R_IA64_LTOFF22X",
+        R_IA64_LDXMOV => "R_IA64_LDXMOV",
+        R_IA64_TPREL14 => "R_IA64_TPREL14",
+        R_IA64_TPREL22 => "R_IA64_TPREL22",
+        R_IA64_TPREL64I => "R_IA64_TPREL64I",
+        R_IA64_TPREL64MSB => "R_IA64_TPREL64MSB",
+        R_IA64_TPREL64LSB => "R_IA64_TPREL64LSB",
+        R_IA64_LTOFF_TPREL22 => "R_IA64_LTOFF_TPREL22",
+        R_IA64_DTPMOD64MSB => "R_IA64_DTPMOD64MSB",
+        R_IA64_DTPMOD64LSB => "R_IA64_DTPMOD64LSB",
+        R_IA64_LTOFF_DTPMOD22 => "R_IA64_LTOFF_DTPMOD22",
+        R_IA64_DTPREL14 => "R_IA64_DTPREL14",
+        R_IA64_DTPREL22 => "R_IA64_DTPREL22",
+        R_IA64_DTPREL64I => "R_IA64_DTPREL64I",
+        R_IA64_DTPREL32MSB => "R_IA64_DTPREL32MSB",
+        R_IA64_DTPREL32LSB => "R_IA64_DTPREL32LSB",
+        R_IA64_DTPREL64MSB => "R_IA64_DTPREL64MSB",
+        R_IA64_DTPREL64LSB => "R_IA64_DTPREL64LSB",
+        R_IA64_LTOFF_DTPREL22 => "R_IA64_LTOFF_DTPREL22",
+        _ => return None,
+    })
+}
+
+fn m32r(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_M32R_NONE => "R_M32R_NONE",
+        R_M32R_16 => "R_M32R_16",
+        R_M32R_32 => "R_M32R_32",
+        R_M32R_24 => "R_M32R_24",
+        R_M32R_10_PCREL => "R_M32R_10_PCREL",
+        R_M32R_18_PCREL => "R_M32R_18_PCREL",
+        R_M32R_26_PCREL => "R_M32R_26_PCREL",
+        R_M32R_HI16_ULO => "R_M32R_HI16_ULO",
+        R_M32R_HI16_SLO => "R_M32R_HI16_SLO",
+        R_M32R_LO16 => "R_M32R_LO16",
+        R_M32R_SDA16 => "R_M32R_SDA16",
+        R_M32R_GNU_VTINHERIT => "R_M32R_GNU_VTINHERIT",
+        R_M32R_GNU_VTENTRY => "R_M32R_GNU_VTENTRY",
+        R_M32R_16_RELA => "R_M32R_16_RELA",
+        R_M32R_32_RELA => "R_M32R_32_RELA",
+        R_M32R_24_RELA => "R_M32R_24_RELA",
+        R_M32R_10_PCREL_RELA => "R_M32R_10_PCREL_RELA",
+        R_M32R_18_PCREL_RELA => "R_M32R_18_PCREL_RELA",
+        R_M32R_26_PCREL_RELA => "R_M32R_26_PCREL_RELA",
+        R_M32R_HI16_ULO_RELA => "R_M32R_HI16_ULO_RELA",
+        R_M32R_HI16_SLO_RELA => "R_M32R_HI16_SLO_RELA",
+        R_M32R_LO16_RELA => "R_M32R_LO16_RELA",
+        R_M32R_SDA16_RELA => "R_M32R_SDA16_RELA",
+        R_M32R_RELA_GNU_VTINHERIT => "R_M32R_RELA_GNU_VTINHERIT",
+        R_M32R_RELA_GNU_VTENTRY => "R_M32R_RELA_GNU_VTENTRY",
+        R_M32R_REL32 => "R_M32R_REL32",
+        R_M32R_GOT24 => "R_M32R_GOT24",
+        R_M32R_26_PLTREL => "R_M32R_26_PLTREL",
+        R_M32R_COPY => "R_M32R_COPY",
+        R_M32R_GLOB_DAT => "R_M32R_GLOB_DAT",
+        R_M32R_JMP_SLOT => "R_M32R_JMP_SLOT",
+        R_M32R_RELATIVE => "R_M32R_RELATIVE",
+        R_M32R_GOTOFF => "R_M32R_GOTOFF",
+        R_M32R_GOTPC24 => "R_M32R_GOTPC24",
+        R_M32R_GOT16_HI_ULO => "R_M32R_GOT16_HI_ULO",
+        R_M32R_GOT16_HI_SLO => "R_M32R_GOT16_HI_SLO",
+        R_M32R_GOT16_LO => "R_M32R_GOT16_LO",
+        R_M32R_GOTPC_HI_ULO => "R_M32R_GOTPC_HI_ULO",
+        R_M32R_GOTPC_HI_SLO => "R_M32R_GOTPC_HI_SLO",
+        R_M32R_GOTPC_LO => "R_M32R_GOTPC_LO",
+        R_M32R_GOTOFF_HI_ULO => "R_M32R_GOTOFF_HI_ULO",
+        R_M32R_GOTOFF_HI_SLO => "R_M32R_GOTOFF_HI_SLO",
+        R_M32R_GOTOFF_LO => "R_M32R_GOTOFF_LO",
+        _ => return None,
+    })
+}
+
+fn mips(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_MIPS_NONE => "R_MIPS_NONE",
+        R_MIPS_16 => "R_MIPS_16",
+        R_MIPS_32 => "R_MIPS_32",
+        R_MIPS_REL32 => "R_MIPS_REL32",
+        R_MIPS_26 => "R_MIPS_26",
+        R_MIPS_HI16 => "R_MIPS_HI16",
+        R_MIPS_LO16 => "R_MIPS_LO16",
+        R_MIPS_GPREL16 => "R_MIPS_GPREL16",
+        R_MIPS_LITERAL => "R_MIPS_LITERAL",
+        R_MIPS_GOT16 => "R_MIPS_GOT16",
+        R_MIPS_PC16 => "R_MIPS_PC16",
+        R_MIPS_CALL16 => "R_MIPS_CALL16",
+        R_MIPS_GPREL32 => "R_MIPS_GPREL32",
+        R_MIPS_SHIFT5 => "R_MIPS_SHIFT5",
+        R_MIPS_SHIFT6 => "R_MIPS_SHIFT6",
+        R_MIPS_64 => "R_MIPS_64",
+        R_MIPS_GOT_DISP => "R_MIPS_GOT_DISP",
+        R_MIPS_GOT_PAGE => "R_MIPS_GOT_PAGE",
+        R_MIPS_GOT_OFST => "R_MIPS_GOT_OFST",
+        R_MIPS_GOT_HI16 => "R_MIPS_GOT_HI16",
+        R_MIPS_GOT_LO16 => "R_MIPS_GOT_LO16",
+        R_MIPS_SUB => "R_MIPS_SUB",
+        R_MIPS_INSERT_A => "R_MIPS_INSERT_A",
+        R_MIPS_INSERT_B => "R_MIPS_INSERT_B",
+        R_MIPS_DELETE => "R_MIPS_DELETE",
+        R_MIPS_HIGHER => "R_MIPS_HIGHER",
+        R_MIPS_HIGHEST => "R_MIPS_HIGHEST",
+        R_MIPS_CALL_HI16 => "R_MIPS_CALL_HI16",
+        R_MIPS_CALL_LO16 => "R_MIPS_CALL_LO16",
+        R_MIPS_SCN_DISP => "R_MIPS_SCN_DISP",
+        R_MIPS_REL16 => "R_MIPS_REL16",
+        R_MIPS_ADD_IMMEDIATE => "R_MIPS_ADD_IMMEDIATE",
+        R_MIPS_PJUMP => "R_MIPS_PJUMP",
+        R_MIPS_RELGOT => "R_MIPS_RELGOT",
+        R_MIPS_JALR => "R_MIPS_JALR",
+        R_MIPS_TLS_DTPMOD32 => "R_MIPS_TLS_DTPMOD32",
+        R_MIPS_TLS_DTPREL32 => "R_MIPS_TLS_DTPREL32",
+        R_MIPS_TLS_DTPMOD64 => "R_MIPS_TLS_DTPMOD64",
+        R_MIPS_TLS_DTPREL64 => "R_MIPS_TLS_DTPREL64",
+        R_MIPS_TLS_GD => "R_MIPS_TLS_GD",
+        R_MIPS_TLS_LDM => "R_MIPS_TLS_LDM",
+        R_MIPS_TLS_DTPREL_HI16 => "R_MIPS_TLS_DTPREL_HI16",
+        R_MIPS_TLS_DTPREL_LO16 => "R_MIPS_TLS_DTPREL_LO16",
+        R_MIPS_TLS_GOTTPREL => "R_MIPS_TLS_GOTTPREL",
+        R_MIPS_TLS_TPREL32 => "R_MIPS_TLS_TPREL32",
+        R_MIPS_TLS_TPREL64 => "R_MIPS_TLS_TPREL64",
+        R_MIPS_TLS_TPREL_HI16 => "R_MIPS_TLS_TPREL_HI16",
+        R_MIPS_TLS_TPREL_LO16 => "R_MIPS_TLS_TPREL_LO16",
+        R_MIPS_GLOB_DAT => "R_MIPS_GLOB_DAT",
+        R_MIPS_COPY => "R_MIPS_COPY",
+        R_MIPS_JUMP_SLOT => "R_MIPS_JUMP_SLOT",
+        _ => return None,
+    })
+}
+
+fn mn10300(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_MN10300_NONE => "R_MN10300_NONE",
+        R_MN10300_32 => "R_MN10300_32",
+        R_MN10300_16 => "R_MN10300_16",
+        R_MN10300_8 => "R_MN10300_8",
+        R_MN10300_PCREL32 => "R_MN10300_PCREL32",
+        R_MN10300_PCREL16 => "R_MN10300_PCREL16",
+        R_MN10300_PCREL8 => "R_MN10300_PCREL8",
+        R_MN10300_GNU_VTINHERIT => "R_MN10300_GNU_VTINHERIT",
+        R_MN10300_GNU_VTENTRY => "R_MN10300_GNU_VTENTRY",
+        R_MN10300_24 => "R_MN10300_24",
+        R_MN10300_GOTPC32 => "R_MN10300_GOTPC32",
+        R_MN10300_GOTPC16 => "R_MN10300_GOTPC16",
+        R_MN10300_GOTOFF32 => "R_MN10300_GOTOFF32",
+        R_MN10300_GOTOFF24 => "R_MN10300_GOTOFF24",
+        R_MN10300_GOTOFF16 => "R_MN10300_GOTOFF16",
+        R_MN10300_PLT32 => "R_MN10300_PLT32",
+        R_MN10300_PLT16 => "R_MN10300_PLT16",
+        R_MN10300_GOT32 => "R_MN10300_GOT32",
+        R_MN10300_GOT24 => "R_MN10300_GOT24",
+        R_MN10300_GOT16 => "R_MN10300_GOT16",
+        R_MN10300_COPY => "R_MN10300_COPY",
+        R_MN10300_GLOB_DAT => "R_MN10300_GLOB_DAT",
+        R_MN10300_JMP_SLOT => "R_MN10300_JMP_SLOT",
+        R_MN10300_RELATIVE => "R_MN10300_RELATIVE",
+        _ => return None,
+    })
+}
+
+fn parisc(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_PARISC_NONE => "R_PARISC_NONE",
+        R_PARISC_DIR32 => "R_PARISC_DIR32",
+        R_PARISC_DIR21L => "R_PARISC_DIR21L",
+        R_PARISC_DIR17R => "R_PARISC_DIR17R",
+        R_PARISC_DIR17F => "R_PARISC_DIR17F",
+        R_PARISC_DIR14R => "R_PARISC_DIR14R",
+        R_PARISC_PCREL32 => "R_PARISC_PCREL32",
+        R_PARISC_PCREL21L => "R_PARISC_PCREL21L",
+        R_PARISC_PCREL17R => "R_PARISC_PCREL17R",
+        R_PARISC_PCREL17F => "R_PARISC_PCREL17F",
+        R_PARISC_PCREL14R => "R_PARISC_PCREL14R",
+        R_PARISC_DPREL21L => "R_PARISC_DPREL21L",
+        R_PARISC_DPREL14R => "R_PARISC_DPREL14R",
+        R_PARISC_GPREL21L => "R_PARISC_GPREL21L",
+        R_PARISC_GPREL14R => "R_PARISC_GPREL14R",
+        R_PARISC_LTOFF21L => "R_PARISC_LTOFF21L",
+        R_PARISC_LTOFF14R => "R_PARISC_LTOFF14R",
+        R_PARISC_SECREL32 => "R_PARISC_SECREL32",
+        R_PARISC_SEGBASE => "R_PARISC_SEGBASE",
+        R_PARISC_SEGREL32 => "R_PARISC_SEGREL32",
+        R_PARISC_PLTOFF21L => "R_PARISC_PLTOFF21L",
+        R_PARISC_PLTOFF14R => "R_PARISC_PLTOFF14R",
+        R_PARISC_LTOFF_FPTR32 => "R_PARISC_LTOFF_FPTR32",
+        R_PARISC_LTOFF_FPTR21L => "R_PARISC_LTOFF_FPTR21L",
+        R_PARISC_LTOFF_FPTR14R => "R_PARISC_LTOFF_FPTR14R",
+        R_PARISC_FPTR64 => "R_PARISC_FPTR64",
+        R_PARISC_PLABEL32 => "R_PARISC_PLABEL32",
+        R_PARISC_PLABEL21L => "R_PARISC_PLABEL21L",
+        R_PARISC_PLABEL14R => "R_PARISC_PLABEL14R",
+        R_PARISC_PCREL64 => "R_PARISC_PCREL64",
+        R_PARISC_PCREL22F => "R_PARISC_PCREL22F",
+        R_PARISC_PCREL14WR => "R_PARISC_PCREL14WR",
+        R_PARISC_PCREL14DR => "R_PARISC_PCREL14DR",
+        R_PARISC_PCREL16F => "R_PARISC_PCREL16F",
+        R_PARISC_PCREL16WF => "R_PARISC_PCREL16WF",
+        R_PARISC_PCREL16DF => "R_PARISC_PCREL16DF",
+        R_PARISC_DIR64 => "R_PARISC_DIR64",
+        R_PARISC_DIR14WR => "R_PARISC_DIR14WR",
+        R_PARISC_DIR14DR => "R_PARISC_DIR14DR",
+        R_PARISC_DIR16F => "R_PARISC_DIR16F",
+        R_PARISC_DIR16WF => "R_PARISC_DIR16WF",
+        R_PARISC_DIR16DF => "R_PARISC_DIR16DF",
+        R_PARISC_GPREL64 => "R_PARISC_GPREL64",
+        R_PARISC_GPREL14WR => "R_PARISC_GPREL14WR",
+        R_PARISC_GPREL14DR => "R_PARISC_GPREL14DR",
+        R_PARISC_GPREL16F => "R_PARISC_GPREL16F",
+        R_PARISC_GPREL16WF => "R_PARISC_GPREL16WF",
+        R_PARISC_GPREL16DF => "R_PARISC_GPREL16DF",
+        R_PARISC_LTOFF64 => "R_PARISC_LTOFF64",
+        R_PARISC_LTOFF14WR => "R_PARISC_LTOFF14WR",
+        R_PARISC_LTOFF14DR => "R_PARISC_LTOFF14DR",
+        R_PARISC_LTOFF16F => "R_PARISC_LTOFF16F",
+        R_PARISC_LTOFF16WF => "R_PARISC_LTOFF16WF",
+        R_PARISC_LTOFF16DF => "R_PARISC_LTOFF16DF",
+        R_PARISC_SECREL64 => "R_PARISC_SECREL64",
+        R_PARISC_SEGREL64 => "R_PARISC_SEGREL64",
+        R_PARISC_PLTOFF14WR => "R_PARISC_PLTOFF14WR",
+        R_PARISC_PLTOFF14DR => "R_PARISC_PLTOFF14DR",
+        R_PARISC_PLTOFF16F => "R_PARISC_PLTOFF16F",
+        R_PARISC_PLTOFF16WF => "R_PARISC_PLTOFF16WF",
+        R_PARISC_PLTOFF16DF => "R_PARISC_PLTOFF16DF",
+        R_PARISC_LTOFF_FPTR64 => "R_PARISC_LTOFF_FPTR64",
+        R_PARISC_LTOFF_FPTR14WR => "R_PARISC_LTOFF_FPTR14WR",
+        R_PARISC_LTOFF_FPTR14DR => "R_PARISC_LTOFF_FPTR14DR",
+        R_PARISC_LTOFF_FPTR16F => "R_PARISC_LTOFF_FPTR16F",
+        R_PARISC_LTOFF_FPTR16WF => "R_PARISC_LTOFF_FPTR16WF",
+        R_PARISC_LTOFF_FPTR16DF => "R_PARISC_LTOFF_FPTR16DF",
+        R_PARISC_COPY => "R_PARISC_COPY",
+        R_PARISC_IPLT => "R_PARISC_IPLT",
+        R_PARISC_EPLT => "R_PARISC_EPLT",
+        R_PARISC_TPREL32 => "R_PARISC_TPREL32",
+        R_PARISC_TPREL21L => "R_PARISC_TPREL21L",
+        R_PARISC_TPREL14R => "R_PARISC_TPREL14R",
+        R_PARISC_LTOFF_TP21L => "R_PARISC_LTOFF_TP21L",
+        R_PARISC_LTOFF_TP14R => "R_PARISC_LTOFF_TP14R",
+        R_PARISC_LTOFF_TP14F => "R_PARISC_LTOFF_TP14F",
+        R_PARISC_TPREL64 => "R_PARISC_TPREL64",
+        R_PARISC_TPREL14WR => "R_PARISC_TPREL14WR",
+        R_PARISC_TPREL14DR => "R_PARISC_TPREL14DR",
+        R_PARISC_TPREL16F => "R_PARISC_TPREL16F",
+        R_PARISC_TPREL16WF => "R_PARISC_TPREL16WF",
+        R_PARISC_TPREL16DF => "R_PARISC_TPREL16DF",
+        R_PARISC_LTOFF_TP64 => "R_PARISC_LTOFF_TP64",
+        R_PARISC_LTOFF_TP14WR => "R_PARISC_LTOFF_TP14WR",
+        R_PARISC_LTOFF_TP14DR => "R_PARISC_LTOFF_TP14DR",
+        R_PARISC_LTOFF_TP16F => "R_PARISC_LTOFF_TP16F",
+        R_PARISC_LTOFF_TP16WF => "R_PARISC_LTOFF_TP16WF",
+        R_PARISC_LTOFF_TP16DF => "R_PARISC_LTOFF_TP16DF",
+        R_PARISC_GNU_VTENTRY => "R_PARISC_GNU_VTENTRY",
+        R_PARISC_GNU_VTINHERIT => "R_PARISC_GNU_VTINHERIT",
+        R_PARISC_TLS_GD21L => "R_PARISC_TLS_GD21L",
+        R_PARISC_TLS_GD14R => "R_PARISC_TLS_GD14R",
+        R_PARISC_TLS_GDCALL => "R_PARISC_TLS_GDCALL",
+        R_PARISC_TLS_LDM21L => "R_PARISC_TLS_LDM21L",
+        R_PARISC_TLS_LDM14R => "R_PARISC_TLS_LDM14R",
+        R_PARISC_TLS_LDMCALL => "R_PARISC_TLS_LDMCALL",
+        R_PARISC_TLS_LDO21L => "R_PARISC_TLS_LDO21L",
+        R_PARISC_TLS_LDO14R => "R_PARISC_TLS_LDO14R",
+        R_PARISC_TLS_DTPMOD32 => "R_PARISC_TLS_DTPMOD32",
+        R_PARISC_TLS_DTPMOD64 => "R_PARISC_TLS_DTPMOD64",
+        R_PARISC_TLS_DTPOFF32 => "R_PARISC_TLS_DTPOFF32",
+        R_PARISC_TLS_DTPOFF64 => "R_PARISC_TLS_DTPOFF64",
+        _ => return None,
+    })
+}
+
+fn sh(r#type: u8) -> Option<&'static str> {
+    Some(match r#type {
+        R_SH_NONE => "R_SH_NONE",
+        R_SH_DIR32 => "R_SH_DIR32",
+        R_SH_REL32 => "R_SH_REL32",
+        R_SH_DIR8WPN => "R_SH_DIR8WPN",
+        R_SH_IND12W => "R_SH_IND12W",
+        R_SH_DIR8WPL => "R_SH_DIR8WPL",
+        R_SH_DIR8WPZ => "R_SH_DIR8WPZ",
+        R_SH_DIR8BP => "R_SH_DIR8BP",
+        R_SH_DIR8W => "R_SH_DIR8W",
+        R_SH_DIR8L => "R_SH_DIR8L",
+        R_SH_SWITCH16 => "R_SH_SWITCH16",
+        R_SH_SWITCH32 => "R_SH_SWITCH32",
+        R_SH_USES => "R_SH_USES",
+        R_SH_COUNT => "R_SH_COUNT",
+        R_SH_ALIGN => "R_SH_ALIGN",
+        R_SH_CODE => "R_SH_CODE",
+        R_SH_DATA => "R_SH_DATA",
+        R_SH_LABEL => "R_SH_LABEL",
+        R_SH_SWITCH8 => "R_SH_SWITCH8",
+        R_SH_GNU_VTINHERIT => "R_SH_GNU_VTINHERIT",
+        R_SH_GNU_VTENTRY => "R_SH_GNU_VTENTRY",
+        R_SH_TLS_GD_32 => "R_SH_TLS_GD_32",
+        R_SH_TLS_LD_32 => "R_SH_TLS_LD_32",
+        R_SH_TLS_LDO_32 => "R_SH_TLS_LDO_32",
+        R_SH_TLS_IE_32 => "R_SH_TLS_IE_32",
+        R_SH_TLS_LE_32 => "R_SH_TLS_LE_32",
+        R_SH_TLS_DTPMOD32 => "R_SH_TLS_DTPMOD32",
+        R_SH_TLS_DTPOFF32 => "R_SH_TLS_DTPOFF32",
+        R_SH_TLS_TPOFF32 => "R_SH_TLS_TPOFF32",
+        R_SH_GOT32 => "R_SH_GOT32",
+        R_SH_PLT32 => "R_SH_PLT32",
+        R_SH_COPY => "R_SH_COPY",
+        R_SH_GLOB_DAT => "R_SH_GLOB_DAT",
+        R_SH_JMP_SLOT => "R_SH_JMP_SLOT",
+        R_SH_RELATIVE => "R_SH_RELATIVE",
+        R_SH_GOTOFF => "R_SH_GOTOFF",
+        R_SH_GOTPC => "R_SH_GOTPC",
+        _ => return None,
+    })
+}
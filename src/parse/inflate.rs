@@ -0,0 +1,305 @@
+use crate::{
+    error::{Error, Res},
+    parse::{Bytes, Endianness},
+    unknown,
+};
+
+const MAX_BITS: usize = 15;
+const LITERAL_CODES: usize = 288;
+const DISTANCE_CODES: usize = 30;
+
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA_BITS: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+// order in which the code lengths of the code length alphabet are stored
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+// deflate packs its fields starting from the least significant bit of every byte
+struct BitReader<'a, B> {
+    bytes: &'a mut B,
+    bits: u32,
+    count: u32,
+}
+
+impl<B: Bytes> BitReader<'_, B> {
+    fn bits(&mut self, count: u32) -> Res<u32> {
+        while self.count < count {
+            self.bits |= u32::from(self.bytes.pull::<u8>()?) << self.count;
+            self.count += 8;
+        }
+        let value = self.bits & ((1 << count) - 1);
+        self.bits >>= count;
+        self.count -= count;
+        Ok(value)
+    }
+
+    fn align(&mut self) {
+        self.bits = 0;
+        self.count = 0;
+    }
+}
+
+// canonical Huffman code, decoded one bit at a time
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0; MAX_BITS + 1];
+        for &length in lengths {
+            counts[usize::from(length)] += 1;
+        }
+        let mut offsets = [0; MAX_BITS + 1];
+        for length in 1..MAX_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                let offset = &mut offsets[usize::from(length)];
+                symbols[usize::from(*offset)] = symbol as u16;
+                *offset += 1;
+            }
+        }
+        counts[0] = 0;
+        Self { counts, symbols }
+    }
+
+    fn decode(&self, reader: &mut BitReader<impl Bytes>) -> Res<u16> {
+        let mut code = 0;
+        let mut first = 0;
+        let mut index = 0;
+        for &count in &self.counts[1..] {
+            code |= reader.bits(1)? as i32;
+            let count = i32::from(count);
+            if code - count < first {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        unknown!()
+    }
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0; LITERAL_CODES];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    (Huffman::new(&lengths), Huffman::new(&[5; DISTANCE_CODES]))
+}
+
+fn dynamic_codes(reader: &mut BitReader<impl Bytes>) -> Res<(Huffman, Huffman)> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_count > LITERAL_CODES || distance_count > DISTANCE_CODES {
+        unknown!();
+    }
+
+    let mut code_lengths = [0; CODE_LENGTH_ORDER.len()];
+    for &symbol in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[symbol] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths);
+
+    let mut lengths = vec![];
+    while lengths.len() < literal_count + distance_count {
+        let (length, repeat) = match code_length_code.decode(reader)? {
+            symbol @ 0..16 => (symbol as u8, 1),
+            16 => match lengths.last() {
+                Some(&previous) => (previous, 3 + reader.bits(2)?),
+                None => unknown!(),
+            },
+            17 => (0, 3 + reader.bits(3)?),
+            _ => (0, 11 + reader.bits(7)?),
+        };
+        lengths.extend(std::iter::repeat_n(length, repeat as usize));
+    }
+    if lengths.len() > literal_count + distance_count || lengths[256] == 0 {
+        unknown!();
+    }
+    Ok((
+        Huffman::new(&lengths[..literal_count]),
+        Huffman::new(&lengths[literal_count..]),
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader<impl Bytes>,
+    output: &mut Vec<u8>,
+    (literals, distances): (Huffman, Huffman),
+) -> Res<()> {
+    loop {
+        let symbol = usize::from(literals.decode(reader)?);
+        match symbol {
+            0..256 => output.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let symbol = symbol - 257;
+                if symbol >= LENGTH_BASES.len() {
+                    unknown!();
+                }
+                let length = usize::from(LENGTH_BASES[symbol])
+                    + reader.bits(LENGTH_EXTRA_BITS[symbol].into())? as usize;
+                let symbol = usize::from(distances.decode(reader)?);
+                if symbol >= DISTANCE_BASES.len() {
+                    unknown!();
+                }
+                let distance = usize::from(DISTANCE_BASES[symbol])
+                    + reader.bits(DISTANCE_EXTRA_BITS[symbol].into())? as usize;
+                if distance > output.len() {
+                    unknown!();
+                }
+                // the copy may overlap the bytes it produces
+                let start = output.len() - distance;
+                for i in 0..length {
+                    output.push(output[start + i]);
+                }
+            }
+        }
+    }
+}
+
+// raw DEFLATE stream (RFC 1951)
+pub fn inflate(bytes: &mut impl Bytes, output: &mut Vec<u8>) -> Res<()> {
+    let mut reader = BitReader {
+        bytes,
+        bits: 0,
+        count: 0,
+    };
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align();
+                let len: u16 = reader.bytes.pull_via(Endianness::Little)?;
+                let inverted_len: u16 = reader.bytes.pull_via(Endianness::Little)?;
+                if len != !inverted_len {
+                    unknown!();
+                }
+                let start = output.len();
+                output.resize(start + usize::from(len), 0);
+                reader.bytes.read_exact(&mut output[start..])?;
+            }
+            1 => inflate_block(&mut reader, output, fixed_codes())?,
+            2 => {
+                let codes = dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, output, codes)?;
+            }
+            _ => unknown!(),
+        }
+        if last {
+            return Ok(());
+        }
+    }
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+    // sums of up to 5552 bytes cannot overflow before the reduction
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+    (b << 16) | a
+}
+
+// DEFLATE stream in a zlib wrapper (RFC 1950)
+pub fn zlib(bytes: &mut impl Bytes) -> Res<Vec<u8>> {
+    let [method, flags]: [u8; 2] = bytes.pull()?;
+    if method & 0x0f != 8 || (u16::from(method) << 8 | u16::from(flags)) % 31 != 0 {
+        unknown!();
+    }
+    if flags & 0x20 > 0 {
+        // preset dictionaries are never used for file contents
+        unknown!();
+    }
+    let mut output = vec![];
+    inflate(bytes, &mut output)?;
+    if bytes.pull_via::<u32>(Endianness::Big)? != adler32(&output) {
+        unknown!();
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // streams written by Python's zlib module
+    const STORED: &[u8] = b"\
+        \x78\x01\x01\x0d\x00\xf2\xff\x68\x65\x6c\x6c\x6f\x2c\x20\x77\x6f\
+        \x72\x6c\x64\x0a\x21\xe7\x04\x93\
+    ";
+
+    const FIXED_HUFFMAN: &[u8] = b"\
+        \x78\x01\xcb\x48\xcd\xc9\xc9\x57\xc8\x40\x90\x3a\x0a\xe5\xf9\x45\
+        \x39\x29\x5c\x00\x79\x29\x08\xfb\
+    ";
+
+    const DYNAMIC_HUFFMAN: &[u8] = b"\
+        \x78\xda\x9d\xcb\xd7\x15\x40\x30\x00\x05\xd0\x7f\x53\xbc\x11\xf4\
+        \xb6\x8d\x12\x84\x48\xb4\x68\xd3\x3b\x6c\xe0\x7d\xdf\x73\x95\xd4\
+        \x02\x6e\x8e\xad\x13\x98\xad\xac\x06\x94\x8b\x39\x34\x1a\x73\xa2\
+        \xb7\xe3\xb4\xc2\xec\x62\xf9\x58\x15\xf7\x85\xda\xb4\x8e\x7a\x8f\
+        \x47\x1c\x9f\x38\x01\x71\x42\xe2\x44\xc4\x89\x89\x93\x10\x27\x25\
+        \x4e\xf6\xef\x3c\xa2\x07\xb7\x80\
+    ";
+
+    fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+        zlib(&mut std::io::Cursor::new(data)).ok()
+    }
+
+    #[test]
+    fn stored_block() {
+        assert_eq!(decompress(STORED).unwrap(), b"hello, world\n");
+    }
+
+    #[test]
+    fn fixed_huffman_block() {
+        assert_eq!(
+            decompress(FIXED_HUFFMAN).unwrap(),
+            b"hello hello hello, world\n"
+        );
+    }
+
+    #[test]
+    fn dynamic_huffman_block() {
+        let lines: String = (0..10)
+            .map(|i| format!("line {i}: the quick brown fox jumps over the lazy dog\n"))
+            .collect();
+        assert_eq!(decompress(DYNAMIC_HUFFMAN).unwrap(), lines.as_bytes());
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let mut data = STORED.to_vec();
+        *data.last_mut().unwrap() ^= 1;
+        assert!(decompress(&data).is_none());
+    }
+}
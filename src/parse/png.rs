@@ -1,6 +1,6 @@
 use crate::{
     error::{Error, Res},
-    parse::{Bytes, Endianness, Pull, Table},
+    parse::{Bytes, Dump, Endianness, Options, Pull, Table, dump, strings},
     unknown,
 };
 
@@ -134,11 +134,45 @@ impl Pull for Chunk {
     }
 }
 
+// data of every chunk of a type concatenated, as IDAT and some other chunks may be split
+fn chunk_data(bytes: &mut impl Bytes, r#type: &[u8]) -> Res<Vec<u8>> {
+    let file_len = bytes.seek(std::io::SeekFrom::End(0))?;
+    bytes.jump(MAGIC.len() as u64)?;
+    let mut data = vec![];
+    loop {
+        let len: usize = bytes
+            .pull_via::<u32>(Endianness::Big)?
+            .try_into()
+            .expect("u32 -> usize");
+        let chunk_type = bytes.pull::<[u8; 4]>()?;
+        // the letter cases of a chunk type mark it critical, private or safe to copy
+        if chunk_type == r#type {
+            if len as u64 > file_len.saturating_sub(bytes.stream_position()?) {
+                unknown!();
+            }
+            let start = data.len();
+            data.resize(start + len, 0);
+            bytes.read_exact(&mut data[start..])?;
+        } else {
+            bytes.forward(len)?;
+        }
+        bytes.forward(4)?; // CRC
+        if &chunk_type == b"IEND" {
+            return Ok(data);
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct Parser;
 
 impl Parser {
-    pub fn parse(self, mut bytes: impl Bytes, all: bool) -> Res<Table> {
+    pub fn parse(
+        self,
+        mut bytes: impl Bytes,
+        _: &std::path::Path,
+        options: &Options,
+    ) -> Res<Table> {
         let mut table = Table::default();
         bytes.forward(std::mem::size_of_val(&MAGIC))?;
         let mut total_len = 0;
@@ -173,7 +207,7 @@ impl Parser {
                             ColorType::RgbAlpha => "RGBA",
                         },
                     );
-                    if !all {
+                    if !options.all {
                         break;
                     }
                 }
@@ -192,13 +226,32 @@ impl Parser {
                 Chunk::Unknown => {}
             }
         }
-        if all {
+        if options.all {
             table.new_unnamed_section();
             table.add_entry("Total IDAT Size", format!("{total_len} bytes"));
             if let Some(gamma) = img_gamma {
                 table.add_entry("Gamma", format!("{gamma}"));
             }
         }
+        if let Some(min_length) = options.strings {
+            let found = strings::scan(&mut bytes, min_length)?;
+            strings::add_entries(&mut table, &found, |_| None);
+        }
+        match &options.dump {
+            Some(Dump::Chunk(r#type)) => dump::contents(
+                &mut table,
+                format!("Contents of {type} Chunks"),
+                &chunk_data(&mut bytes, r#type.as_bytes())?,
+                0,
+                options.output.as_deref(),
+            )?,
+            Some(_) => {
+                return Err(Error::Cli(
+                    "Only chunks can be dumped from PNG files".into(),
+                ));
+            }
+            None => {}
+        }
 
         Ok(table)
     }
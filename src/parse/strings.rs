@@ -0,0 +1,162 @@
+use crate::{
+    error::Res,
+    parse::{Bytes, Table},
+};
+
+pub const DEFAULT_MIN_LENGTH: usize = 4;
+
+pub struct Found {
+    pub offset: u64,
+    pub utf16: bool,
+    pub text: String,
+}
+
+// a run of printable characters being collected
+#[derive(Default)]
+struct Run {
+    start: u64,
+    text: String,
+    chars: usize,
+}
+
+impl Run {
+    fn push(&mut self, offset: u64, c: char) {
+        if self.text.is_empty() {
+            self.start = offset;
+        }
+        self.text.push(c);
+        self.chars += 1;
+    }
+
+    fn end(&mut self, min_length: usize, utf16: bool, found: &mut Vec<Found>) {
+        if self.chars >= min_length {
+            found.push(Found {
+                offset: self.start,
+                utf16,
+                text: std::mem::take(&mut self.text),
+            });
+        }
+        self.text.clear();
+        self.chars = 0;
+    }
+}
+
+fn printable(c: char) -> bool {
+    c == '\t' || !c.is_control()
+}
+
+// UTF-8 runs, which include plain ASCII
+#[derive(Default)]
+struct Utf8Scanner {
+    run: Run,
+    pending: Vec<u8>,
+    pending_start: u64,
+    remaining: usize,
+}
+
+impl Utf8Scanner {
+    fn push(&mut self, offset: u64, byte: u8, min_length: usize, found: &mut Vec<Found>) {
+        if self.remaining > 0 {
+            if byte & 0xc0 == 0x80 {
+                self.pending.push(byte);
+                self.remaining -= 1;
+                if self.remaining == 0 {
+                    match std::str::from_utf8(&self.pending)
+                        .ok()
+                        .and_then(|text| text.chars().next())
+                        .filter(|&c| printable(c))
+                    {
+                        Some(c) => self.run.push(self.pending_start, c),
+                        None => self.run.end(min_length, false, found),
+                    }
+                    self.pending.clear();
+                }
+                return;
+            }
+            // a truncated sequence ends the run, and the byte may start the next one
+            self.pending.clear();
+            self.remaining = 0;
+            self.run.end(min_length, false, found);
+        }
+        match byte {
+            b'\t' | 0x20..=0x7e => self.run.push(offset, byte.into()),
+            0xc2..=0xf4 => {
+                self.pending.push(byte);
+                self.pending_start = offset;
+                self.remaining = match byte {
+                    0xc2..=0xdf => 1,
+                    0xe0..=0xef => 2,
+                    _ => 3,
+                };
+            }
+            _ => self.run.end(min_length, false, found),
+        }
+    }
+}
+
+// UTF-16LE runs, limited to Latin-1 characters as almost any pair of bytes is valid UTF-16
+// and binary data would otherwise read as long runs of text
+#[derive(Default)]
+struct Utf16Scanner {
+    run: Run,
+}
+
+impl Utf16Scanner {
+    fn push(&mut self, offset: u64, unit: u16, min_length: usize, found: &mut Vec<Found>) {
+        match char::from_u32(unit.into()).filter(|&c| unit < 0x100 && printable(c)) {
+            Some(c) => self.run.push(offset, c),
+            None => self.run.end(min_length, true, found),
+        }
+    }
+}
+
+// printable runs of at least `min_length` characters across the whole file
+pub fn scan(bytes: &mut impl Bytes, min_length: usize) -> Res<Vec<Found>> {
+    let min_length = min_length.max(1);
+    let mut found = vec![];
+    let mut utf8 = Utf8Scanner::default();
+    // UTF-16 text may start at an even or odd offset
+    let mut utf16 = [Utf16Scanner::default(), Utf16Scanner::default()];
+    let mut previous = None;
+    let mut offset = 0;
+    bytes.jump(0)?;
+    loop {
+        let buffer = bytes.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        let len = buffer.len();
+        for &byte in buffer {
+            utf8.push(offset, byte, min_length, &mut found);
+            if let Some(low) = previous {
+                let unit = u16::from_le_bytes([low, byte]);
+                utf16[(offset % 2) as usize].push(offset - 1, unit, min_length, &mut found);
+            }
+            previous = Some(byte);
+            offset += 1;
+        }
+        bytes.consume(len);
+    }
+    utf8.run.end(min_length, false, &mut found);
+    for scanner in &mut utf16 {
+        scanner.run.end(min_length, true, &mut found);
+    }
+    found.sort_by_key(|found| found.offset);
+    Ok(found)
+}
+
+// lists strings by file offset, along with where `locate` says each one lives
+pub fn add_entries(table: &mut Table, found: &[Found], locate: impl Fn(u64) -> Option<String>) {
+    table.new_named_section(format!("Strings ({} found)", found.len()));
+    for found in found {
+        let mut value = format!("{:?}", found.text);
+        let location = locate(found.offset);
+        match (location, found.utf16) {
+            (Some(location), false) => value += &format!(" ({location})"),
+            (Some(location), true) => value += &format!(" ({location}, UTF-16LE)"),
+            (None, true) => value += " (UTF-16LE)",
+            (None, false) => {}
+        }
+        table.add_entry(format!("0x{:X}", found.offset), value);
+    }
+}
@@ -0,0 +1,897 @@
+use crate::{
+    error::{Error, Res},
+    parse::{Bytes, Endianness},
+    unknown,
+};
+
+const MAGIC: u32 = 0xFD2F_B528;
+const SKIPPABLE_MAGIC: u32 = 0x184D_2A50;
+const MAX_BLOCK_SIZE: usize = 128 * 1024;
+
+const HUFFMAN_MAX_BITS: u8 = 11;
+const HUFFMAN_MAX_WEIGHT_ACCURACY: u8 = 6;
+
+const LITERAL_LENGTH_MAX_ACCURACY: u8 = 9;
+const MATCH_LENGTH_MAX_ACCURACY: u8 = 9;
+const OFFSET_MAX_ACCURACY: u8 = 8;
+
+const LITERAL_LENGTH_DEFAULT_ACCURACY: u8 = 6;
+const LITERAL_LENGTH_DEFAULT: [i16; 36] = [
+    4, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 1, 1, 1, 1, 1,
+    -1, -1, -1, -1,
+];
+const MATCH_LENGTH_DEFAULT_ACCURACY: u8 = 6;
+const MATCH_LENGTH_DEFAULT: [i16; 53] = [
+    1, 4, 3, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1, -1, -1,
+];
+const OFFSET_DEFAULT_ACCURACY: u8 = 5;
+const OFFSET_DEFAULT: [i16; 29] = [
+    1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1,
+];
+const OFFSET_MAX_CODE: u8 = 31;
+
+// literal length codes from 16 on and match length codes from 32 on carry extra bits
+const LITERAL_LENGTH_CODES: [(u32, u8); 20] = [
+    (16, 1),
+    (18, 1),
+    (20, 1),
+    (22, 1),
+    (24, 2),
+    (28, 2),
+    (32, 3),
+    (40, 3),
+    (48, 4),
+    (64, 6),
+    (128, 7),
+    (256, 8),
+    (512, 9),
+    (1024, 10),
+    (2048, 11),
+    (4096, 12),
+    (8192, 13),
+    (16384, 14),
+    (32768, 15),
+    (65536, 16),
+];
+const MATCH_LENGTH_CODES: [(u32, u8); 21] = [
+    (35, 1),
+    (37, 1),
+    (39, 1),
+    (41, 1),
+    (43, 2),
+    (47, 2),
+    (51, 3),
+    (59, 3),
+    (67, 4),
+    (83, 4),
+    (99, 5),
+    (131, 7),
+    (259, 8),
+    (515, 9),
+    (1027, 10),
+    (2051, 11),
+    (4099, 12),
+    (8195, 13),
+    (16387, 14),
+    (32771, 15),
+    (65539, 16),
+];
+
+fn highest_bit(value: u32) -> u32 {
+    u32::BITS - 1 - value.leading_zeros()
+}
+
+// bits read from the start of a slice, least significant bit first
+struct ForwardBits<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl ForwardBits<'_> {
+    fn read(&mut self, count: u32) -> Res<u32> {
+        let mut value = 0;
+        for i in 0..count {
+            let Some(byte) = self.data.get(self.position / 8) else {
+                unknown!()
+            };
+            value |= u32::from(byte >> (self.position % 8) & 1) << i;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    fn consumed_bytes(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+}
+
+// bits read from the end of a slice towards its start, as FSE and Huffman streams are written
+struct BackwardBits<'a> {
+    data: &'a [u8],
+    offset: isize,
+}
+
+impl<'a> BackwardBits<'a> {
+    fn new(data: &'a [u8]) -> Res<Self> {
+        // the highest set bit of the last byte marks where the stream starts
+        let Some(&last) = data.last().filter(|&&last| last != 0) else {
+            unknown!()
+        };
+        Ok(Self {
+            data,
+            offset: (data.len() * 8 - 8 + highest_bit(last.into()) as usize) as isize,
+        })
+    }
+
+    // reading past the start yields zeros, which the decoders rely on at the end of a stream
+    fn read(&mut self, count: u8) -> u64 {
+        if count == 0 {
+            return 0;
+        }
+        self.offset -= isize::from(count);
+        let (start, available) = if self.offset < 0 {
+            (0, isize::from(count) + self.offset)
+        } else {
+            (self.offset as usize, isize::from(count))
+        };
+        let mut value = 0;
+        let mut done = 0;
+        while done < available.max(0) as usize {
+            let position = start + done;
+            let take = (8 - position % 8).min(available as usize - done);
+            let byte = u64::from(self.data[position / 8] >> (position % 8));
+            value |= (byte & ((1 << take) - 1)) << done;
+            done += take;
+        }
+        match self.offset {
+            0.. => value,
+            -63..0 => value << -self.offset,
+            _ => 0,
+        }
+    }
+}
+
+struct Fse {
+    accuracy_log: u8,
+    symbols: Vec<u8>,
+    bits: Vec<u8>,
+    bases: Vec<u16>,
+}
+
+impl Fse {
+    fn new(frequencies: &[i16], accuracy_log: u8) -> Res<Self> {
+        let size = 1 << accuracy_log;
+        let mut symbols = vec![0; size];
+        let mut next = vec![0u16; frequencies.len()];
+
+        // symbols with a "less than 1" probability take a single cell at the end
+        let mut high_threshold = size;
+        for (symbol, &frequency) in frequencies.iter().enumerate() {
+            if frequency == -1 {
+                high_threshold -= 1;
+                symbols[high_threshold] = symbol as u8;
+                next[symbol] = 1;
+            }
+        }
+        let step = (size >> 1) + (size >> 3) + 3;
+        let mut position = 0;
+        for (symbol, &frequency) in frequencies.iter().enumerate() {
+            if frequency <= 0 {
+                continue;
+            }
+            next[symbol] = frequency as u16;
+            for _ in 0..frequency {
+                symbols[position] = symbol as u8;
+                position = (position + step) & (size - 1);
+                while position >= high_threshold {
+                    position = (position + step) & (size - 1);
+                }
+            }
+        }
+        if position != 0 {
+            unknown!();
+        }
+
+        let mut bits = vec![0; size];
+        let mut bases = vec![0; size];
+        for state in 0..size {
+            let symbol = usize::from(symbols[state]);
+            let next_state = next[symbol];
+            next[symbol] += 1;
+            bits[state] = accuracy_log - highest_bit(next_state.into()) as u8;
+            bases[state] = ((u32::from(next_state) << bits[state]) - size as u32) as u16;
+        }
+        Ok(Self {
+            accuracy_log,
+            symbols,
+            bits,
+            bases,
+        })
+    }
+
+    fn rle(symbol: u8) -> Self {
+        Self {
+            accuracy_log: 0,
+            symbols: vec![symbol],
+            bits: vec![0],
+            bases: vec![0],
+        }
+    }
+
+    // decodes a table description, returning the table and the bytes it took
+    fn read(data: &[u8], max_accuracy_log: u8, max_symbols: usize) -> Res<(Self, usize)> {
+        let mut bits = ForwardBits { data, position: 0 };
+        let accuracy_log = bits.read(4)? as u8 + 5;
+        if accuracy_log > max_accuracy_log {
+            unknown!();
+        }
+        let mut remaining = 1 << accuracy_log;
+        let mut frequencies = vec![];
+        while remaining > 0 && frequencies.len() < max_symbols {
+            let count = highest_bit(remaining as u32 + 1) + 1;
+            let mut value = bits.read(count)? as i32;
+            let lower_mask = (1 << (count - 1)) - 1;
+            let threshold = (1 << count) - 1 - (remaining + 1);
+            if value & lower_mask < threshold {
+                bits.position -= 1;
+                value &= lower_mask;
+            } else if value > lower_mask {
+                value -= threshold;
+            }
+            let probability = value - 1;
+            remaining -= probability.abs();
+            frequencies.push(probability as i16);
+            if probability == 0 {
+                loop {
+                    let repeat = bits.read(2)?;
+                    frequencies.extend(std::iter::repeat_n(0, repeat as usize));
+                    if repeat != 3 {
+                        break;
+                    }
+                }
+            }
+        }
+        if remaining != 0 || frequencies.len() > max_symbols {
+            unknown!();
+        }
+        Ok((
+            Self::new(&frequencies, accuracy_log)?,
+            bits.consumed_bytes(),
+        ))
+    }
+
+    fn init(&self, bits: &mut BackwardBits) -> usize {
+        bits.read(self.accuracy_log) as usize
+    }
+
+    fn update(&self, state: &mut usize, bits: &mut BackwardBits) {
+        *state = usize::from(self.bases[*state]) + bits.read(self.bits[*state]) as usize;
+    }
+}
+
+struct Huffman {
+    max_bits: u8,
+    symbols: Vec<u8>,
+    bits: Vec<u8>,
+}
+
+impl Huffman {
+    // decodes a tree description, returning the table and the bytes it took
+    fn read(data: &[u8]) -> Res<(Self, usize)> {
+        let Some((&header, data)) = data.split_first() else {
+            unknown!()
+        };
+        let mut weights = vec![];
+        let len = if header < 128 {
+            let Some(data) = data.get(..header.into()) else {
+                unknown!()
+            };
+            let (fse, used) = Fse::read(data, HUFFMAN_MAX_WEIGHT_ACCURACY, 256)?;
+            let mut bits = BackwardBits::new(&data[used..])?;
+            // two interleaved states share the stream until it runs out
+            let mut states = [fse.init(&mut bits), fse.init(&mut bits)];
+            for i in (0..2).cycle() {
+                weights.push(fse.symbols[states[i]]);
+                fse.update(&mut states[i], &mut bits);
+                if bits.offset < 0 || weights.len() > 255 {
+                    weights.push(fse.symbols[states[1 - i]]);
+                    break;
+                }
+            }
+            header.into()
+        } else {
+            let count = usize::from(header - 127);
+            let Some(data) = data.get(..count.div_ceil(2)) else {
+                unknown!()
+            };
+            for i in 0..count {
+                weights.push(if i % 2 == 0 {
+                    data[i / 2] >> 4
+                } else {
+                    data[i / 2] & 0xf
+                });
+            }
+            count.div_ceil(2)
+        };
+        if weights.len() > 255 || weights.iter().any(|&weight| weight > HUFFMAN_MAX_BITS) {
+            unknown!();
+        }
+
+        // the weight of the last symbol is implied by the others summing up to a power of 2
+        let weight_sum: u32 = weights
+            .iter()
+            .filter(|&&weight| weight > 0)
+            .map(|&weight| 1 << (weight - 1))
+            .sum();
+        if weight_sum == 0 {
+            unknown!();
+        }
+        let max_bits = highest_bit(weight_sum) as u8 + 1;
+        let left_over = (1 << max_bits) - weight_sum;
+        if max_bits > HUFFMAN_MAX_BITS || !left_over.is_power_of_two() {
+            unknown!();
+        }
+        weights.push(highest_bit(left_over) as u8 + 1);
+
+        let code_bits: Vec<u8> = weights
+            .iter()
+            .map(|&weight| if weight > 0 { max_bits + 1 - weight } else { 0 })
+            .collect();
+        let mut rank_counts = [0usize; HUFFMAN_MAX_BITS as usize + 1];
+        for &bits in &code_bits {
+            rank_counts[usize::from(bits)] += 1;
+        }
+        // longer codes take the lower table indices
+        let mut rank_starts = [0; HUFFMAN_MAX_BITS as usize + 1];
+        let mut bits_table = vec![0; 1 << max_bits];
+        for bits in (1..=usize::from(max_bits)).rev() {
+            rank_starts[bits - 1] =
+                rank_starts[bits] + rank_counts[bits] * (1 << (usize::from(max_bits) - bits));
+            bits_table[rank_starts[bits]..rank_starts[bits - 1]].fill(bits as u8);
+        }
+        let mut symbols = vec![0; 1 << max_bits];
+        for (symbol, &bits) in code_bits.iter().enumerate() {
+            if bits > 0 {
+                let start = rank_starts[usize::from(bits)];
+                let len = 1 << (max_bits - bits);
+                symbols[start..start + len].fill(symbol as u8);
+                rank_starts[usize::from(bits)] += len;
+            }
+        }
+        Ok((
+            Self {
+                max_bits,
+                symbols,
+                bits: bits_table,
+            },
+            1 + len,
+        ))
+    }
+
+    fn decode_stream(&self, data: &[u8], count: usize, output: &mut Vec<u8>) -> Res<()> {
+        let mut bits = BackwardBits::new(data)?;
+        let mask = (1 << self.max_bits) - 1;
+        let mut state = bits.read(self.max_bits) as usize;
+        for _ in 0..count {
+            output.push(self.symbols[state]);
+            let count = self.bits[state];
+            state = ((state << count) | bits.read(count) as usize) & mask;
+        }
+        // the state always runs max_bits ahead of the consumed codes
+        if bits.offset != -isize::from(self.max_bits) {
+            unknown!();
+        }
+        Ok(())
+    }
+}
+
+struct Sequence {
+    literal_length: u32,
+    match_length: u32,
+    offset_value: u32,
+}
+
+// tables and offsets carried from one block of a frame to the next
+struct FrameState {
+    huffman: Option<Huffman>,
+    literal_lengths: Option<Fse>,
+    offsets: Option<Fse>,
+    match_lengths: Option<Fse>,
+    repeat_offsets: [usize; 3],
+}
+
+fn read_le(data: &[u8]) -> u32 {
+    data.iter()
+        .rev()
+        .fold(0, |value, &byte| value << 8 | u32::from(byte))
+}
+
+fn literals(block: &[u8], state: &mut FrameState) -> Res<(Vec<u8>, usize)> {
+    let Some(&first) = block.first() else {
+        unknown!()
+    };
+    let size_format = (first >> 2) & 3;
+    match first & 3 {
+        literals_type @ (0 | 1) => {
+            let header_len = match size_format {
+                0 | 2 => 1,
+                1 => 2,
+                _ => 3,
+            };
+            let Some(header) = block.get(..header_len) else {
+                unknown!()
+            };
+            let size = match header_len {
+                1 => usize::from(first >> 3),
+                _ => (read_le(header) >> 4) as usize,
+            };
+            if literals_type == 0 {
+                let Some(literals) = block.get(header_len..header_len + size) else {
+                    unknown!()
+                };
+                Ok((literals.to_vec(), header_len + size))
+            } else {
+                let Some(&byte) = block.get(header_len) else {
+                    unknown!()
+                };
+                Ok((vec![byte; size], header_len + 1))
+            }
+        }
+        literals_type => {
+            let (stream_count, header_len, size_bits) = match size_format {
+                0 => (1, 3, 10),
+                1 => (4, 3, 10),
+                2 => (4, 4, 14),
+                _ => (4, 5, 18),
+            };
+            let Some(header) = block.get(..header_len) else {
+                unknown!()
+            };
+            // the 5 byte header does not fit in 32 bits
+            let header = u64::from(read_le(&header[..header_len.min(4)]))
+                | header.get(4).map_or(0, |&byte| u64::from(byte) << 32);
+            let mask = (1 << size_bits) - 1;
+            let regenerated_size = ((header >> 4) & mask) as usize;
+            let compressed_size = ((header >> (4 + size_bits)) & mask) as usize;
+            let Some(mut data) = block.get(header_len..header_len + compressed_size) else {
+                unknown!()
+            };
+            if literals_type == 2 {
+                let (huffman, used) = Huffman::read(data)?;
+                state.huffman = Some(huffman);
+                data = &data[used..];
+            }
+            let Some(huffman) = &state.huffman else {
+                unknown!()
+            };
+
+            let mut literals = Vec::with_capacity(regenerated_size);
+            if stream_count == 1 {
+                huffman.decode_stream(data, regenerated_size, &mut literals)?;
+            } else {
+                let Some(jump_table) = data.get(..6) else {
+                    unknown!()
+                };
+                let mut sizes = [0; 4];
+                for (size, bytes) in sizes.iter_mut().zip(jump_table.chunks(2)) {
+                    *size = read_le(bytes) as usize;
+                }
+                let Some(last) = (data.len() - 6).checked_sub(sizes[..3].iter().sum()) else {
+                    unknown!()
+                };
+                sizes[3] = last;
+                let per_stream = regenerated_size.div_ceil(4);
+                let Some(last_count) = regenerated_size.checked_sub(3 * per_stream) else {
+                    unknown!()
+                };
+                let mut start = 6;
+                for (i, size) in sizes.into_iter().enumerate() {
+                    let count = if i == 3 { last_count } else { per_stream };
+                    huffman.decode_stream(&data[start..start + size], count, &mut literals)?;
+                    start += size;
+                }
+            }
+            Ok((literals, header_len + compressed_size))
+        }
+    }
+}
+
+fn sequence_table(
+    data: &[u8],
+    mode: u8,
+    (default, default_accuracy): (&[i16], u8),
+    max_accuracy_log: u8,
+    previous: &mut Option<Fse>,
+) -> Res<usize> {
+    let used = match mode {
+        0 => {
+            *previous = Some(Fse::new(default, default_accuracy)?);
+            0
+        }
+        1 => {
+            let Some(&symbol) = data.first() else {
+                unknown!()
+            };
+            *previous = Some(Fse::rle(symbol));
+            1
+        }
+        2 => {
+            let (fse, used) = Fse::read(data, max_accuracy_log, default.len().max(32))?;
+            *previous = Some(fse);
+            used
+        }
+        _ => {
+            if previous.is_none() {
+                unknown!();
+            }
+            0
+        }
+    };
+    Ok(used)
+}
+
+fn sequences(data: &[u8], state: &mut FrameState) -> Res<Vec<Sequence>> {
+    let (count, mut start) = match *data {
+        [0, ..] => return Ok(vec![]),
+        [first @ 0..128, ..] => (usize::from(first), 1),
+        [first @ 128..=254, second, ..] => (usize::from(first - 128) << 8 | usize::from(second), 2),
+        [255, second, third, ..] => (usize::from(second) + (usize::from(third) << 8) + 0x7f00, 3),
+        _ => unknown!(),
+    };
+    let Some(&modes) = data.get(start) else {
+        unknown!()
+    };
+    start += 1;
+    start += sequence_table(
+        &data[start..],
+        modes >> 6,
+        (&LITERAL_LENGTH_DEFAULT, LITERAL_LENGTH_DEFAULT_ACCURACY),
+        LITERAL_LENGTH_MAX_ACCURACY,
+        &mut state.literal_lengths,
+    )?;
+    start += sequence_table(
+        &data[start..],
+        (modes >> 4) & 3,
+        (&OFFSET_DEFAULT, OFFSET_DEFAULT_ACCURACY),
+        OFFSET_MAX_ACCURACY,
+        &mut state.offsets,
+    )?;
+    start += sequence_table(
+        &data[start..],
+        (modes >> 2) & 3,
+        (&MATCH_LENGTH_DEFAULT, MATCH_LENGTH_DEFAULT_ACCURACY),
+        MATCH_LENGTH_MAX_ACCURACY,
+        &mut state.match_lengths,
+    )?;
+    let (Some(literal_lengths), Some(offsets), Some(match_lengths)) =
+        (&state.literal_lengths, &state.offsets, &state.match_lengths)
+    else {
+        unknown!()
+    };
+
+    let Some(data) = data.get(start..) else {
+        unknown!()
+    };
+    let mut bits = BackwardBits::new(data)?;
+    let mut literal_length_state = literal_lengths.init(&mut bits);
+    let mut offset_state = offsets.init(&mut bits);
+    let mut match_length_state = match_lengths.init(&mut bits);
+    let mut sequences = Vec::with_capacity(count);
+    for i in 0..count {
+        let offset_code = offsets.symbols[offset_state];
+        let literal_length_code = usize::from(literal_lengths.symbols[literal_length_state]);
+        let match_length_code = usize::from(match_lengths.symbols[match_length_state]);
+        if offset_code > OFFSET_MAX_CODE {
+            unknown!();
+        }
+        let offset_value = (1 << offset_code) + bits.read(offset_code) as u32;
+        let match_length = match match_length_code {
+            0..32 => match_length_code as u32 + 3,
+            _ => {
+                let Some(&(base, extra)) = MATCH_LENGTH_CODES.get(match_length_code - 32) else {
+                    unknown!()
+                };
+                base + bits.read(extra) as u32
+            }
+        };
+        let literal_length = match literal_length_code {
+            0..16 => literal_length_code as u32,
+            _ => {
+                let Some(&(base, extra)) = LITERAL_LENGTH_CODES.get(literal_length_code - 16)
+                else {
+                    unknown!()
+                };
+                base + bits.read(extra) as u32
+            }
+        };
+        sequences.push(Sequence {
+            literal_length,
+            match_length,
+            offset_value,
+        });
+        if i + 1 < count {
+            literal_lengths.update(&mut literal_length_state, &mut bits);
+            match_lengths.update(&mut match_length_state, &mut bits);
+            offsets.update(&mut offset_state, &mut bits);
+        }
+    }
+    if bits.offset != 0 {
+        unknown!();
+    }
+    Ok(sequences)
+}
+
+fn compressed_block(block: &[u8], state: &mut FrameState, output: &mut Vec<u8>) -> Res<()> {
+    let (literals, used) = literals(block, state)?;
+    let mut literals = literals.as_slice();
+    for sequence in sequences(&block[used..], state)? {
+        let literal_length = sequence.literal_length as usize;
+        let Some((copied, rest)) = literals.split_at_checked(literal_length) else {
+            unknown!()
+        };
+        output.extend_from_slice(copied);
+        literals = rest;
+
+        // offset values 1 to 3 pick one of the recent offsets
+        let repeats = &mut state.repeat_offsets;
+        let offset = match (sequence.offset_value, literal_length) {
+            (value @ 4.., _) => {
+                let offset = value as usize - 3;
+                *repeats = [offset, repeats[0], repeats[1]];
+                offset
+            }
+            (1, 0) | (2, 1..) => {
+                *repeats = [repeats[1], repeats[0], repeats[2]];
+                repeats[0]
+            }
+            (1, _) => repeats[0],
+            (2, 0) | (3, 1..) => {
+                *repeats = [repeats[2], repeats[0], repeats[1]];
+                repeats[0]
+            }
+            _ => {
+                let Some(offset) = repeats[0].checked_sub(1).filter(|&offset| offset > 0) else {
+                    unknown!()
+                };
+                *repeats = [offset, repeats[0], repeats[1]];
+                offset
+            }
+        };
+        let Some(start) = output.len().checked_sub(offset) else {
+            unknown!()
+        };
+        // the match may overlap the bytes it produces
+        for i in 0..sequence.match_length as usize {
+            output.push(output[start + i]);
+        }
+    }
+    output.extend_from_slice(literals);
+    Ok(())
+}
+
+fn xxh64(data: &[u8]) -> u64 {
+    const PRIME_1: u64 = 0x9E37_79B1_85EB_CA87;
+    const PRIME_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+    const PRIME_3: u64 = 0x1656_67B1_9E37_79F9;
+    const PRIME_4: u64 = 0x85EB_CA77_C2B2_AE63;
+    const PRIME_5: u64 = 0x27D4_EB2F_1656_67C5;
+    let word = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().expect("chunk of 8 bytes"));
+    let round = |acc: u64, input: u64| {
+        acc.wrapping_add(input.wrapping_mul(PRIME_2))
+            .rotate_left(31)
+            .wrapping_mul(PRIME_1)
+    };
+    let merge = |hash: u64, acc: u64| {
+        (hash ^ round(0, acc))
+            .wrapping_mul(PRIME_1)
+            .wrapping_add(PRIME_4)
+    };
+
+    let stripes = data.chunks_exact(32);
+    let mut rest = stripes.remainder();
+    let mut hash = if data.len() >= 32 {
+        let mut accs = [
+            PRIME_1.wrapping_add(PRIME_2),
+            PRIME_2,
+            0,
+            PRIME_1.wrapping_neg(),
+        ];
+        for stripe in stripes {
+            for (acc, lane) in accs.iter_mut().zip(stripe.chunks_exact(8)) {
+                *acc = round(*acc, word(lane));
+            }
+        }
+        let hash = accs[0]
+            .rotate_left(1)
+            .wrapping_add(accs[1].rotate_left(7))
+            .wrapping_add(accs[2].rotate_left(12))
+            .wrapping_add(accs[3].rotate_left(18));
+        accs.into_iter().fold(hash, merge)
+    } else {
+        PRIME_5
+    };
+    hash = hash.wrapping_add(data.len() as u64);
+
+    while let Some((lane, remainder)) = rest.split_first_chunk::<8>() {
+        hash = (hash ^ round(0, u64::from_le_bytes(*lane)))
+            .rotate_left(27)
+            .wrapping_mul(PRIME_1)
+            .wrapping_add(PRIME_4);
+        rest = remainder;
+    }
+    if let Some((lane, remainder)) = rest.split_first_chunk::<4>() {
+        hash = (hash ^ u64::from(u32::from_le_bytes(*lane)).wrapping_mul(PRIME_1))
+            .rotate_left(23)
+            .wrapping_mul(PRIME_2)
+            .wrapping_add(PRIME_3);
+        rest = remainder;
+    }
+    for &byte in rest {
+        hash = (hash ^ u64::from(byte).wrapping_mul(PRIME_5))
+            .rotate_left(11)
+            .wrapping_mul(PRIME_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME_3);
+    hash ^ (hash >> 32)
+}
+
+// a single Zstandard frame (RFC 8878), skipping any skippable frames before it
+pub fn decompress(bytes: &mut impl Bytes) -> Res<Vec<u8>> {
+    loop {
+        match bytes.pull_via::<u32>(Endianness::Little)? {
+            MAGIC => break,
+            magic if magic & 0xffff_fff0 == SKIPPABLE_MAGIC => {
+                let size: u32 = bytes.pull_via(Endianness::Little)?;
+                bytes.forward(size as usize)?;
+            }
+            _ => unknown!(),
+        }
+    }
+
+    let descriptor: u8 = bytes.pull()?;
+    let single_segment = descriptor & 0x20 > 0;
+    let has_checksum = descriptor & 0x04 > 0;
+    if descriptor & 0x08 > 0 {
+        unknown!();
+    }
+    if !single_segment {
+        bytes.forward_sizeof::<u8>()?; // window descriptor
+    }
+    let dictionary_id = match descriptor & 3 {
+        0 => 0,
+        1 => bytes.pull_via::<u8>(Endianness::Little)?.into(),
+        2 => bytes.pull_via::<u16>(Endianness::Little)?.into(),
+        _ => bytes.pull_via::<u32>(Endianness::Little)?,
+    };
+    if dictionary_id != 0 {
+        // dictionaries live outside of the file
+        unknown!();
+    }
+    let content_size = match descriptor >> 6 {
+        0 if single_segment => Some(bytes.pull_via::<u8>(Endianness::Little)?.into()),
+        0 => None,
+        1 => Some(u64::from(bytes.pull_via::<u16>(Endianness::Little)?) + 256),
+        2 => Some(bytes.pull_via::<u32>(Endianness::Little)?.into()),
+        _ => Some(bytes.pull_via::<u64>(Endianness::Little)?),
+    };
+
+    let mut output = vec![];
+    let mut state = FrameState {
+        huffman: None,
+        literal_lengths: None,
+        offsets: None,
+        match_lengths: None,
+        repeat_offsets: [1, 4, 8],
+    };
+    loop {
+        let header = read_le(&bytes.pull::<[u8; 3]>()?);
+        let size = (header >> 3) as usize;
+        if size > MAX_BLOCK_SIZE {
+            unknown!();
+        }
+        match (header >> 1) & 3 {
+            0 => {
+                let start = output.len();
+                output.resize(start + size, 0);
+                bytes.read_exact(&mut output[start..])?;
+            }
+            1 => {
+                let byte: u8 = bytes.pull()?;
+                output.resize(output.len() + size, byte);
+            }
+            2 => {
+                let mut block = vec![0; size];
+                bytes.read_exact(&mut block)?;
+                compressed_block(&block, &mut state, &mut output)?;
+            }
+            _ => unknown!(),
+        }
+        if header & 1 > 0 {
+            break;
+        }
+    }
+
+    if has_checksum && bytes.pull_via::<u32>(Endianness::Little)? != xxh64(&output) as u32 {
+        unknown!();
+    }
+    if content_size.is_some_and(|size| size != output.len() as u64) {
+        unknown!();
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // frames written by the zstd command line tool unless noted
+    const RAW: &[u8] = b"\
+        \x28\xb5\x2f\xfd\x04\x58\x69\x00\x00\x68\x65\x6c\x6c\x6f\x2c\x20\
+        \x77\x6f\x72\x6c\x64\x0a\x4c\x1f\xf9\xf1\
+    ";
+
+    // written by hand after a skippable frame, as the tool compresses runs into sequences
+    const RLE: &[u8] = b"\
+        \x50\x2a\x4d\x18\x04\x00\x00\x00\xde\xad\xbe\xef\x28\xb5\x2f\xfd\
+        \x20\x10\x83\x00\x00\x78\
+    ";
+
+    const COMPRESSED: &[u8] = b"\
+        \x28\xb5\x2f\xfd\x04\x68\xad\x02\x00\x94\x03\x6c\x69\x6e\x65\x20\
+        \x30\x3a\x20\x74\x68\x65\x20\x71\x75\x69\x63\x6b\x20\x62\x72\x6f\
+        \x77\x6e\x20\x66\x6f\x78\x20\x6a\x75\x6d\x70\x73\x20\x6f\x76\x65\
+        \x72\x6c\x61\x7a\x79\x20\x64\x6f\x67\x0a\x31\x32\x33\x34\x35\x36\
+        \x37\x38\x39\x3a\x0b\x20\xb0\x33\x07\x9a\x88\x93\xe1\x14\x38\x0f\
+        \x27\xc3\x29\x78\x1e\x4e\x06\x8f\xb8\x96\x63\x71\x91\x27\x1d\x70\
+        \x38\x8a\
+    ";
+
+    const HUFFMAN_LITERALS: &[u8] = b"\
+        \x28\xb5\x2f\xfd\x04\x68\x85\x01\x00\x42\x03\x0b\x10\xb0\xeb\x14\
+        \xb1\xbd\x65\x8e\xa5\x88\xad\x93\x24\x67\xfc\x4e\x0c\xe0\x1d\x4d\
+        \x52\xf8\xe5\xb0\xf8\x09\xa8\x19\xff\x14\x74\x56\x85\x9a\xb1\xba\
+        \x5c\xa7\xef\xd5\xeb\x9b\x3e\xb2\x00\xc6\xf1\xa8\x80\
+    ";
+
+    fn decompress_frame(data: &[u8]) -> Option<Vec<u8>> {
+        decompress(&mut std::io::Cursor::new(data)).ok()
+    }
+
+    #[test]
+    fn raw_block() {
+        assert_eq!(decompress_frame(RAW).unwrap(), b"hello, world\n");
+    }
+
+    #[test]
+    fn rle_block() {
+        assert_eq!(decompress_frame(RLE).unwrap(), [b'x'; 16]);
+    }
+
+    #[test]
+    fn compressed_block() {
+        let lines: String = (0..10)
+            .map(|i| format!("line {i}: the quick brown fox jumps over the lazy dog\n"))
+            .collect();
+        assert_eq!(decompress_frame(COMPRESSED).unwrap(), lines.as_bytes());
+    }
+
+    #[test]
+    fn huffman_literals() {
+        assert_eq!(
+            decompress_frame(HUFFMAN_LITERALS).unwrap(),
+            b"the over seven vows jumps while jumbled fox dog hex\n"
+        );
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let mut data = RAW.to_vec();
+        *data.last_mut().unwrap() ^= 1;
+        assert!(decompress_frame(&data).is_none());
+    }
+}